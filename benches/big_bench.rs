@@ -19,7 +19,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         sql_1,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -32,7 +32,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         sql_2,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -45,7 +45,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         sql_3,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -15,7 +15,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         sql_1,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -28,7 +28,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         sql_2,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -46,7 +46,7 @@ WHERE u.active = 1 AND o.status = 'completed';";
         sql_3,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -73,7 +73,7 @@ FROM students;";
         sql_4,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -86,7 +86,7 @@ FROM students;";
         sql_5,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -99,7 +99,7 @@ FROM students;";
         sql_6,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -112,7 +112,7 @@ FROM students;";
         sql_7,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -125,7 +125,7 @@ FROM students;";
         sql_9,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -138,7 +138,7 @@ FROM students;";
         sql_10,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -151,7 +151,7 @@ FROM students;";
         sql_11,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -164,7 +164,7 @@ FROM students;";
         sql_12,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -177,7 +177,7 @@ FROM students;";
         sql_13,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -190,7 +190,7 @@ FROM students;";
         sql_14,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -203,7 +203,7 @@ FROM students;";
         sql_15,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -216,7 +216,7 @@ FROM students;";
         sql_16,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -229,7 +229,7 @@ FROM students;";
         sql_17,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -242,7 +242,7 @@ FROM students;";
         sql_18,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -255,7 +255,7 @@ FROM students;";
         sql_19,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -268,7 +268,7 @@ FROM students;";
         sql_20,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -281,7 +281,7 @@ FROM students;";
         sql_21,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -294,7 +294,7 @@ FROM students;";
         sql_22,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -307,7 +307,7 @@ FROM students;";
         sql_23,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -320,7 +320,7 @@ FROM students;";
         sql_24,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -333,7 +333,7 @@ FROM students;";
         sql_25,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -362,7 +362,7 @@ LIMIT 100;"#;
         sql_26,
         |b, i| {
             b.iter(|| {
-                parser.parse(black_box(&i)).unwrap();
+                parser.parse(black_box(i)).unwrap();
             });
         },
     );
@@ -4,30 +4,46 @@ use minivec::MiniVec;
 use crate::{
     ParserError,
     ast::query::Query,
-    common::utils::{expect_kind, maybe_kind},
+    common::{
+        ident::Ident,
+        utils::{expect_kind, maybe_kind},
+    },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct CteBinding<'a> {
-    pub name: &'a  str,
-    pub columns: Option<MiniVec<&'a str>>,
+    pub name: Ident<'a>,
+    pub columns: Option<MiniVec<Ident<'a>>>,
     pub query: Box<Query<'a>>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for CteBinding<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Cte<'a> {
     pub recursive: bool,
     pub bindings: MiniVec<CteBinding<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for Cte<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> CteBinding<'a> {
     fn new(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
-        let name = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-        };
+        let start = *cursor;
+        let name = Ident::from_token(token_table, cursor)?;
         *cursor += 1;
 
         let columns = if maybe_kind(token_table, cursor, &TokenKind::LeftParen) {
@@ -42,11 +58,11 @@ impl<'a> CteBinding<'a> {
                         *cursor += 1;
                         break;
                     }
-                    Some(TokenKind::Identifier) => {
-                        cols.push(token_table.source_at(*cursor));
+                    Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                        cols.push(Ident::from_token(token_table, cursor)?);
                         *cursor += 1;
                     }
-                    _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                    _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
                 }
             }
             Some(cols)
@@ -67,6 +83,7 @@ impl<'a> CteBinding<'a> {
             name,
             columns,
             query,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
@@ -77,6 +94,7 @@ impl<'a> Cte<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::With))?;
+        let start = *cursor;
         *cursor += 1;
 
         let recursive = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Recursive))
@@ -93,7 +111,7 @@ impl<'a> Cte<'a> {
                 Some(TokenKind::Comma) => {
                     *cursor += 1;
                 }
-                Some(TokenKind::Identifier) => {
+                Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
                     bindings.push(CteBinding::new(token_table, cursor)?);
                 }
                 _ => break,
@@ -101,12 +119,13 @@ impl<'a> Cte<'a> {
         }
 
         if bindings.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
 
         Ok(Cte {
             recursive,
             bindings,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
@@ -1,75 +1,294 @@
 use crate::{
     ParserError,
-    common::utils::{expect_kind, maybe_kind},
+    common::{
+        data_type::DataType,
+        expr::{Expr, StringLiteral},
+        ident::Ident,
+        order::OrderItem,
+        utils::{expect_kind, expect_word, is_word, maybe_kind, syntax_error},
+    },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
     SelectStatement,
 };
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ColumnConstraint<'a> {
+    /// `CONSTRAINT name` written before the inline constraints.
+    pub name: Option<Ident<'a>>,
     pub not_null: bool,
-    pub default: Option<&'a str>,
+    /// `DEFAULT expr`
+    pub default: Option<Box<Expr<'a>>>,
     pub primary_key: bool,
     pub unique: bool,
+    /// `CHECK (expr)`
+    pub check: Option<Box<Expr<'a>>>,
+    /// `REFERENCES t (col) [ON DELETE ...] [ON UPDATE ...]`
+    pub references: Option<Box<ForeignKeyReference<'a>>>,
+    /// `GENERATED ... AS (expr)` or `GENERATED ... AS IDENTITY`
+    pub generated: Option<GeneratedColumn<'a>>,
+    /// MySQL `AUTO_INCREMENT`
+    pub auto_increment: bool,
+}
+
+/// How a column computes its value.
+#[derive(Debug, PartialEq, Clone)]
+pub enum GeneratedColumn<'a> {
+    /// `GENERATED ALWAYS AS (expr) [STORED | VIRTUAL]`
+    Expr { expr: Box<Expr<'a>>, stored: bool },
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`
+    Identity { always: bool },
 }
 
-#[derive(Debug, PartialEq)]
+/// What happens to referencing rows when the referenced row changes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ReferentialAction {
+    Cascade,
+    Restrict,
+    SetNull,
+    SetDefault,
+    NoAction,
+}
+
+/// The `REFERENCES` part of a foreign key.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ForeignKeyReference<'a> {
+    pub table: Ident<'a>,
+    /// The referenced columns, empty for the primary key of `table`.
+    pub columns: Vec<Ident<'a>>,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for ForeignKeyReference<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TableConstraintKind<'a> {
+    /// `PRIMARY KEY (a, b)`
+    PrimaryKey { columns: Vec<Ident<'a>> },
+    /// `UNIQUE (a, b)`
+    Unique { columns: Vec<Ident<'a>> },
+    /// `FOREIGN KEY (a) REFERENCES t (b) ...`
+    ForeignKey {
+        columns: Vec<Ident<'a>>,
+        references: ForeignKeyReference<'a>,
+    },
+    /// `CHECK (expr)`
+    Check(Expr<'a>),
+}
+
+/// A constraint in the element list of `CREATE TABLE`, e.g.
+/// `CONSTRAINT pk PRIMARY KEY (a, b)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TableConstraint<'a> {
+    pub name: Option<Ident<'a>>,
+    pub kind: TableConstraintKind<'a>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for TableConstraint<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct ColumnDef<'a> {
-    pub name: &'a str,
-    pub col_type: &'a str,
-    pub col_type_params: Option<&'a str>,
+    pub name: Ident<'a>,
+    pub data_type: DataType<'a>,
     pub constraint: ColumnConstraint<'a>,
+    /// `COLLATE name`
+    pub collation: Option<Ident<'a>>,
+    /// MySQL `COMMENT '...'`
+    pub comment: Option<StringLiteral<'a>>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for ColumnDef<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum CreateTable<'a> {
     Table {
         if_not_exists: bool,
-        name: &'a str,
+        name: Ident<'a>,
         columns: Vec<ColumnDef<'a>>,
+        constraints: Vec<TableConstraint<'a>>,
+        span: Span,
     },
     AsSelect {
-        name: &'a str,
-        columns: Option<Vec<&'a str>>,
+        name: Ident<'a>,
+        columns: Option<Vec<Ident<'a>>>,
         select: Box<SelectStatement<'a>>,
+        span: Span,
     },
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for CreateTable<'a> {
+    fn span(&self) -> Span {
+        match self {
+            CreateTable::Table { span, .. } | CreateTable::AsSelect { span, .. } => *span,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct DropTable<'a> {
     pub if_exists: bool,
-    pub names: Vec<&'a str>,
+    pub names: Vec<Ident<'a>>,
     pub cascade: bool,
+    pub span: Span,
+}
+
+impl<'a> Spanned for DropTable<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum AlterTableOperation<'a> {
     AddColumn {
+        if_not_exists: bool,
         column: ColumnDef<'a>,
     },
     DropColumn {
-        name: &'a str,
+        name: Ident<'a>,
         cascade: bool,
     },
-    RenameTo(&'a str),
+    RenameTo(Ident<'a>),
     RenameColumn {
-        old: &'a str,
-        new: &'a str,
+        old: Ident<'a>,
+        new: Ident<'a>,
+    },
+    /// `ALTER [COLUMN] name action`
+    AlterColumn {
+        name: Ident<'a>,
+        action: AlterColumnAction<'a>,
+    },
+    /// `ADD [CONSTRAINT name] PRIMARY KEY (...)` and the other table constraints
+    AddConstraint(TableConstraint<'a>),
+    /// `DROP CONSTRAINT [IF EXISTS] name [CASCADE | RESTRICT]`
+    DropConstraint {
+        if_exists: bool,
+        name: Ident<'a>,
+        cascade: bool,
+    },
+    /// MySQL `MODIFY [COLUMN] column_def`
+    ModifyColumn {
+        column: ColumnDef<'a>,
+    },
+    /// MySQL `CHANGE [COLUMN] old column_def`
+    ChangeColumn {
+        old: Ident<'a>,
+        column: ColumnDef<'a>,
+    },
+}
+
+/// What `ALTER COLUMN` changes.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AlterColumnAction<'a> {
+    /// `SET DEFAULT expr`
+    SetDefault(Expr<'a>),
+    /// `DROP DEFAULT`
+    DropDefault,
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `[SET DATA] TYPE data_type [USING expr]`
+    SetDataType {
+        data_type: DataType<'a>,
+        using: Option<Expr<'a>>,
     },
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct AlterTable<'a> {
-    pub name: &'a str,
-    pub operation: AlterTableOperation<'a>,
+    pub name: Ident<'a>,
+    /// The comma-separated actions, in source order.
+    pub operations: Vec<AlterTableOperation<'a>>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for AlterTable<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// `CREATE [UNIQUE] INDEX [CONCURRENTLY] [IF NOT EXISTS] [name] ON table ...`
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateIndex<'a> {
+    pub unique: bool,
+    pub concurrently: bool,
+    pub if_not_exists: bool,
+    /// Omitted in PostgreSQL `CREATE INDEX ON t (...)`.
+    pub name: Option<Ident<'a>>,
+    pub table: Ident<'a>,
+    /// The index method, e.g. `btree` in `USING btree`.
+    pub using: Option<Ident<'a>>,
+    /// The key columns or expressions with their sort order.
+    pub columns: Vec<OrderItem<'a>>,
+    /// `INCLUDE (a, b)`, empty when absent.
+    pub include: Vec<Ident<'a>>,
+    /// The predicate of a partial index.
+    pub where_statement: Option<Expr<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for CreateIndex<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// `DROP INDEX [CONCURRENTLY] [IF EXISTS] name, ... [ON table] [CASCADE | RESTRICT]`
+#[derive(Debug, PartialEq, Clone)]
+pub struct DropIndex<'a> {
+    pub concurrently: bool,
+    pub if_exists: bool,
+    pub names: Vec<Ident<'a>>,
+    /// MySQL `DROP INDEX name ON table`
+    pub table: Option<Ident<'a>>,
+    pub cascade: bool,
+    pub span: Span,
+}
+
+impl<'a> Spanned for DropIndex<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum DdlStatement<'a> {
     CreateTable(CreateTable<'a>),
     DropTable(DropTable<'a>),
     AlterTable(AlterTable<'a>),
+    CreateIndex(CreateIndex<'a>),
+    DropIndex(DropIndex<'a>),
+}
+
+impl<'a> Spanned for DdlStatement<'a> {
+    fn span(&self) -> Span {
+        match self {
+            DdlStatement::CreateTable(create) => create.span(),
+            DdlStatement::DropTable(drop) => drop.span,
+            DdlStatement::AlterTable(alter) => alter.span,
+            DdlStatement::CreateIndex(create) => create.span,
+            DdlStatement::DropIndex(drop) => drop.span,
+        }
+    }
 }
 
 impl<'a> DdlStatement<'a> {
@@ -87,7 +306,7 @@ impl<'a> DdlStatement<'a> {
             Some(TokenKind::Keyword(Keyword::Alter)) => {
                 Self::parse_alter(token_table, cursor)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
@@ -100,20 +319,32 @@ impl<'a> DdlStatement<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Create))?;
+        let start = *cursor;
         *cursor += 1;
 
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::Table)) => {
                 *cursor += 1;
-                Self::parse_create_table(token_table, cursor)
+                Self::parse_create_table(token_table, cursor, start)
+            }
+            Some(TokenKind::Keyword(Keyword::Unique)) => {
+                *cursor += 1;
+                expect_word(token_table, cursor, "INDEX")?;
+                Self::parse_create_index(token_table, cursor, start, true)
+            }
+            // INDEX 不是保留字，按名字匹配
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "INDEX") => {
+                *cursor += 1;
+                Self::parse_create_index(token_table, cursor, start, false)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
     fn parse_create_table(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
+        start: usize,
     ) -> Result<Self, ParserError> {
         let if_not_exists =
             if let Some(TokenKind::Keyword(Keyword::If)) = token_table.get_kind(*cursor) {
@@ -127,10 +358,7 @@ impl<'a> DdlStatement<'a> {
                 false
             };
 
-        let name = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-        };
+        let name = Ident::from_token(token_table, cursor)?;
         *cursor += 1;
 
         match token_table.get_kind(*cursor) {
@@ -138,6 +366,7 @@ impl<'a> DdlStatement<'a> {
             Some(TokenKind::LeftParen) => {
                 *cursor += 1;
                 let mut columns = Vec::new();
+                let mut constraints = Vec::new();
                 loop {
                     match token_table.get_kind(*cursor) {
                         Some(TokenKind::RightParen) => {
@@ -147,16 +376,21 @@ impl<'a> DdlStatement<'a> {
                         Some(TokenKind::Comma) => {
                             *cursor += 1;
                         }
-                        Some(TokenKind::Identifier) => {
+                        _ if Self::starts_table_constraint(token_table, *cursor) => {
+                            constraints.push(Self::parse_table_constraint(token_table, cursor)?);
+                        }
+                        Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
                             columns.push(Self::parse_column_def(token_table, cursor)?);
                         }
-                        _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                        _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
                     }
                 }
                 Ok(DdlStatement::CreateTable(CreateTable::Table {
                     if_not_exists,
                     name,
                     columns,
+                    constraints,
+                    span: token_table.span_between(start, *cursor),
                 }))
             }
             // CREATE TABLE name AS SELECT ...
@@ -168,6 +402,7 @@ impl<'a> DdlStatement<'a> {
                     name,
                     columns: None,
                     select: Box::new(select),
+                    span: token_table.span_between(start, *cursor),
                 }))
             }
             // CREATE TABLE name (col, ...) AS SELECT ...
@@ -177,9 +412,10 @@ impl<'a> DdlStatement<'a> {
                     name,
                     columns: None,
                     select: Box::new(select),
+                    span: token_table.span_between(start, *cursor),
                 }))
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
@@ -187,23 +423,33 @@ impl<'a> DdlStatement<'a> {
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<ColumnDef<'a>, ParserError> {
-        let name = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-        };
+        let start = *cursor;
+        let name = Ident::from_token(token_table, cursor)?;
         *cursor += 1;
 
-        let (col_type, col_type_params) = Self::parse_column_type(token_table, cursor)?;
+        let data_type = DataType::parse(token_table, cursor)?;
 
         let mut constraint = ColumnConstraint {
+            name: None,
             not_null: false,
             default: None,
             primary_key: false,
             unique: false,
+            check: None,
+            references: None,
+            generated: None,
+            auto_increment: false,
         };
+        let mut collation = None;
+        let mut comment = None;
 
         loop {
             match token_table.get_kind(*cursor) {
+                Some(TokenKind::Keyword(Keyword::Constraint)) => {
+                    *cursor += 1;
+                    constraint.name = Some(Ident::from_token(token_table, cursor)?);
+                    *cursor += 1;
+                }
                 Some(TokenKind::Keyword(Keyword::Not)) => {
                     *cursor += 1;
                     expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Null))?;
@@ -212,17 +458,26 @@ impl<'a> DdlStatement<'a> {
                 }
                 Some(TokenKind::Keyword(Keyword::Default)) => {
                     *cursor += 1;
-                    constraint.default = Some(match token_table.get_kind(*cursor) {
-                        Some(TokenKind::StringLiteral) => token_table.source_at(*cursor),
-                        Some(TokenKind::Number) => token_table.source_at(*cursor),
-                        Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-                        Some(TokenKind::Keyword(Keyword::True))
-                        | Some(TokenKind::Keyword(Keyword::False)) => token_table.source_at(*cursor),
-                        Some(TokenKind::Keyword(Keyword::Null)) => token_table.source_at(*cursor),
-                        _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-                    });
+                    constraint.default = Some(Box::new(Expr::build(token_table, cursor)?));
+                }
+                // 以下选项不是关键字，按标识符匹配，避免占用常见的列名
+                Some(TokenKind::Identifier) if is_word(token_table, *cursor, "COLLATE") => {
+                    *cursor += 1;
+                    collation = Some(Ident::from_token(token_table, cursor)?);
                     *cursor += 1;
                 }
+                Some(TokenKind::Identifier) if is_word(token_table, *cursor, "GENERATED") => {
+                    *cursor += 1;
+                    constraint.generated = Some(Self::parse_generated(token_table, cursor)?);
+                }
+                Some(TokenKind::Identifier) if is_word(token_table, *cursor, "AUTO_INCREMENT") => {
+                    *cursor += 1;
+                    constraint.auto_increment = true;
+                }
+                Some(TokenKind::Identifier) if is_word(token_table, *cursor, "COMMENT") => {
+                    *cursor += 1;
+                    comment = Some(StringLiteral::from_token(token_table, cursor)?);
+                }
                 Some(TokenKind::Keyword(Keyword::Primary)) => {
                     *cursor += 1;
                     expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Key))?;
@@ -233,6 +488,14 @@ impl<'a> DdlStatement<'a> {
                     *cursor += 1;
                     constraint.unique = true;
                 }
+                Some(TokenKind::Keyword(Keyword::Check)) => {
+                    *cursor += 1;
+                    constraint.check = Some(Box::new(Self::parse_paren_expr(token_table, cursor)?));
+                }
+                Some(TokenKind::Keyword(Keyword::References)) => {
+                    constraint.references =
+                        Some(Box::new(Self::parse_references(token_table, cursor)?));
+                }
                 Some(TokenKind::Comma) | Some(TokenKind::RightParen) => {
                     break;
                 }
@@ -240,46 +503,385 @@ impl<'a> DdlStatement<'a> {
             }
         }
 
+        // CONSTRAINT name 后面必须跟着约束
+        if constraint.name.is_some()
+            && !constraint.not_null
+            && constraint.default.is_none()
+            && !constraint.primary_key
+            && !constraint.unique
+            && constraint.check.is_none()
+            && constraint.references.is_none()
+            && constraint.generated.is_none()
+            && !constraint.auto_increment
+        {
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+        }
+
         Ok(ColumnDef {
             name,
-            col_type,
-            col_type_params,
+            data_type,
             constraint,
+            collation,
+            comment,
+            span: token_table.span_between(start, *cursor),
         })
     }
 
-    fn parse_column_type(
+    // 表约束的开头；FOREIGN 不是保留字，只有后面跟着 KEY 时才算
+    fn starts_table_constraint(token_table: &TokenTable<'a>, cursor: usize) -> bool {
+        match token_table.get_kind(cursor) {
+            Some(TokenKind::Keyword(
+                Keyword::Constraint | Keyword::Primary | Keyword::Unique | Keyword::Check,
+            )) => true,
+            Some(TokenKind::Identifier) => {
+                is_word(token_table, cursor, "FOREIGN")
+                    && token_table.get_kind(cursor + 1) == Some(&TokenKind::Keyword(Keyword::Key))
+            }
+            _ => false,
+        }
+    }
+
+    // GENERATED 之后：{ ALWAYS | BY DEFAULT } AS { (expr) [STORED | VIRTUAL] | IDENTITY }
+    fn parse_generated(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<GeneratedColumn<'a>, ParserError> {
+        let always = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "ALWAYS") => {
+                *cursor += 1;
+                true
+            }
+            Some(TokenKind::Keyword(Keyword::By)) => {
+                *cursor += 1;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Default))?;
+                *cursor += 1;
+                false
+            }
+            _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+        };
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::As))?;
+        *cursor += 1;
+
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "IDENTITY") => {
+                *cursor += 1;
+                Ok(GeneratedColumn::Identity { always })
+            }
+            // 生成列只能是 GENERATED ALWAYS
+            Some(TokenKind::LeftParen) if always => {
+                let expr = Self::parse_paren_expr(token_table, cursor)?;
+                let stored = match token_table.get_kind(*cursor) {
+                    Some(TokenKind::Identifier) if is_word(token_table, *cursor, "STORED") => {
+                        *cursor += 1;
+                        true
+                    }
+                    Some(TokenKind::Identifier) if is_word(token_table, *cursor, "VIRTUAL") => {
+                        *cursor += 1;
+                        false
+                    }
+                    _ => false,
+                };
+                Ok(GeneratedColumn::Expr {
+                    expr: Box::new(expr),
+                    stored,
+                })
+            }
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+        }
+    }
+
+    fn parse_table_constraint(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
-    ) -> Result<(&'a str, Option<&'a str>), ParserError> {
-        let col_type = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+    ) -> Result<TableConstraint<'a>, ParserError> {
+        let start = *cursor;
+        let name = if maybe_kind(
+            token_table,
+            cursor,
+            &TokenKind::Keyword(Keyword::Constraint),
+        ) {
+            *cursor += 1;
+            let name = Ident::from_token(token_table, cursor)?;
+            *cursor += 1;
+            Some(name)
+        } else {
+            None
+        };
+
+        let kind = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::Primary)) => {
+                *cursor += 1;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Key))?;
+                *cursor += 1;
+                TableConstraintKind::PrimaryKey {
+                    columns: Self::parse_ident_list(token_table, cursor)?,
+                }
+            }
+            Some(TokenKind::Keyword(Keyword::Unique)) => {
+                *cursor += 1;
+                TableConstraintKind::Unique {
+                    columns: Self::parse_ident_list(token_table, cursor)?,
+                }
+            }
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "FOREIGN") => {
+                *cursor += 1;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Key))?;
+                *cursor += 1;
+                let columns = Self::parse_ident_list(token_table, cursor)?;
+                let references = Self::parse_references(token_table, cursor)?;
+                TableConstraintKind::ForeignKey {
+                    columns,
+                    references,
+                }
+            }
+            Some(TokenKind::Keyword(Keyword::Check)) => {
+                *cursor += 1;
+                TableConstraintKind::Check(Self::parse_paren_expr(token_table, cursor)?)
+            }
+            _ => {
+                return Err(syntax_error(
+                    token_table,
+                    cursor,
+                    &[
+                        TokenKind::Keyword(Keyword::Primary),
+                        TokenKind::Keyword(Keyword::Unique),
+                        TokenKind::Identifier,
+                        TokenKind::Keyword(Keyword::Check),
+                    ],
+                ));
+            }
         };
+
+        Ok(TableConstraint {
+            name,
+            kind,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    // 括号里的表达式，用于 CHECK 和生成列
+    fn parse_paren_expr(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Expr<'a>, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let expr = Expr::build(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(expr)
+    }
+
+    // REFERENCES t [(col, ...)] [ON DELETE action] [ON UPDATE action]
+    fn parse_references(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<ForeignKeyReference<'a>, ParserError> {
+        expect_kind(
+            token_table,
+            cursor,
+            &TokenKind::Keyword(Keyword::References),
+        )?;
+        let start = *cursor;
+        *cursor += 1;
+
+        let table = Ident::from_token(token_table, cursor)?;
         *cursor += 1;
 
-        // Check for parameterized type: VARCHAR(100), DECIMAL(10,2)
-        // For P0, we skip the params (they're stored as part of the raw SQL, not AST)
-        let params = if let Some(TokenKind::LeftParen) = token_table.get_kind(*cursor) {
+        let columns = if maybe_kind(token_table, cursor, &TokenKind::LeftParen) {
+            Self::parse_ident_list(token_table, cursor)?
+        } else {
+            Vec::new()
+        };
+
+        let mut on_delete = None;
+        let mut on_update = None;
+        while maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On)) {
+            *cursor += 1;
+            let target = match token_table.get_kind(*cursor) {
+                Some(TokenKind::Keyword(Keyword::Delete)) => &mut on_delete,
+                Some(TokenKind::Keyword(Keyword::Update)) => &mut on_update,
+                _ => {
+                    return Err(syntax_error(
+                        token_table,
+                        cursor,
+                        &[
+                            TokenKind::Keyword(Keyword::Delete),
+                            TokenKind::Keyword(Keyword::Update),
+                        ],
+                    ));
+                }
+            };
+            // 同一个 ON DELETE / ON UPDATE 不能写两次
+            if target.is_some() {
+                return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+            }
             *cursor += 1;
-            let mut depth = 1u32;
-            loop {
+            *target = Some(Self::parse_referential_action(token_table, cursor)?);
+        }
+
+        Ok(ForeignKeyReference {
+            table,
+            columns,
+            on_delete,
+            on_update,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    fn parse_referential_action(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<ReferentialAction, ParserError> {
+        let action = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::Cascade)) => ReferentialAction::Cascade,
+            Some(TokenKind::Keyword(Keyword::Restrict)) => ReferentialAction::Restrict,
+            Some(TokenKind::Keyword(Keyword::Set)) => {
+                *cursor += 1;
                 match token_table.get_kind(*cursor) {
-                    Some(TokenKind::LeftParen) => { *cursor += 1; depth += 1; }
-                    Some(TokenKind::RightParen) => {
-                        depth -= 1;
-                        *cursor += 1;
-                        if depth == 0 { break Some(""); }
+                    Some(TokenKind::Keyword(Keyword::Null)) => ReferentialAction::SetNull,
+                    Some(TokenKind::Keyword(Keyword::Default)) => ReferentialAction::SetDefault,
+                    _ => {
+                        return Err(syntax_error(
+                            token_table,
+                            cursor,
+                            &[
+                                TokenKind::Keyword(Keyword::Null),
+                                TokenKind::Keyword(Keyword::Default),
+                            ],
+                        ));
                     }
-                    Some(_) => { *cursor += 1; }
-                    None => return Err(ParserError::SyntaxError(*cursor, *cursor)),
                 }
             }
+            // NO ACTION 不是关键字，按标识符匹配
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "NO") => {
+                *cursor += 1;
+                expect_word(token_table, cursor, "ACTION")?;
+                return Ok(ReferentialAction::NoAction);
+            }
+            _ => {
+                return Err(syntax_error(
+                    token_table,
+                    cursor,
+                    &[
+                        TokenKind::Keyword(Keyword::Cascade),
+                        TokenKind::Keyword(Keyword::Restrict),
+                        TokenKind::Keyword(Keyword::Set),
+                    ],
+                ));
+            }
+        };
+        *cursor += 1;
+        Ok(action)
+    }
+
+    // (a, b, ...)，至少一个列名
+    fn parse_ident_list(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Vec<Ident<'a>>, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let mut columns = Vec::new();
+        loop {
+            columns.push(Ident::from_token(token_table, cursor)?);
+            *cursor += 1;
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(columns)
+    }
+
+    fn parse_create_index(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+        unique: bool,
+    ) -> Result<Self, ParserError> {
+        let concurrently = Self::parse_concurrently(token_table, cursor);
+        let if_not_exists = Self::parse_if_not_exists(token_table, cursor)?;
+
+        // PostgreSQL 允许省略索引名
+        let name = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On)) {
+            if if_not_exists {
+                return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+            }
+            None
+        } else {
+            let name = Ident::from_token(token_table, cursor)?;
+            *cursor += 1;
+            Some(name)
+        };
+
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On))?;
+        *cursor += 1;
+        let table = Ident::from_token(token_table, cursor)?;
+        *cursor += 1;
+
+        let using = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Using)) {
+            *cursor += 1;
+            let method = Ident::from_token(token_table, cursor)?;
+            *cursor += 1;
+            Some(method)
         } else {
             None
         };
 
-        Ok((col_type, params))
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let mut columns = Vec::new();
+        loop {
+            columns.push(OrderItem::build(token_table, cursor)?);
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+
+        // INCLUDE 不是关键字，按标识符匹配
+        let include = if is_word(token_table, *cursor, "INCLUDE") {
+            *cursor += 1;
+            Self::parse_ident_list(token_table, cursor)?
+        } else {
+            Vec::new()
+        };
+
+        let where_statement =
+            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Where)) {
+                *cursor += 1;
+                Some(Expr::build(token_table, cursor)?)
+            } else {
+                None
+            };
+
+        Ok(DdlStatement::CreateIndex(CreateIndex {
+            unique,
+            concurrently,
+            if_not_exists,
+            name,
+            table,
+            using,
+            columns,
+            include,
+            where_statement,
+            span: token_table.span_between(start, *cursor),
+        }))
+    }
+
+    fn parse_concurrently(token_table: &TokenTable<'a>, cursor: &mut usize) -> bool {
+        let concurrently = is_word(token_table, *cursor, "CONCURRENTLY");
+        if concurrently {
+            *cursor += 1;
+        }
+        concurrently
     }
 
     // ========================================================================
@@ -291,20 +893,26 @@ impl<'a> DdlStatement<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Drop))?;
+        let start = *cursor;
         *cursor += 1;
 
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::Table)) => {
                 *cursor += 1;
-                Self::parse_drop_table(token_table, cursor)
+                Self::parse_drop_table(token_table, cursor, start)
+            }
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "INDEX") => {
+                *cursor += 1;
+                Self::parse_drop_index(token_table, cursor, start)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
     fn parse_drop_table(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
+        start: usize,
     ) -> Result<Self, ParserError> {
         let if_exists =
             if let Some(TokenKind::Keyword(Keyword::If)) = token_table.get_kind(*cursor) {
@@ -319,8 +927,8 @@ impl<'a> DdlStatement<'a> {
         let mut names = Vec::new();
         loop {
             match token_table.get_kind(*cursor) {
-                Some(TokenKind::Identifier) => {
-                    names.push(token_table.source_at(*cursor));
+                Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                    names.push(Ident::from_token(token_table, cursor)?);
                     *cursor += 1;
                 }
                 Some(TokenKind::Comma) => {
@@ -331,7 +939,7 @@ impl<'a> DdlStatement<'a> {
         }
 
         if names.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
 
         let cascade = if let Some(TokenKind::Keyword(Keyword::Cascade)) =
@@ -352,6 +960,47 @@ impl<'a> DdlStatement<'a> {
             if_exists,
             names,
             cascade,
+            span: token_table.span_between(start, *cursor),
+        }))
+    }
+
+    fn parse_drop_index(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+    ) -> Result<Self, ParserError> {
+        let concurrently = Self::parse_concurrently(token_table, cursor);
+        let if_exists = Self::parse_if_exists(token_table, cursor)?;
+
+        let mut names = Vec::new();
+        loop {
+            names.push(Ident::from_token(token_table, cursor)?);
+            *cursor += 1;
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+
+        let table = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On)) {
+            *cursor += 1;
+            let table = Ident::from_token(token_table, cursor)?;
+            *cursor += 1;
+            Some(table)
+        } else {
+            None
+        };
+
+        let cascade = Self::parse_drop_behavior(token_table, cursor);
+
+        Ok(DdlStatement::DropIndex(DropIndex {
+            concurrently,
+            if_exists,
+            names,
+            table,
+            cascade,
+            span: token_table.span_between(start, *cursor),
         }))
     }
 
@@ -364,73 +1013,121 @@ impl<'a> DdlStatement<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Alter))?;
+        let start = *cursor;
         *cursor += 1;
 
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::Table)) => {
                 *cursor += 1;
-                Self::parse_alter_table(token_table, cursor)
+                Self::parse_alter_table(token_table, cursor, start)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
     fn parse_alter_table(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
+        start: usize,
     ) -> Result<Self, ParserError> {
-        let name = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-        };
+        let name = Ident::from_token(token_table, cursor)?;
         *cursor += 1;
 
+        let mut operations = Vec::new();
+        loop {
+            operations.push(Self::parse_alter_operation(token_table, cursor)?);
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+
+        Ok(DdlStatement::AlterTable(AlterTable {
+            name,
+            operations,
+            span: token_table.span_between(start, *cursor),
+        }))
+    }
+
+    fn parse_alter_operation(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<AlterTableOperation<'a>, ParserError> {
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::Add)) => {
                 *cursor += 1;
+                if Self::starts_table_constraint(token_table, *cursor) {
+                    let constraint = Self::parse_table_constraint(token_table, cursor)?;
+                    return Ok(AlterTableOperation::AddConstraint(constraint));
+                }
                 // OPTIONAL COLUMN keyword
                 if let Some(TokenKind::Keyword(Keyword::Column)) = token_table.get_kind(*cursor) {
                     *cursor += 1;
                 }
+                let if_not_exists = Self::parse_if_not_exists(token_table, cursor)?;
                 let column = Self::parse_column_def(token_table, cursor)?;
-                Ok(DdlStatement::AlterTable(AlterTable {
-                    name,
-                    operation: AlterTableOperation::AddColumn { column },
-                }))
+                Ok(AlterTableOperation::AddColumn {
+                    if_not_exists,
+                    column,
+                })
             }
             Some(TokenKind::Keyword(Keyword::Drop)) => {
                 *cursor += 1;
+                if maybe_kind(
+                    token_table,
+                    cursor,
+                    &TokenKind::Keyword(Keyword::Constraint),
+                ) {
+                    *cursor += 1;
+                    let if_exists = Self::parse_if_exists(token_table, cursor)?;
+                    let name = Ident::from_token(token_table, cursor)?;
+                    *cursor += 1;
+                    let cascade = Self::parse_drop_behavior(token_table, cursor);
+                    return Ok(AlterTableOperation::DropConstraint {
+                        if_exists,
+                        name,
+                        cascade,
+                    });
+                }
                 // OPTIONAL COLUMN keyword
                 if let Some(TokenKind::Keyword(Keyword::Column)) = token_table.get_kind(*cursor) {
                     *cursor += 1;
                 }
-                let col_name = match token_table.get_kind(*cursor) {
-                    Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-                    _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-                };
+                let name = Ident::from_token(token_table, cursor)?;
                 *cursor += 1;
-
-                let cascade =
-                    if let Some(TokenKind::Keyword(Keyword::Cascade)) = token_table.get_kind(*cursor)
-                    {
-                        *cursor += 1;
-                        true
-                    } else if let Some(TokenKind::Keyword(Keyword::Restrict)) =
-                        token_table.get_kind(*cursor)
-                    {
-                        *cursor += 1;
-                        false
-                    } else {
-                        false
-                    };
-
-                Ok(DdlStatement::AlterTable(AlterTable {
-                    name,
-                    operation: AlterTableOperation::DropColumn {
-                        name: col_name,
-                        cascade,
-                    },
-                }))
+                let cascade = Self::parse_drop_behavior(token_table, cursor);
+                Ok(AlterTableOperation::DropColumn { name, cascade })
+            }
+            Some(TokenKind::Keyword(Keyword::Alter)) => {
+                *cursor += 1;
+                // OPTIONAL COLUMN keyword
+                if let Some(TokenKind::Keyword(Keyword::Column)) = token_table.get_kind(*cursor) {
+                    *cursor += 1;
+                }
+                let name = Ident::from_token(token_table, cursor)?;
+                *cursor += 1;
+                let action = Self::parse_alter_column_action(token_table, cursor)?;
+                Ok(AlterTableOperation::AlterColumn { name, action })
+            }
+            // MODIFY 与 CHANGE 不是关键字，按标识符匹配
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "MODIFY") => {
+                *cursor += 1;
+                if let Some(TokenKind::Keyword(Keyword::Column)) = token_table.get_kind(*cursor) {
+                    *cursor += 1;
+                }
+                let column = Self::parse_column_def(token_table, cursor)?;
+                Ok(AlterTableOperation::ModifyColumn { column })
+            }
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "CHANGE") => {
+                *cursor += 1;
+                if let Some(TokenKind::Keyword(Keyword::Column)) = token_table.get_kind(*cursor) {
+                    *cursor += 1;
+                }
+                let old = Ident::from_token(token_table, cursor)?;
+                *cursor += 1;
+                let column = Self::parse_column_def(token_table, cursor)?;
+                Ok(AlterTableOperation::ChangeColumn { old, column })
             }
             Some(TokenKind::Keyword(Keyword::Rename)) => {
                 *cursor += 1;
@@ -448,62 +1145,163 @@ impl<'a> DdlStatement<'a> {
                     Some(TokenKind::Keyword(Keyword::To)) => {
                         // RENAME TO new_name (COLUMN keyword not allowed here)
                         if opt_column {
-                            return Err(ParserError::SyntaxError(*cursor, *cursor));
+                            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
                         }
                         *cursor += 1;
-                        let new_name = match token_table.get_kind(*cursor) {
-                            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-                            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-                        };
+                        let new_name = Ident::from_token(token_table, cursor)?;
                         *cursor += 1;
-                        Ok(DdlStatement::AlterTable(AlterTable {
-                            name,
-                            operation: AlterTableOperation::RenameTo(new_name),
-                        }))
+                        Ok(AlterTableOperation::RenameTo(new_name))
                     }
-                    Some(TokenKind::Identifier) if !opt_column => {
-                        // Ambiguous: could be RENAME old TO new or RENAME TO old (syntax error)
-                        // Check if next token is TO
-                        let first = token_table.source_at(*cursor);
+                    Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                        // RENAME [COLUMN] old_name TO new_name
+                        let old = Ident::from_token(token_table, cursor)?;
                         *cursor += 1;
-                        if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::To)) {
-                            *cursor += 1;
-                            let new = match token_table.get_kind(*cursor) {
-                                Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-                                _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-                            };
-                            *cursor += 1;
-                            Ok(DdlStatement::AlterTable(AlterTable {
-                                name,
-                                operation: AlterTableOperation::RenameColumn {
-                                    old: first,
-                                    new,
-                                },
-                            }))
-                        } else {
-                            Err(ParserError::SyntaxError(*cursor, *cursor))
-                        }
+                        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::To))?;
+                        *cursor += 1;
+                        let new = Ident::from_token(token_table, cursor)?;
+                        *cursor += 1;
+                        Ok(AlterTableOperation::RenameColumn { old, new })
                     }
-                    Some(TokenKind::Identifier) => {
-                        // RENAME COLUMN old_name TO new_name
-                        let old = token_table.source_at(*cursor);
+                    _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+                }
+            }
+            _ => Err(syntax_error(
+                token_table,
+                cursor,
+                &[
+                    TokenKind::Keyword(Keyword::Add),
+                    TokenKind::Keyword(Keyword::Drop),
+                    TokenKind::Keyword(Keyword::Alter),
+                    TokenKind::Keyword(Keyword::Rename),
+                ],
+            )),
+        }
+    }
+
+    fn parse_alter_column_action(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<AlterColumnAction<'a>, ParserError> {
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::Set)) => {
+                *cursor += 1;
+                match token_table.get_kind(*cursor) {
+                    Some(TokenKind::Keyword(Keyword::Default)) => {
                         *cursor += 1;
-                        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::To))?;
+                        Ok(AlterColumnAction::SetDefault(Expr::build(
+                            token_table,
+                            cursor,
+                        )?))
+                    }
+                    Some(TokenKind::Keyword(Keyword::Not)) => {
                         *cursor += 1;
-                        let new = match token_table.get_kind(*cursor) {
-                            Some(TokenKind::Identifier) => token_table.source_at(*cursor),
-                            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
-                        };
+                        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Null))?;
                         *cursor += 1;
-                        Ok(DdlStatement::AlterTable(AlterTable {
-                            name,
-                            operation: AlterTableOperation::RenameColumn { old, new },
-                        }))
+                        Ok(AlterColumnAction::SetNotNull)
                     }
-                    _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+                    // SET DATA TYPE
+                    Some(TokenKind::Identifier) if is_word(token_table, *cursor, "DATA") => {
+                        *cursor += 1;
+                        Self::parse_set_data_type(token_table, cursor)
+                    }
+                    _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+                }
+            }
+            Some(TokenKind::Keyword(Keyword::Drop)) => {
+                *cursor += 1;
+                match token_table.get_kind(*cursor) {
+                    Some(TokenKind::Keyword(Keyword::Default)) => {
+                        *cursor += 1;
+                        Ok(AlterColumnAction::DropDefault)
+                    }
+                    Some(TokenKind::Keyword(Keyword::Not)) => {
+                        *cursor += 1;
+                        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Null))?;
+                        *cursor += 1;
+                        Ok(AlterColumnAction::DropNotNull)
+                    }
+                    _ => Err(syntax_error(
+                        token_table,
+                        cursor,
+                        &[
+                            TokenKind::Keyword(Keyword::Default),
+                            TokenKind::Keyword(Keyword::Not),
+                        ],
+                    )),
                 }
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "TYPE") => {
+                Self::parse_set_data_type(token_table, cursor)
+            }
+            _ => Err(syntax_error(
+                token_table,
+                cursor,
+                &[
+                    TokenKind::Keyword(Keyword::Set),
+                    TokenKind::Keyword(Keyword::Drop),
+                ],
+            )),
+        }
+    }
+
+    // TYPE data_type [USING expr]
+    fn parse_set_data_type(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<AlterColumnAction<'a>, ParserError> {
+        expect_word(token_table, cursor, "TYPE")?;
+        let data_type = DataType::parse(token_table, cursor)?;
+        let using = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Using)) {
+            *cursor += 1;
+            Some(Expr::build(token_table, cursor)?)
+        } else {
+            None
+        };
+        Ok(AlterColumnAction::SetDataType { data_type, using })
+    }
+
+    fn parse_if_not_exists(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<bool, ParserError> {
+        if let Some(TokenKind::Keyword(Keyword::If)) = token_table.get_kind(*cursor) {
+            *cursor += 1;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Not))?;
+            *cursor += 1;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Exists))?;
+            *cursor += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn parse_if_exists(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<bool, ParserError> {
+        if let Some(TokenKind::Keyword(Keyword::If)) = token_table.get_kind(*cursor) {
+            *cursor += 1;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Exists))?;
+            *cursor += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    // [CASCADE | RESTRICT]，默认 RESTRICT
+    fn parse_drop_behavior(token_table: &TokenTable<'a>, cursor: &mut usize) -> bool {
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::Cascade)) => {
+                *cursor += 1;
+                true
+            }
+            Some(TokenKind::Keyword(Keyword::Restrict)) => {
+                *cursor += 1;
+                false
+            }
+            _ => false,
         }
     }
 }
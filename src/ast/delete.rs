@@ -2,18 +2,21 @@ use minivec::MiniVec;
 
 use crate::{
     ParserError,
+    ast::select::parse_returning,
     common::{
+        alias::Alias,
         expr::Expr,
         from::{From, Table},
         utils::{expect_kind, maybe_kind},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
 /// Represents a DELETE statement.
 ///
-/// Supports three syntactic forms:
+/// Supports four syntactic forms:
 ///
 /// 1. Simple single-table delete:
 ///    `DELETE FROM users WHERE id = 1`
@@ -24,17 +27,32 @@ use crate::{
 /// 3. Multi-table delete (MySQL style):
 ///    `DELETE u, o FROM users u JOIN orders o ON u.id = o.user_id`
 ///
+/// 4. DELETE with extra tables (PostgreSQL style):
+///    `DELETE FROM users u USING orders o WHERE u.id = o.user_id`
+///
 /// The `delete_tables` field lists explicit target aliases when present.
 /// When `None`, the entire FROM clause is the implicit delete target.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct DeleteStatement<'a> {
     /// Optional explicit list of table aliases to delete from.
     /// Present in MySQL-style `DELETE t1, t2 FROM ...` syntax.
     pub delete_tables: Option<MiniVec<Table<'a>>>,
     /// The FROM clause — a single table or tree of joined tables.
     pub from: From<'a>,
+    /// Optional PostgreSQL `USING` list of extra tables for the condition.
+    pub using: Option<MiniVec<From<'a>>>,
     /// Optional WHERE condition.
     pub conditions: Option<Expr<'a>>,
+    /// Optional `RETURNING` list.
+    pub returning: Option<Vec<Alias<'a, Expr<'a>>>>,
+    /// Byte range of the whole statement, from `DELETE` to the last token.
+    pub span: Span,
+}
+
+impl<'a> Spanned for DeleteStatement<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> DeleteStatement<'a> {
@@ -47,20 +65,21 @@ impl<'a> DeleteStatement<'a> {
 
     fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Delete))?;
+        let start = *cursor;
         *cursor += 1;
 
         // ── detect MySQL-style multi-table delete: DELETE t1, t2 FROM ... ──
         // If the token immediately after DELETE is an Identifier (not FROM),
         // collect comma-separated table aliases as explicit delete targets.
         let delete_tables = match token_table.get_kind(*cursor) {
-            Some(TokenKind::Identifier) => {
+            Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
                 let mut tables = MiniVec::new();
                 loop {
                     match token_table.get_kind(*cursor) {
                         Some(TokenKind::Comma) => {
                             *cursor += 1;
                         }
-                        Some(TokenKind::Identifier) => {
+                        Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
                             tables.push(Table::class_name_with_single(token_table, cursor)?);
                         }
                         _ => break,
@@ -81,6 +100,14 @@ impl<'a> DeleteStatement<'a> {
         // ── parse FROM clause (supports JOINs via From::parse) ──
         let from = From::parse(token_table, cursor)?;
 
+        // ── optional USING list (PostgreSQL) ──
+        let using = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Using)) {
+            *cursor += 1;
+            Some(From::parse_list(token_table, cursor)?)
+        } else {
+            None
+        };
+
         // ── optional WHERE condition ──
         let conditions = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Where)) {
             *cursor += 1;
@@ -89,10 +116,16 @@ impl<'a> DeleteStatement<'a> {
             None
         };
 
+        // ── optional RETURNING list ──
+        let returning = parse_returning(token_table, cursor)?;
+
         Ok(Self {
             delete_tables,
             from,
+            using,
             conditions,
+            returning,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
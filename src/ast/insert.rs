@@ -1,62 +1,52 @@
 use minivec::MiniVec;
 
 use crate::{
-    ParserError, SelectStatement, common::{
+    ParserError,
+    ast::{query::Query, select::parse_returning, update::Assignment},
+    common::{
+        alias::Alias,
         expr::Expr,
         from::Table,
-        utils::expect_kind,
-    }, keyword::Keyword, token::{TokenKind, TokenTable}
+        ident::Ident,
+        utils::{expect_kind, expect_word, is_word, maybe_kind, syntax_error},
+    },
+    keyword::Keyword,
+    span::{Span, Spanned},
+    token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum InsertValue<'a> {
     AllSelect {
-        select: SelectStatement<'a>,
+        select: Query<'a>,
     },
     PartOfSelect {
-        select: SelectStatement<'a>,
+        select: Query<'a>,
         columns: MiniVec<Expr<'a>>,
     },
     Values {
         columns: MiniVec<Expr<'a>>,
         values: MiniVec<MiniVec<Expr<'a>>>,
-    }
+    },
+    /// `DEFAULT VALUES`
+    DefaultValues,
 }
 
 impl<'a> InsertValue<'a> {
     pub(crate) fn build(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::Values)) => {
-                *cursor += 1;
-                let mut values = MiniVec::new();
-                loop {
-                    match token_table.get_kind(*cursor) {
-                        Some(TokenKind::LeftParen) => {
-                            *cursor += 1;
-                            let mut value_row = MiniVec::new();
-                            loop {
-                                match token_table.get_kind(*cursor) {
-                                    Some(TokenKind::Comma) => { *cursor += 1; }
-                                    Some(TokenKind::RightParen) => { break; }
-                                    Some(_) => {
-                                        let value = Expr::build(token_table, cursor)?;
-                                        value_row.push(value);
-                                    }
-                                    None => break,
-                                }
-                            }
-                            expect_kind(token_table, cursor, &TokenKind::RightParen)?;
-                            *cursor += 1;
-                            values.push(value_row);
-                        }
-                        Some(TokenKind::Comma) => { *cursor += 1; }
-                        _ => break,
-                    }
-                }
+                let values = Self::parse_rows(token_table, cursor)?;
                 Ok(Self::Values { columns: MiniVec::new(), values })
             },
-            Some(TokenKind::Keyword(Keyword::Select)) => {
-                let select = SelectStatement::new(token_table, cursor)?;
+            Some(TokenKind::Keyword(Keyword::Default)) => {
+                *cursor += 1;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Values))?;
+                *cursor += 1;
+                Ok(Self::DefaultValues)
+            }
+            Some(TokenKind::Keyword(Keyword::Select | Keyword::With)) => {
+                let select = Query::parse(token_table, cursor)?;
                 Ok(Self::AllSelect { select })
             },
             Some(TokenKind::LeftParen) => {
@@ -83,58 +73,216 @@ impl<'a> InsertValue<'a> {
                 *cursor += 1;
 
                 match token_table.get_kind(*cursor) {
-                    Some(TokenKind::Keyword(Keyword::Select)) => {
-                        let select = SelectStatement::new(token_table, cursor)?;
+                    Some(TokenKind::Keyword(Keyword::Select | Keyword::With)) => {
+                        let select = Query::parse(token_table, cursor)?;
                         Ok(Self::PartOfSelect { select, columns })
                     },
                     Some(TokenKind::Keyword(Keyword::Values)) => {
-                        *cursor += 1;
-                        let mut values = MiniVec::new();
-                        loop {
-                            match token_table.get_kind(*cursor) {
-                                Some(TokenKind::LeftParen) => {
-                                    *cursor += 1;
-                                    let mut value_row = MiniVec::new();
-                                    loop {
-                                        match token_table.get_kind(*cursor) {
-                                            Some(TokenKind::Comma) => {
-                                                *cursor += 1;
-                                            }
-                                            Some(TokenKind::RightParen) => {
-                                                break;
-                                            }
-                                            Some(_) => {
-                                                let value = Expr::build(token_table, cursor)?;
-                                                value_row.push(value);
-                                            }
-                                            None => break,
-                                        }
-                                    }
-                                    expect_kind(token_table, cursor, &TokenKind::RightParen)?;
-                                    *cursor += 1;
-                                    values.push(value_row);
-                                }
-                                Some(TokenKind::Comma) => {
-                                    *cursor += 1;
-                                }
-                                _ => break,
+                        let values = Self::parse_rows(token_table, cursor)?;
+                        Ok(Self::Values { columns, values })
+                    }
+                    _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+                }
+            }
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+        }
+    }
+
+    // VALUES (...), (...)，行内可以写 DEFAULT
+    fn parse_rows(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<MiniVec<MiniVec<Expr<'a>>>, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Values))?;
+        *cursor += 1;
+        let mut values = MiniVec::new();
+        loop {
+            match token_table.get_kind(*cursor) {
+                Some(TokenKind::LeftParen) => {
+                    *cursor += 1;
+                    let mut value_row = MiniVec::new();
+                    loop {
+                        match token_table.get_kind(*cursor) {
+                            Some(TokenKind::Comma) => {
+                                *cursor += 1;
+                            }
+                            Some(TokenKind::RightParen) => {
+                                break;
                             }
+                            Some(_) => {
+                                let value = Expr::build_or_default(token_table, cursor)?;
+                                value_row.push(value);
+                            }
+                            None => break,
                         }
+                    }
+                    expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+                    *cursor += 1;
+                    values.push(value_row);
+                }
+                Some(TokenKind::Comma) => {
+                    *cursor += 1;
+                }
+                _ => break,
+            }
+        }
+        Ok(values)
+    }
+}
 
-                        Ok(Self::Values { columns, values })
+/// What `ON CONFLICT` checks for a conflict.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConflictTarget<'a> {
+    /// `ON CONFLICT (a, b)`
+    Columns(MiniVec<Ident<'a>>),
+    /// `ON CONFLICT ON CONSTRAINT name`
+    Constraint(Ident<'a>),
+}
+
+/// An upsert clause after the inserted rows.
+#[derive(Debug, PartialEq, Clone)]
+pub enum OnConflict<'a> {
+    /// PostgreSQL `ON CONFLICT [target] DO NOTHING`
+    DoNothing {
+        target: Option<ConflictTarget<'a>>,
+        span: Span,
+    },
+    /// PostgreSQL `ON CONFLICT [target] DO UPDATE SET ... [WHERE ...]`
+    DoUpdate {
+        target: Option<ConflictTarget<'a>>,
+        assignments: MiniVec<Assignment<'a>>,
+        where_statement: Option<Expr<'a>>,
+        span: Span,
+    },
+    /// MySQL `ON DUPLICATE KEY UPDATE ...`
+    DuplicateKeyUpdate {
+        assignments: MiniVec<Assignment<'a>>,
+        span: Span,
+    },
+}
+
+impl<'a> Spanned for OnConflict<'a> {
+    fn span(&self) -> Span {
+        match self {
+            OnConflict::DoNothing { span, .. }
+            | OnConflict::DoUpdate { span, .. }
+            | OnConflict::DuplicateKeyUpdate { span, .. } => *span,
+        }
+    }
+}
+
+impl<'a> OnConflict<'a> {
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On))?;
+        let start = *cursor;
+        *cursor += 1;
+
+        // CONFLICT、DUPLICATE、DO、NOTHING 不是保留字，按名字匹配
+        if is_word(token_table, *cursor, "DUPLICATE") {
+            *cursor += 1;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Key))?;
+            *cursor += 1;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Update))?;
+            *cursor += 1;
+            let assignments = Assignment::build_list(token_table, cursor)?;
+            return Ok(Self::DuplicateKeyUpdate {
+                assignments,
+                span: token_table.span_between(start, *cursor),
+            });
+        }
+
+        expect_word(token_table, cursor, "CONFLICT")?;
+        let target = Self::parse_target(token_table, cursor)?;
+        expect_word(token_table, cursor, "DO")?;
+        if is_word(token_table, *cursor, "NOTHING") {
+            *cursor += 1;
+            return Ok(Self::DoNothing {
+                target,
+                span: token_table.span_between(start, *cursor),
+            });
+        }
+        if !maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Update)) {
+            return Err(syntax_error(
+                token_table,
+                cursor,
+                &[TokenKind::Identifier, TokenKind::Keyword(Keyword::Update)],
+            ));
+        }
+        *cursor += 1;
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Set))?;
+        *cursor += 1;
+        let assignments = Assignment::build_list(token_table, cursor)?;
+        let where_statement =
+            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Where)) {
+                *cursor += 1;
+                Some(Expr::build(token_table, cursor)?)
+            } else {
+                None
+            };
+        Ok(Self::DoUpdate {
+            target,
+            assignments,
+            where_statement,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    fn parse_target(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Option<ConflictTarget<'a>>, ParserError> {
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::LeftParen) => {
+                *cursor += 1;
+                let mut columns = MiniVec::new();
+                loop {
+                    columns.push(Ident::from_token(token_table, cursor)?);
+                    *cursor += 1;
+                    if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                        *cursor += 1;
+                    } else {
+                        break;
                     }
-                    _ => Err(ParserError::SyntaxError(*cursor, *cursor))
                 }
-            },
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor))
+                expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+                *cursor += 1;
+                Ok(Some(ConflictTarget::Columns(columns)))
+            }
+            Some(TokenKind::Keyword(Keyword::On)) => {
+                *cursor += 1;
+                expect_kind(
+                    token_table,
+                    cursor,
+                    &TokenKind::Keyword(Keyword::Constraint),
+                )?;
+                *cursor += 1;
+                let name = Ident::from_token(token_table, cursor)?;
+                *cursor += 1;
+                Ok(Some(ConflictTarget::Constraint(name)))
+            }
+            _ => Ok(None),
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct InsertStatement<'a> {
     pub table: Table<'a>,
     pub insert_value: InsertValue<'a>,
+    /// `ON CONFLICT ...` or `ON DUPLICATE KEY UPDATE ...`
+    pub on_conflict: Option<Box<OnConflict<'a>>>,
+    /// `RETURNING ...`
+    pub returning: Option<Vec<Alias<'a, Expr<'a>>>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for InsertStatement<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> InsertStatement<'a> {
@@ -147,6 +295,7 @@ impl<'a> InsertStatement<'a> {
 
     fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Insert))?;
+        let start = *cursor;
         *cursor += 1;
 
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Into))?;
@@ -156,9 +305,20 @@ impl<'a> InsertStatement<'a> {
 
         let insert_value = InsertValue::build(token_table, cursor)?;
 
+        let on_conflict = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On)) {
+            Some(Box::new(OnConflict::build(token_table, cursor)?))
+        } else {
+            None
+        };
+
+        let returning = parse_returning(token_table, cursor)?;
+
         Ok(InsertStatement {
             table,
             insert_value,
+            on_conflict,
+            returning,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
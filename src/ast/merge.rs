@@ -0,0 +1,208 @@
+use minivec::MiniVec;
+
+use crate::{
+    ParserError,
+    ast::{insert::InsertValue, update::Assignment},
+    common::{
+        expr::Expr,
+        from::Table,
+        utils::{expect_kind, expect_word, is_word, maybe_kind, syntax_error},
+    },
+    keyword::Keyword,
+    span::{Span, Spanned},
+    token::{TokenKind, TokenTable},
+};
+
+/// Which rows a `WHEN` clause of a `MERGE` applies to.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MergeMatch {
+    /// `WHEN MATCHED`
+    Matched,
+    /// `WHEN NOT MATCHED`
+    NotMatched,
+}
+
+/// The action of a `WHEN ... THEN` clause.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MergeAction<'a> {
+    /// `UPDATE SET a = ..., b = ...`
+    Update {
+        assignments: MiniVec<Assignment<'a>>,
+    },
+    /// `DELETE`
+    Delete,
+    /// `INSERT [(a, b)] VALUES (...)` or `INSERT DEFAULT VALUES`
+    Insert { insert_value: Box<InsertValue<'a>> },
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+/// `WHEN [NOT] MATCHED [AND condition] THEN action`
+#[derive(Debug, PartialEq, Clone)]
+pub struct MergeClause<'a> {
+    pub kind: MergeMatch,
+    pub condition: Option<Expr<'a>>,
+    pub action: MergeAction<'a>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for MergeClause<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> MergeClause<'a> {
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::When))?;
+        let start = *cursor;
+        *cursor += 1;
+
+        let kind = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Not)) {
+            *cursor += 1;
+            MergeMatch::NotMatched
+        } else {
+            MergeMatch::Matched
+        };
+        expect_word(token_table, cursor, "MATCHED")?;
+
+        let condition = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::And)) {
+            *cursor += 1;
+            Some(Expr::build(token_table, cursor)?)
+        } else {
+            None
+        };
+
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Then))?;
+        *cursor += 1;
+
+        let action = Self::parse_action(kind, token_table, cursor)?;
+
+        Ok(Self {
+            kind,
+            condition,
+            action,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    // MATCHED 只能 UPDATE/DELETE，NOT MATCHED 只能 INSERT，两者都可以 DO NOTHING
+    fn parse_action(
+        kind: MergeMatch,
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<MergeAction<'a>, ParserError> {
+        match (kind, token_table.get_kind(*cursor)) {
+            (MergeMatch::Matched, Some(TokenKind::Keyword(Keyword::Update))) => {
+                *cursor += 1;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Set))?;
+                *cursor += 1;
+                let assignments = Assignment::build_list(token_table, cursor)?;
+                Ok(MergeAction::Update { assignments })
+            }
+            (MergeMatch::Matched, Some(TokenKind::Keyword(Keyword::Delete))) => {
+                *cursor += 1;
+                Ok(MergeAction::Delete)
+            }
+            (MergeMatch::NotMatched, Some(TokenKind::Keyword(Keyword::Insert))) => {
+                *cursor += 1;
+                let start = *cursor;
+                let insert_value = InsertValue::build(token_table, cursor)?;
+                // MERGE 的 INSERT 只接受 VALUES，不接受查询
+                if let InsertValue::AllSelect { .. } | InsertValue::PartOfSelect { .. } =
+                    insert_value
+                {
+                    return Err(ParserError::SyntaxError(
+                        token_table.span_between(start, *cursor),
+                    ));
+                }
+                Ok(MergeAction::Insert {
+                    insert_value: Box::new(insert_value),
+                })
+            }
+            // DO NOTHING 不是保留字，按名字匹配
+            (_, Some(TokenKind::Identifier)) if is_word(token_table, *cursor, "DO") => {
+                *cursor += 1;
+                expect_word(token_table, cursor, "NOTHING")?;
+                Ok(MergeAction::DoNothing)
+            }
+            (MergeMatch::Matched, _) => Err(syntax_error(
+                token_table,
+                cursor,
+                &[
+                    TokenKind::Keyword(Keyword::Update),
+                    TokenKind::Keyword(Keyword::Delete),
+                    TokenKind::Identifier,
+                ],
+            )),
+            (MergeMatch::NotMatched, _) => Err(syntax_error(
+                token_table,
+                cursor,
+                &[TokenKind::Keyword(Keyword::Insert), TokenKind::Identifier],
+            )),
+        }
+    }
+}
+
+/// `MERGE INTO target USING source ON condition WHEN ... THEN ...`
+#[derive(Debug, PartialEq, Clone)]
+pub struct MergeStatement<'a> {
+    pub target: Table<'a>,
+    /// The joined source, a table or a parenthesized query.
+    pub source: Table<'a>,
+    pub on: Expr<'a>,
+    pub clauses: MiniVec<MergeClause<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for MergeStatement<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> MergeStatement<'a> {
+    pub(crate) fn new(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        Self::build_ast(token_table, cursor)
+    }
+
+    fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
+        let start = *cursor;
+        expect_word(token_table, cursor, "MERGE")?;
+
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Into))?;
+        *cursor += 1;
+        let target = Table::build(token_table, cursor)?;
+
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Using))?;
+        *cursor += 1;
+        let source = Table::build(token_table, cursor)?;
+
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::On))?;
+        *cursor += 1;
+        let on = Expr::build(token_table, cursor)?;
+
+        // 至少要有一个 WHEN 子句
+        let mut clauses = MiniVec::new();
+        loop {
+            clauses.push(MergeClause::build(token_table, cursor)?);
+            if !maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::When)) {
+                break;
+            }
+        }
+
+        Ok(Self {
+            target,
+            source,
+            on,
+            clauses,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
@@ -2,6 +2,7 @@ pub mod cte;
 pub mod ddl;
 pub mod delete;
 pub mod insert;
+pub mod merge;
 pub mod query;
 pub mod select;
 pub mod statement;
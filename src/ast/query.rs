@@ -1,9 +1,11 @@
+use std::convert::Infallible;
+
 use minivec::MiniVec;
 
 use crate::error::ParserError;
 use crate::{
     SelectStatement,
-    ast::cte::CteBinding,
+    ast::cte::{Cte, CteBinding},
     common::{
         limit::Limit,
         order::Order,
@@ -11,10 +13,11 @@ use crate::{
         utils::{expect_kind, maybe_kind},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum SetOperator {
     Union,
     UnionAll,
@@ -40,12 +43,13 @@ impl PrecedenceTrait for SetOperator {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Query<'a> {
     Select(SelectStatement<'a>),
     Cte {
         ctes: MiniVec<CteBinding<'a>>,
         query: Box<Query<'a>>,
+        span: Span,
     },
     SetOperation {
         op: SetOperator,
@@ -56,7 +60,49 @@ pub enum Query<'a> {
     },
 }
 
+impl<'a> Spanned for Query<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Query::Select(select) => select.span,
+            Query::Cte { span, .. } => *span,
+            Query::SetOperation {
+                left,
+                right,
+                order_by,
+                limit,
+                ..
+            } => {
+                let mut span = left.span().union(right.span());
+                if let Some(order_by) = order_by {
+                    span = span.union(order_by.span);
+                }
+                if let Some(limit) = limit {
+                    span = span.union(limit.span);
+                }
+                span
+            }
+        }
+    }
+}
+
 impl<'a> Query<'a> {
+    /// 可带 WITH 前缀的完整查询
+    pub(crate) fn parse(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        if !maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::With)) {
+            return Self::build(token_table, cursor);
+        }
+        let cte = Cte::build(token_table, cursor)?;
+        let query = Self::build(token_table, cursor)?;
+        Ok(Self::Cte {
+            span: cte.span.union(query.span()),
+            ctes: cte.bindings,
+            query: Box::new(query),
+        })
+    }
+
     pub(crate) fn build(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
@@ -90,6 +136,7 @@ impl<'a> Query<'a> {
 
 impl<'a> PrattParserTrait<'a> for Query<'a> {
     type Item = SetOperator;
+    type Prefix = Infallible;
     type Output = Self;
 
     fn match_item(token_table: &TokenTable, cursor: &mut usize) -> Option<Self::Item> {
@@ -125,12 +172,12 @@ impl<'a> PrattParserTrait<'a> for Query<'a> {
             }
             Some(TokenKind::LeftParen) => {
                 *cursor += 1;
-                let query = Self::build(token_table, cursor)?;
+                let query = Self::parse(token_table, cursor)?;
                 expect_kind(token_table, cursor, &TokenKind::RightParen)?;
                 *cursor += 1;
                 Ok(query)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 
@@ -138,6 +185,7 @@ impl<'a> PrattParserTrait<'a> for Query<'a> {
         left: Self::Output,
         _token_table: &TokenTable<'a>,
         _cursor: &mut usize,
+        _min_precedence: usize,
     ) -> Result<(Self::Output, Flow), ParserError> {
         Ok((left, Flow::Run))
     }
@@ -2,20 +2,22 @@ use minivec::MiniVec;
 
 use crate::{
     ParserError,
+    ast::query::Query,
     common::{
         alias::{Alias, Aliasable},
-        expr::Expr,
+        expr::{Expr, NamedWindow},
         from::From,
         group::Group,
         limit::Limit,
         order::Order,
-        utils::{expect_kind, maybe_kind},
+        utils::{expect_kind, is_word, maybe_kind, starts_clause},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct SelectStatement<'a> {
     pub distinct: bool,
     pub columns: Vec<Alias<'a, Expr<'a>>>,
@@ -23,8 +25,17 @@ pub struct SelectStatement<'a> {
     pub where_statement: Option<Expr<'a>>,
     pub group_by: Option<Group<'a>>,
     pub having_statement: Option<Expr<'a>>,
+    /// `WINDOW w AS (...)`, windows referenced by name from `OVER w`.
+    pub window: Option<MiniVec<NamedWindow<'a>>>,
     pub order_by: Option<Order<'a>>,
     pub limit: Option<Limit<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for SelectStatement<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> SelectStatement<'a> {
@@ -32,18 +43,11 @@ impl<'a> SelectStatement<'a> {
         Self::build_ast(token_table, cursor)
     }
 
-    fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
-        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Select))?;
-        *cursor += 1;
-
-        let distinct =
-            if let Some(TokenKind::Keyword(Keyword::Distinct)) = token_table.get_kind(*cursor) {
-                *cursor += 1;
-                true
-            } else {
-                false
-            };
-
+    /// SELECT 的列表，遇到关键字、右括号或语句结束为止
+    pub(crate) fn parse_columns(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Vec<Alias<'a, Expr<'a>>>, ParserError> {
         let mut columns = Vec::new();
         loop {
             match token_table.get_kind(*cursor) {
@@ -51,7 +55,15 @@ impl<'a> SelectStatement<'a> {
                     *cursor += 1;
                 }
                 Some(TokenKind::Delimiter | TokenKind::RightParen) => break,
-                Some(TokenKind::Keyword(Keyword::Case)) | Some(TokenKind::Keyword(Keyword::True)) | Some(TokenKind::Keyword(Keyword::False)) | Some(TokenKind::Keyword(Keyword::Null)) => {
+                Some(TokenKind::Identifier) if starts_clause(token_table, *cursor) => break,
+                Some(TokenKind::Keyword(
+                    Keyword::Case
+                    | Keyword::True
+                    | Keyword::False
+                    | Keyword::Null
+                    | Keyword::Not
+                    | Keyword::Exists,
+                )) => {
                     let expr = Alias::new(token_table, cursor)?;
                     columns.push(expr);
                 }
@@ -67,6 +79,23 @@ impl<'a> SelectStatement<'a> {
                 None => break,
             }
         }
+        Ok(columns)
+    }
+
+    fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Select))?;
+        let start = *cursor;
+        *cursor += 1;
+
+        let distinct =
+            if let Some(TokenKind::Keyword(Keyword::Distinct)) = token_table.get_kind(*cursor) {
+                *cursor += 1;
+                true
+            } else {
+                false
+            };
+
+        let columns = Self::parse_columns(token_table, cursor)?;
 
         let from = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From)) {
             *cursor += 1;
@@ -80,6 +109,7 @@ impl<'a> SelectStatement<'a> {
                     Some(TokenKind::RightParen)
                     | Some(TokenKind::Keyword(_))
                     | Some(TokenKind::Delimiter) => break,
+                    Some(TokenKind::Identifier) if starts_clause(token_table, *cursor) => break,
                     Some(_) => {
                         list.push(From::parse(token_table, cursor)?);
                     }
@@ -87,7 +117,7 @@ impl<'a> SelectStatement<'a> {
                 }
             }
             if list.is_empty() {
-                return Err(ParserError::SyntaxError(*cursor, *cursor));
+                return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
             }
             Some(list)
         } else {
@@ -116,6 +146,22 @@ impl<'a> SelectStatement<'a> {
                 None
             };
 
+        let window = if is_word(token_table, *cursor, "WINDOW") {
+            *cursor += 1;
+            let mut windows = MiniVec::new();
+            loop {
+                windows.push(NamedWindow::build(token_table, cursor)?);
+                if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                    *cursor += 1;
+                } else {
+                    break;
+                }
+            }
+            Some(windows)
+        } else {
+            None
+        };
+
         let order_by = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Order)) {
             Some(Order::build(token_table, cursor)?)
         } else {
@@ -134,22 +180,42 @@ impl<'a> SelectStatement<'a> {
             where_statement,
             group_by,
             having_statement,
+            window,
             order_by,
             limit,
             distinct,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
 
-pub type SubSelectStatement<'a> = Box<SelectStatement<'a>>;
+/// `RETURNING a, b AS c`，列表写法与 SELECT 列相同。
+pub(crate) fn parse_returning<'a>(
+    token_table: &TokenTable<'a>,
+    cursor: &mut usize,
+) -> Result<Option<Vec<Alias<'a, Expr<'a>>>>, ParserError> {
+    // RETURNING 不是保留字，按名字匹配
+    if !is_word(token_table, *cursor, "RETURNING") {
+        return Ok(None);
+    }
+    *cursor += 1;
+    let columns = SelectStatement::parse_columns(token_table, cursor)?;
+    if columns.is_empty() {
+        return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+    }
+    Ok(Some(columns))
+}
+
+/// A query in a subquery position: a derived table, `IN (...)` or `EXISTS (...)`.
+pub type SubSelectStatement<'a> = Box<Query<'a>>;
 
 impl<'a> Aliasable<'a> for SubSelectStatement<'a> {
     fn aliasable(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
         *cursor += 1;
-        let select_stmt = SelectStatement::new(token_table, cursor)?;
+        let query = Query::parse(token_table, cursor)?;
         expect_kind(token_table, cursor, &TokenKind::RightParen)?;
         *cursor += 1;
-        Ok(Box::new(select_stmt))
+        Ok(Box::new(query))
     }
 }
@@ -1,17 +1,61 @@
-use super::{ddl::DdlStatement, insert::InsertStatement, update::UpdateStatement};
+use super::{
+    ddl::DdlStatement, insert::InsertStatement, merge::MergeStatement, update::UpdateStatement,
+};
 use crate::{
-    ast::{cte::Cte, delete::DeleteStatement, query::Query},
+    ast::{delete::DeleteStatement, query::Query},
+    common::{
+        expr::{Expr, Placeholder},
+        utils::{is_word, syntax_error},
+        walk::WalkExpr,
+    },
     error::ParserError,
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+/// Keywords that can begin a statement, reported when none of them is found.
+const STATEMENT_START: &[TokenKind] = &[
+    TokenKind::Keyword(Keyword::Select),
+    TokenKind::Keyword(Keyword::With),
+    TokenKind::Keyword(Keyword::Insert),
+    TokenKind::Keyword(Keyword::Update),
+    TokenKind::Keyword(Keyword::Delete),
+    TokenKind::Identifier,
+    TokenKind::Keyword(Keyword::Create),
+    TokenKind::Keyword(Keyword::Drop),
+    TokenKind::Keyword(Keyword::Alter),
+];
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Statement<'a> {
     pub list: Vec<StatementInner<'a>>,
 }
 
+impl<'a> Spanned for Statement<'a> {
+    fn span(&self) -> Span {
+        self.list
+            .iter()
+            .map(Spanned::span)
+            .reduce(Span::union)
+            .unwrap_or_default()
+    }
+}
+
 impl<'a> Statement<'a> {
+    /// Every bind parameter of the statement list, in source order.
+    pub fn placeholders(&self) -> Vec<&Placeholder<'a>> {
+        let mut placeholders = Vec::new();
+        self.walk_expr(&mut |expr| {
+            if let Expr::Placeholder(placeholder) = expr {
+                placeholders.push(placeholder);
+            }
+        });
+        // 遍历顺序基本与源码一致，再按位置排序保证结果稳定
+        placeholders.sort_by_key(|placeholder| placeholder.span.start);
+        placeholders
+    }
+
     pub(crate) fn new(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         let mut list = Vec::new();
         loop {
@@ -31,20 +75,94 @@ impl<'a> Statement<'a> {
         }
 
         if list.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
 
         Ok(Self { list })
     }
+
+    /// Like [`Statement::new`], but a failing statement does not stop the parse:
+    /// its error is collected, the tokens up to the next `;` are skipped and
+    /// parsing resumes after it. With `with_placeholders` every skipped
+    /// statement leaves a [`StatementInner::Error`] covering the skipped tokens.
+    pub(crate) fn new_recovering(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        with_placeholders: bool,
+        errors: &mut Vec<ParserError>,
+    ) -> Self {
+        let mut list = Vec::new();
+        loop {
+            match token_table.get_kind(*cursor) {
+                Some(TokenKind::Delimiter) => {
+                    *cursor += 1;
+                    continue;
+                }
+                None => {
+                    break;
+                }
+                _ => {
+                    let start = *cursor;
+                    match StatementInner::new(token_table, cursor) {
+                        Ok(inner) => list.push(inner),
+                        Err(err) => {
+                            // 词法阶段已经报告过的位置不再重复报告
+                            let reported = err.span().is_some_and(|span| {
+                                errors.iter().any(|prev| prev.span() == Some(span))
+                            });
+                            if !reported {
+                                errors.push(err);
+                            }
+                            *cursor = (*cursor).max(start);
+                            while let Some(kind) = token_table.get_kind(*cursor) {
+                                if *kind == TokenKind::Delimiter {
+                                    break;
+                                }
+                                *cursor += 1;
+                            }
+                            if with_placeholders {
+                                list.push(StatementInner::Error(
+                                    token_table.span_between(start, *cursor),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if list.is_empty() && errors.is_empty() {
+            errors.push(ParserError::SyntaxError(token_table.span_at(*cursor)));
+        }
+
+        Self { list }
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum StatementInner<'a> {
     Query(Query<'a>),
     Insert(InsertStatement<'a>),
     Update(UpdateStatement<'a>),
     Delete(DeleteStatement<'a>),
+    Merge(MergeStatement<'a>),
     Ddl(DdlStatement<'a>),
+    /// Placeholder left by a recovering parse where a statement failed to parse.
+    Error(Span),
+}
+
+impl<'a> Spanned for StatementInner<'a> {
+    fn span(&self) -> Span {
+        match self {
+            StatementInner::Query(query) => query.span(),
+            StatementInner::Insert(insert) => insert.span,
+            StatementInner::Update(update) => update.span,
+            StatementInner::Delete(delete) => delete.span,
+            StatementInner::Merge(merge) => merge.span,
+            StatementInner::Ddl(ddl) => ddl.span(),
+            StatementInner::Error(span) => *span,
+        }
+    }
 }
 
 impl<'a> StatementInner<'a> {
@@ -57,16 +175,8 @@ impl<'a> StatementInner<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         match token_table.get_kind(*cursor) {
-            Some(TokenKind::Keyword(Keyword::With)) => {
-                let cte = Cte::build(token_table, cursor)?;
-                let query = Query::build(token_table, cursor)?;
-                Ok(Self::Query(Query::Cte {
-                    ctes: cte.bindings,
-                    query: Box::new(query),
-                }))
-            }
-            Some(TokenKind::Keyword(Keyword::Select)) => {
-                Query::build(token_table, cursor).map(Self::Query)
+            Some(TokenKind::Keyword(Keyword::With | Keyword::Select)) => {
+                Query::parse(token_table, cursor).map(Self::Query)
             }
             Some(TokenKind::Keyword(Keyword::Insert)) => {
                 Ok(Self::Insert(InsertStatement::new(token_table, cursor)?))
@@ -77,12 +187,16 @@ impl<'a> StatementInner<'a> {
             Some(TokenKind::Keyword(Keyword::Delete)) => {
                 Ok(Self::Delete(DeleteStatement::new(token_table, cursor)?))
             }
+            // MERGE 不是保留字，按名字匹配
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "MERGE") => {
+                Ok(Self::Merge(MergeStatement::new(token_table, cursor)?))
+            }
             Some(TokenKind::Keyword(Keyword::Create))
             | Some(TokenKind::Keyword(Keyword::Drop))
             | Some(TokenKind::Keyword(Keyword::Alter)) => {
                 DdlStatement::build(token_table, cursor).map(Self::Ddl)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(syntax_error(token_table, cursor, STATEMENT_START)),
         }
     }
 }
@@ -2,20 +2,111 @@ use minivec::MiniVec;
 
 use crate::{
     ParserError,
+    ast::select::parse_returning,
     common::{
-        expr::Expr,
+        alias::Alias,
+        expr::{Expr, Field},
         from::From,
         utils::{expect_kind, maybe_kind},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+/// The left side of a `SET` assignment.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AssignmentTarget<'a> {
+    /// `a = ...` or `t.a = ...`
+    Column(Field<'a>),
+    /// `(a, b) = (SELECT ...)`
+    Tuple(MiniVec<Field<'a>>),
+}
+
+/// `target = value` in `UPDATE ... SET` and upsert clauses.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Assignment<'a> {
+    pub target: AssignmentTarget<'a>,
+    /// The assigned value, `Expr::Default` for `DEFAULT`.
+    pub value: Expr<'a>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for Assignment<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> Assignment<'a> {
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        let start = *cursor;
+        let target = if maybe_kind(token_table, cursor, &TokenKind::LeftParen) {
+            *cursor += 1;
+            let mut columns = MiniVec::new();
+            loop {
+                columns.push(Field::from_token(token_table, cursor)?);
+                if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                    *cursor += 1;
+                } else {
+                    break;
+                }
+            }
+            expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+            *cursor += 1;
+            AssignmentTarget::Tuple(columns)
+        } else {
+            AssignmentTarget::Column(Field::from_token(token_table, cursor)?)
+        };
+
+        expect_kind(token_table, cursor, &TokenKind::Equal)?;
+        *cursor += 1;
+        let value = Expr::build_or_default(token_table, cursor)?;
+
+        Ok(Self {
+            target,
+            value,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    /// 逗号分隔的赋值列表
+    pub(crate) fn build_list(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<MiniVec<Self>, ParserError> {
+        let mut assignments = MiniVec::with_capacity(8);
+        loop {
+            assignments.push(Self::build(token_table, cursor)?);
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        Ok(assignments)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct UpdateStatement<'a> {
     pub table: From<'a>,
-    pub assignments: MiniVec<Expr<'a>>,
+    pub assignments: MiniVec<Assignment<'a>>,
+    /// PostgreSQL `FROM` list joined into the update.
+    pub from: Option<MiniVec<From<'a>>>,
     pub where_statement: Option<Expr<'a>>,
+    /// `RETURNING ...`
+    pub returning: Option<Vec<Alias<'a, Expr<'a>>>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for UpdateStatement<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> UpdateStatement<'a> {
@@ -25,6 +116,7 @@ impl<'a> UpdateStatement<'a> {
 
     fn build_ast(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Update))?;
+        let start = *cursor;
         *cursor += 1;
 
         let table = From::parse(token_table, cursor)?;
@@ -32,21 +124,14 @@ impl<'a> UpdateStatement<'a> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Set))?;
         *cursor += 1;
 
-        let mut assignments = MiniVec::with_capacity(8);
-        loop {
-            match token_table.get_kind(*cursor) {
-                Some(TokenKind::Comma) => {
-                    *cursor += 1;
-                }
-                Some(TokenKind::Keyword(_)) => {
-                    break;
-                }
-                Some(_) => {
-                    assignments.push(Expr::build(token_table, cursor)?);
-                }
-                None => break,
-            }
-        }
+        let assignments = Assignment::build_list(token_table, cursor)?;
+
+        let from = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From)) {
+            *cursor += 1;
+            Some(From::parse_list(token_table, cursor)?)
+        } else {
+            None
+        };
 
         let where_statement =
             if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Where)) {
@@ -56,10 +141,15 @@ impl<'a> UpdateStatement<'a> {
                 None
             };
 
+        let returning = parse_returning(token_table, cursor)?;
+
         Ok(Self {
             table,
             assignments,
+            from,
             where_statement,
+            returning,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
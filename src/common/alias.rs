@@ -1,7 +1,9 @@
 
 use crate::{
     ParserError,
+    common::{ident::Ident, utils::starts_clause},
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
@@ -9,10 +11,17 @@ pub trait Aliasable<'a>: Sized {
     fn aliasable(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError>;
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Alias<'a, T> {
-    pub name: Option<&'a str>,
+    pub name: Option<Ident<'a>>,
     pub value: T,
+    pub span: Span,
+}
+
+impl<'a, T> Spanned for Alias<'a, T> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a, T> Alias<'a, T>
@@ -23,30 +32,41 @@ where
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
+        let start = *cursor;
         let value = T::aliasable(token_table, cursor)?;
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Keyword(Keyword::As)) => {
                 *cursor += 1;
-                if let Some(TokenKind::Identifier) = token_table.get_kind(*cursor) {
-                    let name = token_table.source_at(*cursor);
+                if let Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) =
+                    token_table.get_kind(*cursor)
+                {
+                    let name = Ident::from_token(token_table, cursor)?;
                     *cursor += 1;
                     Ok(Alias {
                         name: Some(name),
                         value,
+                        span: token_table.span_between(start, *cursor),
                     })
                 } else {
-                    Err(ParserError::SyntaxError(*cursor, *cursor))
+                    Err(ParserError::SyntaxError(token_table.span_at(*cursor)))
                 }
             }
-            Some(TokenKind::Identifier) => {
-                let name = token_table.source_at(*cursor);
+            Some(TokenKind::Identifier | TokenKind::QuotedIdentifier)
+                if !starts_clause(token_table, *cursor) =>
+            {
+                let name = Ident::from_token(token_table, cursor)?;
                 *cursor += 1;
                 Ok(Alias {
                     name: Some(name),
                     value,
+                    span: token_table.span_between(start, *cursor),
                 })
             }
-            _ => Ok(Alias { name: None, value }),
+            _ => Ok(Alias {
+                name: None,
+                value,
+                span: token_table.span_between(start, *cursor),
+            }),
         }
     }
 }
@@ -0,0 +1,382 @@
+use crate::{
+    ParserError,
+    common::utils::{expect_kind, expect_word, is_word, maybe_kind},
+    keyword::Keyword,
+    span::{Span, Spanned},
+    token::{TokenKind, TokenTable},
+};
+
+/// A data type as written in `CAST`, typed literals and column definitions,
+/// e.g. `INT` or `DECIMAL(12, 2)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataType<'a> {
+    pub kind: DataTypeKind<'a>,
+    pub span: Span,
+}
+
+/// The type itself, with synonyms folded together so that e.g. `INTEGER` and
+/// `INT4` compare equal. MySQL display widths such as `INT(11)` are accepted
+/// and dropped.
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataTypeKind<'a> {
+    /// `BOOLEAN`, `BOOL`
+    Boolean,
+    /// `TINYINT`
+    TinyInt { unsigned: bool },
+    /// `SMALLINT`, `INT2`
+    SmallInt { unsigned: bool },
+    /// `INT`, `INTEGER`, `INT4`
+    Int { unsigned: bool },
+    /// `BIGINT`, `INT8`
+    BigInt { unsigned: bool },
+    /// `REAL`, `FLOAT4`
+    Real,
+    /// `DOUBLE`, `DOUBLE PRECISION`, `FLOAT8`
+    Double,
+    /// `FLOAT[(p)]`
+    Float { precision: Option<u64> },
+    /// `DECIMAL[(p[, s])]`, `NUMERIC`, `DEC`
+    Decimal {
+        precision: Option<u64>,
+        scale: Option<u64>,
+    },
+    /// `CHAR[(n)]`, `CHARACTER[(n)]`
+    Char { length: Option<u64> },
+    /// `VARCHAR[(n)]`, `CHARACTER VARYING[(n)]`, `CHAR VARYING[(n)]`
+    Varchar { length: Option<u64> },
+    /// `TEXT`
+    Text,
+    /// `BINARY[(n)]`
+    Binary { length: Option<u64> },
+    /// `VARBINARY[(n)]`
+    Varbinary { length: Option<u64> },
+    /// `BLOB`
+    Blob,
+    /// `DATE`
+    Date,
+    /// `TIME[(p)] [WITH | WITHOUT TIME ZONE]`, `TIMETZ`
+    Time {
+        precision: Option<u64>,
+        with_time_zone: bool,
+    },
+    /// `TIMESTAMP[(p)] [WITH | WITHOUT TIME ZONE]`, `TIMESTAMPTZ`
+    Timestamp {
+        precision: Option<u64>,
+        with_time_zone: bool,
+    },
+    /// MySQL `DATETIME[(p)]`
+    Datetime { precision: Option<u64> },
+    /// `INTERVAL`
+    Interval,
+    /// `JSON`
+    Json,
+    /// `JSONB`
+    Jsonb,
+    /// `UUID`
+    Uuid,
+    /// MySQL `ENUM('a', 'b')`, values as written with quotes.
+    Enum(Vec<&'a str>),
+    /// `INT[]`, `INT[3]` or `INT ARRAY`; `INT[][]` nests.
+    Array {
+        element: Box<DataTypeKind<'a>>,
+        size: Option<u64>,
+    },
+    /// Any other type, e.g. `geometry(Point, 4326)`.
+    Custom { name: &'a str, args: Vec<&'a str> },
+}
+
+impl<'a> Spanned for DataType<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> DataType<'a> {
+    pub(crate) fn parse(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Identifier)?;
+        let start = *cursor;
+
+        // MySQL 允许 UNSIGNED 写在类型前面；单独的 UNSIGNED 按自定义类型处理
+        let unsigned_prefix = is_word(token_table, *cursor, "UNSIGNED")
+            && token_table.get_kind(*cursor + 1) == Some(&TokenKind::Identifier);
+        if unsigned_prefix {
+            *cursor += 1;
+        }
+
+        let mut kind = Self::parse_kind(token_table, cursor)?;
+
+        let unsigned = unsigned_prefix || is_word(token_table, *cursor, "UNSIGNED");
+        if unsigned {
+            match &mut kind {
+                DataTypeKind::TinyInt { unsigned }
+                | DataTypeKind::SmallInt { unsigned }
+                | DataTypeKind::Int { unsigned }
+                | DataTypeKind::BigInt { unsigned } => *unsigned = true,
+                _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+            }
+            if !unsigned_prefix {
+                *cursor += 1;
+            }
+        }
+
+        loop {
+            if let Some(size) = Self::parse_array_suffix(token_table, cursor)? {
+                kind = DataTypeKind::Array {
+                    element: Box::new(kind),
+                    size,
+                };
+            } else if is_word(token_table, *cursor, "ARRAY") {
+                *cursor += 1;
+                let size = Self::parse_array_suffix(token_table, cursor)?.flatten();
+                kind = DataTypeKind::Array {
+                    element: Box::new(kind),
+                    size,
+                };
+            } else {
+                break;
+            }
+        }
+
+        Ok(Self {
+            kind,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    fn parse_kind(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<DataTypeKind<'a>, ParserError> {
+        let name = token_table.source_at(*cursor);
+        *cursor += 1;
+
+        let upper = name.to_ascii_uppercase();
+        let kind = match upper.as_str() {
+            "BOOLEAN" | "BOOL" => DataTypeKind::Boolean,
+            "TINYINT" | "SMALLINT" | "INT2" | "INT" | "INTEGER" | "INT4" | "BIGINT" | "INT8" => {
+                // 显示宽度不影响类型本身
+                Self::parse_lengths(token_table, cursor, 1)?;
+                match upper.as_str() {
+                    "TINYINT" => DataTypeKind::TinyInt { unsigned: false },
+                    "SMALLINT" | "INT2" => DataTypeKind::SmallInt { unsigned: false },
+                    "BIGINT" | "INT8" => DataTypeKind::BigInt { unsigned: false },
+                    _ => DataTypeKind::Int { unsigned: false },
+                }
+            }
+            "REAL" | "FLOAT4" => DataTypeKind::Real,
+            "DOUBLE" => {
+                if is_word(token_table, *cursor, "PRECISION") {
+                    *cursor += 1;
+                }
+                DataTypeKind::Double
+            }
+            "FLOAT8" => DataTypeKind::Double,
+            "FLOAT" => DataTypeKind::Float {
+                precision: Self::parse_length(token_table, cursor)?,
+            },
+            "DECIMAL" | "NUMERIC" | "DEC" => {
+                let lengths = Self::parse_lengths(token_table, cursor, 2)?;
+                DataTypeKind::Decimal {
+                    precision: lengths.first().copied(),
+                    scale: lengths.get(1).copied(),
+                }
+            }
+            "CHAR" | "CHARACTER" => {
+                if is_word(token_table, *cursor, "VARYING") {
+                    *cursor += 1;
+                    DataTypeKind::Varchar {
+                        length: Self::parse_length(token_table, cursor)?,
+                    }
+                } else {
+                    DataTypeKind::Char {
+                        length: Self::parse_length(token_table, cursor)?,
+                    }
+                }
+            }
+            "VARCHAR" => DataTypeKind::Varchar {
+                length: Self::parse_length(token_table, cursor)?,
+            },
+            "TEXT" => DataTypeKind::Text,
+            "BINARY" => DataTypeKind::Binary {
+                length: Self::parse_length(token_table, cursor)?,
+            },
+            "VARBINARY" => DataTypeKind::Varbinary {
+                length: Self::parse_length(token_table, cursor)?,
+            },
+            "BLOB" => DataTypeKind::Blob,
+            "DATE" => DataTypeKind::Date,
+            "TIME" | "TIMESTAMP" => {
+                let precision = Self::parse_length(token_table, cursor)?;
+                let with_time_zone = Self::parse_time_zone(token_table, cursor)?;
+                if upper == "TIME" {
+                    DataTypeKind::Time {
+                        precision,
+                        with_time_zone,
+                    }
+                } else {
+                    DataTypeKind::Timestamp {
+                        precision,
+                        with_time_zone,
+                    }
+                }
+            }
+            "TIMETZ" => DataTypeKind::Time {
+                precision: Self::parse_length(token_table, cursor)?,
+                with_time_zone: true,
+            },
+            "TIMESTAMPTZ" => DataTypeKind::Timestamp {
+                precision: Self::parse_length(token_table, cursor)?,
+                with_time_zone: true,
+            },
+            "DATETIME" => DataTypeKind::Datetime {
+                precision: Self::parse_length(token_table, cursor)?,
+            },
+            "INTERVAL" => DataTypeKind::Interval,
+            "JSON" => DataTypeKind::Json,
+            "JSONB" => DataTypeKind::Jsonb,
+            "UUID" => DataTypeKind::Uuid,
+            "ENUM" => DataTypeKind::Enum(Self::parse_enum_values(token_table, cursor)?),
+            _ => DataTypeKind::Custom {
+                name,
+                args: Self::parse_args(token_table, cursor)?,
+            },
+        };
+        Ok(kind)
+    }
+
+    // 可选的数组后缀 `[]` 或 `[n]`；没有后缀时返回 None
+    fn parse_array_suffix(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Option<Option<u64>>, ParserError> {
+        if !maybe_kind(token_table, cursor, &TokenKind::LeftBracket) {
+            return Ok(None);
+        }
+        *cursor += 1;
+        let size = if maybe_kind(token_table, cursor, &TokenKind::Number) {
+            let size = token_table
+                .source_at(*cursor)
+                .parse()
+                .map_err(|_| ParserError::SyntaxError(token_table.span_at(*cursor)))?;
+            *cursor += 1;
+            Some(size)
+        } else {
+            None
+        };
+        expect_kind(token_table, cursor, &TokenKind::RightBracket)?;
+        *cursor += 1;
+        Ok(Some(size))
+    }
+
+    // [WITH | WITHOUT] TIME ZONE
+    fn parse_time_zone(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<bool, ParserError> {
+        let with_time_zone = match token_table.get_kind(*cursor) {
+            // WITH 之后不是 TIME 时可能是别的子句，不消耗
+            Some(TokenKind::Keyword(Keyword::With))
+                if is_word(token_table, *cursor + 1, "TIME") =>
+            {
+                true
+            }
+            Some(TokenKind::Identifier) if is_word(token_table, *cursor, "WITHOUT") => false,
+            _ => return Ok(false),
+        };
+        *cursor += 1;
+        expect_word(token_table, cursor, "TIME")?;
+        expect_word(token_table, cursor, "ZONE")?;
+        Ok(with_time_zone)
+    }
+
+    fn parse_length(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Option<u64>, ParserError> {
+        Ok(Self::parse_lengths(token_table, cursor, 1)?
+            .first()
+            .copied())
+    }
+
+    // 可选的 (n[, m ...])，最多 max 个数字
+    fn parse_lengths(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        max: usize,
+    ) -> Result<Vec<u64>, ParserError> {
+        let mut lengths = Vec::new();
+        if !maybe_kind(token_table, cursor, &TokenKind::LeftParen) {
+            return Ok(lengths);
+        }
+        *cursor += 1;
+        loop {
+            expect_kind(token_table, cursor, &TokenKind::Number)?;
+            let length = token_table
+                .source_at(*cursor)
+                .parse()
+                .map_err(|_| ParserError::SyntaxError(token_table.span_at(*cursor)))?;
+            lengths.push(length);
+            *cursor += 1;
+            if lengths.len() < max && maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(lengths)
+    }
+
+    fn parse_enum_values(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Vec<&'a str>, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let mut values = Vec::new();
+        loop {
+            expect_kind(token_table, cursor, &TokenKind::StringLiteral)?;
+            values.push(token_table.source_at(*cursor));
+            *cursor += 1;
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(values)
+    }
+
+    fn parse_args(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Vec<&'a str>, ParserError> {
+        let mut args = Vec::new();
+        if maybe_kind(token_table, cursor, &TokenKind::LeftParen) {
+            *cursor += 1;
+            loop {
+                match token_table.get_kind(*cursor) {
+                    Some(TokenKind::Number | TokenKind::Identifier | TokenKind::StringLiteral) => {
+                        args.push(token_table.source_at(*cursor));
+                        *cursor += 1;
+                    }
+                    _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+                }
+                if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                    *cursor += 1;
+                } else {
+                    break;
+                }
+            }
+            expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+            *cursor += 1;
+        }
+        Ok(args)
+    }
+}
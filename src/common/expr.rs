@@ -1,19 +1,25 @@
 use minivec::MiniVec;
 
 use crate::{
-    ParserError, SelectStatement,
-    ast::select::SubSelectStatement,
+    ParserError,
+    ast::{query::Query, select::SubSelectStatement},
     common::{
         alias::Aliasable,
+        data_type::{DataType, DataTypeKind},
+        ident::Ident,
+        limit::Limit,
         order::Order,
-        pratt_parser::{Flow, PrattOutput, PrattParser, PrattParserTrait, PrecedenceTrait},
-        utils::{expect_kind, maybe_kind},
+        pratt_parser::{
+            Flow, Infix, PrattOutput, PrattParser, PrattParserTrait, PrattPrefix, PrecedenceTrait,
+        },
+        utils::{expect_kind, expect_word, is_word, maybe_kind, syntax_error},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum BinaryOperator {
     Add,
     Subtract,
@@ -27,6 +33,7 @@ pub enum BinaryOperator {
     Greater,
     GreaterEqual,
     BitAnd,
+    BitOr,
     BitXor,
     LeftShift,
     RightShift,
@@ -36,9 +43,39 @@ pub enum BinaryOperator {
     In,
     Like,
     Not,
+    /// `||`, string concatenation.
+    StringConcat,
+    /// `->`, JSON field or element.
+    JsonGet,
+    /// `->>`, JSON field or element as text.
+    JsonGetText,
+    /// `#>`, JSON value at a path.
+    JsonGetPath,
+    /// `#>>`, JSON value at a path as text.
+    JsonGetPathText,
+    /// `@>`, left contains right.
+    Contains,
+    /// `<@`, left is contained by right.
+    ContainedBy,
+    /// `<=>`, MySQL null-safe equality.
+    NullSafeEqual,
+    /// `~`, case-sensitive regex match.
+    RegexMatch,
+    /// `~*`, case-insensitive regex match.
+    RegexIMatch,
+    /// `!~`, negated case-sensitive regex match.
+    RegexNotMatch,
+    /// `!~*`, negated case-insensitive regex match.
+    RegexNotIMatch,
+    /// `IS DISTINCT FROM`
+    IsDistinctFrom,
+    /// `IS NOT DISTINCT FROM`
+    IsNotDistinctFrom,
 }
 
 impl BinaryOperator {
+    /// Maps an infix operator token to its operator. `BETWEEN`, `IN`, `LIKE`
+    /// and the other postfix forms are handled by `parse_postfix` instead.
     pub(crate) fn from_token_kind(kind: &TokenKind) -> Option<Self> {
         match kind {
             TokenKind::Plus => Some(BinaryOperator::Add),
@@ -53,14 +90,24 @@ impl BinaryOperator {
             TokenKind::Greater => Some(BinaryOperator::Greater),
             TokenKind::GreaterEqual => Some(BinaryOperator::GreaterEqual),
             TokenKind::BitAnd => Some(BinaryOperator::BitAnd),
+            TokenKind::BitOr => Some(BinaryOperator::BitOr),
             TokenKind::BitXor => Some(BinaryOperator::BitXor),
             TokenKind::LeftShift => Some(BinaryOperator::LeftShift),
             TokenKind::RightShift => Some(BinaryOperator::RightShift),
+            TokenKind::Concat => Some(BinaryOperator::StringConcat),
+            TokenKind::Arrow => Some(BinaryOperator::JsonGet),
+            TokenKind::LongArrow => Some(BinaryOperator::JsonGetText),
+            TokenKind::HashArrow => Some(BinaryOperator::JsonGetPath),
+            TokenKind::HashLongArrow => Some(BinaryOperator::JsonGetPathText),
+            TokenKind::AtArrow => Some(BinaryOperator::Contains),
+            TokenKind::ArrowAt => Some(BinaryOperator::ContainedBy),
+            TokenKind::Spaceship => Some(BinaryOperator::NullSafeEqual),
+            TokenKind::Tilde => Some(BinaryOperator::RegexMatch),
+            TokenKind::TildeStar => Some(BinaryOperator::RegexIMatch),
+            TokenKind::ExclamationTilde => Some(BinaryOperator::RegexNotMatch),
+            TokenKind::ExclamationTildeStar => Some(BinaryOperator::RegexNotIMatch),
             TokenKind::Keyword(Keyword::And) => Some(BinaryOperator::And),
             TokenKind::Keyword(Keyword::Or) => Some(BinaryOperator::Or),
-            TokenKind::Keyword(Keyword::Between) => Some(BinaryOperator::Between),
-            TokenKind::Keyword(Keyword::In) => Some(BinaryOperator::In),
-            TokenKind::Keyword(Keyword::Like) => Some(BinaryOperator::Like),
             _ => None,
         }
     }
@@ -71,11 +118,17 @@ impl PrecedenceTrait for BinaryOperator {
         match self {
             BinaryOperator::Or => 1,
             BinaryOperator::And => 2,
-            BinaryOperator::Equal | BinaryOperator::NotEqual => 3,
+            BinaryOperator::Equal | BinaryOperator::NotEqual | BinaryOperator::NullSafeEqual => 3,
             BinaryOperator::Not
             | BinaryOperator::Between
             | BinaryOperator::In
-            | BinaryOperator::Like => 4,
+            | BinaryOperator::Like
+            | BinaryOperator::RegexMatch
+            | BinaryOperator::RegexIMatch
+            | BinaryOperator::RegexNotMatch
+            | BinaryOperator::RegexNotIMatch
+            | BinaryOperator::IsDistinctFrom
+            | BinaryOperator::IsNotDistinctFrom => 4,
             BinaryOperator::Less
             | BinaryOperator::LessEqual
             | BinaryOperator::Greater
@@ -83,7 +136,15 @@ impl PrecedenceTrait for BinaryOperator {
             BinaryOperator::LeftShift
             | BinaryOperator::RightShift
             | BinaryOperator::BitAnd
-            | BinaryOperator::BitXor => 6,
+            | BinaryOperator::BitOr
+            | BinaryOperator::BitXor
+            | BinaryOperator::StringConcat
+            | BinaryOperator::JsonGet
+            | BinaryOperator::JsonGetText
+            | BinaryOperator::JsonGetPath
+            | BinaryOperator::JsonGetPathText
+            | BinaryOperator::Contains
+            | BinaryOperator::ContainedBy => 6,
             BinaryOperator::Add | BinaryOperator::Subtract => 7,
             BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Mod => 8,
         }
@@ -98,20 +159,76 @@ impl PrecedenceTrait for BinaryOperator {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum UnaryOperator {
+    /// `-expr`
+    Minus,
+    /// `+expr`
+    Plus,
+    /// `NOT expr`
+    Not,
+    /// `~expr`
+    BitNot,
+}
+
+impl PrecedenceTrait for UnaryOperator {
+    fn precedence(&self) -> usize {
+        match self {
+            // NOT 的操作数可以是比较表达式：`NOT a = b` 即 `NOT (a = b)`
+            UnaryOperator::Not => BinaryOperator::Equal.precedence(),
+            UnaryOperator::Minus | UnaryOperator::Plus | UnaryOperator::BitNot => 9,
+        }
+    }
+
+    fn is_left_associative(&self) -> bool {
+        false
+    }
+
+    fn min_precedence() -> usize {
+        0
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnaryOp<'a> {
+    pub op: UnaryOperator,
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct BinaryOp<'a> {
     pub op: BinaryOperator,
     pub left: Expr<'a>,
     pub right: Expr<'a>,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for BinaryOp<'a> {
+    fn span(&self) -> Span {
+        self.left.span().union(self.right.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum Expr<'a> {
     Field(Field<'a>),
     Star(Star<'a>),
-    FunctionCall(FunctionCall<'a>),
+    FunctionCall(Box<FunctionCall<'a>>),
     StringLiteral(StringLiteral<'a>),
+    /// `E'...'`, backslash escapes are interpreted.
+    EscapedStringLiteral(StringLiteral<'a>),
+    /// `N'...'`, text in the national character set.
+    NationalStringLiteral(StringLiteral<'a>),
+    /// `X'...'`, bytes written as hex digits.
+    HexStringLiteral(StringLiteral<'a>),
+    /// `B'...'`, bits written as `0`/`1`.
+    BitStringLiteral(StringLiteral<'a>),
+    /// `_utf8mb4'...'`, text with a MySQL charset introducer.
+    IntroducedStringLiteral(IntroducedStringLiteral<'a>),
+    /// PostgreSQL `$$...$$` or `$tag$...$tag$`.
+    DollarQuotedStringLiteral(DollarQuotedStringLiteral<'a>),
     NumericLiteral(NumericLiteral<'a>),
+    UnaryOp(Box<UnaryOp<'a>>),
     BinaryOp(Box<BinaryOp<'a>>),
     Between(Between<'a>),
     In(In<'a>),
@@ -119,9 +236,71 @@ pub enum Expr<'a> {
     Like(Like<'a>),
     IsNull(IsNull<'a>),
     Exists(Box<ExistsExpr<'a>>),
-    BoolLiteral(bool),
-    NullLiteral,
+    BoolLiteral(BoolLiteral),
+    NullLiteral(NullLiteral),
+    /// `DEFAULT` in a `VALUES` row, the column's default value.
+    Default(DefaultValue),
     WindowFunction(Box<WindowFunction<'a>>),
+    /// A bind parameter: `?`, `$1`, `:name` or `@name`.
+    Placeholder(Placeholder<'a>),
+    /// `CAST(expr AS type)`, `TRY_CAST(expr AS type)` or `expr::type`.
+    Cast(Box<Cast<'a>>),
+    /// A string literal preceded by its type, e.g. `DATE '2024-01-01'`.
+    TypedLiteral(Box<TypedLiteral<'a>>),
+    /// `EXTRACT(field FROM expr)`
+    Extract(Box<Extract<'a>>),
+    /// `SUBSTRING(expr FROM start FOR length)`
+    Substring(Box<Substring<'a>>),
+    /// `TRIM([LEADING | TRAILING | BOTH] [characters] FROM expr)`
+    Trim(Box<Trim<'a>>),
+    /// `POSITION(substring IN expr)`
+    Position(Box<Position<'a>>),
+    /// `OVERLAY(expr PLACING replacement FROM start FOR length)`
+    Overlay(Box<Overlay<'a>>),
+    /// A query in parentheses used as a value, e.g. `(SELECT max(y) FROM t)`.
+    Subquery(Box<Subquery<'a>>),
+    /// A comparison against every row of a subquery, e.g. `x > ALL (SELECT ...)`.
+    Quantified(Box<Quantified<'a>>),
+}
+
+impl<'a> Spanned for Expr<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Expr::Field(field) => field.span,
+            Expr::Star(star) => star.span,
+            Expr::FunctionCall(call) => call.span,
+            Expr::StringLiteral(literal)
+            | Expr::EscapedStringLiteral(literal)
+            | Expr::NationalStringLiteral(literal)
+            | Expr::HexStringLiteral(literal)
+            | Expr::BitStringLiteral(literal) => literal.span,
+            Expr::IntroducedStringLiteral(literal) => literal.span,
+            Expr::DollarQuotedStringLiteral(literal) => literal.span,
+            Expr::NumericLiteral(literal) => literal.span,
+            Expr::UnaryOp(unary_op) => unary_op.span,
+            Expr::BinaryOp(binary_op) => binary_op.span(),
+            Expr::Between(between) => between.span(),
+            Expr::In(in_expr) => in_expr.span,
+            Expr::Case(case) => case.span,
+            Expr::Like(like) => like.span(),
+            Expr::IsNull(is_null) => is_null.span,
+            Expr::Exists(exists) => exists.span,
+            Expr::BoolLiteral(literal) => literal.span,
+            Expr::NullLiteral(literal) => literal.span,
+            Expr::Default(default) => default.span,
+            Expr::WindowFunction(window_function) => window_function.span(),
+            Expr::Placeholder(placeholder) => placeholder.span,
+            Expr::Cast(cast) => cast.span,
+            Expr::TypedLiteral(literal) => literal.span,
+            Expr::Extract(extract) => extract.span,
+            Expr::Substring(substring) => substring.span,
+            Expr::Trim(trim) => trim.span,
+            Expr::Position(position) => position.span,
+            Expr::Overlay(overlay) => overlay.span,
+            Expr::Subquery(subquery) => subquery.span,
+            Expr::Quantified(quantified) => quantified.span,
+        }
+    }
 }
 
 impl<'a> Expr<'a> {
@@ -136,14 +315,40 @@ impl<'a> Expr<'a> {
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
-        FunctionCall::from_token(token_table, cursor).map(Expr::FunctionCall)
+        FunctionCall::from_token(token_table, cursor).map(|call| Expr::FunctionCall(Box::new(call)))
     }
 
     pub(crate) fn class_string_literal(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
-        StringLiteral::from_token(token_table, cursor).map(Expr::StringLiteral)
+        let start = *cursor;
+        let literal = StringLiteral::from_token(token_table, cursor)?;
+        let source = token_table.source_at(start);
+        if source.starts_with('$') {
+            return Ok(Expr::DollarQuotedStringLiteral(
+                DollarQuotedStringLiteral::from_source(source, literal.span),
+            ));
+        }
+        // 前缀写在第一个引号之前，value 只保留引号部分
+        let Some(quote) = source.find('\'').filter(|quote| *quote > 0) else {
+            return Ok(Expr::StringLiteral(literal));
+        };
+        let literal = StringLiteral {
+            value: &source[quote..],
+            span: literal.span,
+        };
+        Ok(match &source[..quote] {
+            "E" | "e" => Expr::EscapedStringLiteral(literal),
+            "N" | "n" => Expr::NationalStringLiteral(literal),
+            "X" | "x" => Expr::HexStringLiteral(literal),
+            "B" | "b" => Expr::BitStringLiteral(literal),
+            introducer => Expr::IntroducedStringLiteral(IntroducedStringLiteral {
+                charset: introducer.trim_start_matches('_'),
+                value: literal.value,
+                span: literal.span,
+            }),
+        })
     }
 
     pub(crate) fn class_number_literal(
@@ -167,12 +372,38 @@ impl<'a> Expr<'a> {
         PrattParser::parse_expression::<Self>(token_table, cursor)
     }
 
+    /// BETWEEN 的上下界、LIKE 的模式与 IS DISTINCT FROM 的右侧：只吸收比 BETWEEN
+    /// 优先级更高的运算符，避免吞掉 `BETWEEN 1 AND 2` 中的 AND
+    pub(crate) fn parse_bound(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        PrattParser::parse_expression_with_min_precedence::<Self>(
+            token_table,
+            cursor,
+            BinaryOperator::Between.precedence() + 1,
+        )
+    }
+
     pub(crate) fn build(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         Self::parse_expression(token_table, cursor)
     }
+
+    /// 写入列的值：普通表达式，或单独的 `DEFAULT`
+    pub(crate) fn build_or_default(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Default)) {
+            let span = token_table.span_at(*cursor);
+            *cursor += 1;
+            return Ok(Expr::Default(DefaultValue { span }));
+        }
+        Self::parse_expression(token_table, cursor)
+    }
 }
 
 impl<'a> Aliasable<'a> for Expr<'a> {
@@ -187,8 +418,38 @@ impl<'a> PrattOutput<BinaryOperator> for Expr<'a> {
     }
 }
 
+impl<'a> PrattPrefix<UnaryOperator> for Expr<'a> {
+    fn apply_prefix(op: UnaryOperator, operand: Self, span: Span) -> Self {
+        Expr::UnaryOp(Box::new(UnaryOp {
+            op,
+            expr: operand,
+            span,
+        }))
+    }
+}
+
+/// Tokens that can begin an expression, reported when none of them is found.
+const EXPRESSION_START: &[TokenKind] = &[
+    TokenKind::Identifier,
+    TokenKind::Number,
+    TokenKind::StringLiteral,
+    TokenKind::Placeholder,
+    TokenKind::LeftParen,
+    TokenKind::Multiply,
+    TokenKind::Subtract,
+    TokenKind::Plus,
+    TokenKind::Tilde,
+    TokenKind::Keyword(Keyword::Not),
+    TokenKind::Keyword(Keyword::Case),
+    TokenKind::Keyword(Keyword::Exists),
+    TokenKind::Keyword(Keyword::Null),
+    TokenKind::Keyword(Keyword::True),
+    TokenKind::Keyword(Keyword::False),
+];
+
 impl<'a> PrattParserTrait<'a> for Expr<'a> {
     type Item = BinaryOperator;
+    type Prefix = UnaryOperator;
     type Output = Self;
 
     fn parse_primary(
@@ -198,9 +459,35 @@ impl<'a> PrattParserTrait<'a> for Expr<'a> {
         match token_table.get_kind(*cursor) {
             Some(TokenKind::Number) => Self::class_number_literal(token_table, cursor),
             Some(TokenKind::StringLiteral) => Self::class_string_literal(token_table, cursor),
+            Some(TokenKind::Placeholder) => {
+                Placeholder::from_token(token_table, cursor).map(Expr::Placeholder)
+            }
+            Some(TokenKind::Identifier)
+                if token_table.get_kind(*cursor + 1) == Some(&TokenKind::StringLiteral)
+                    && TypedLiteral::is_literal_type(token_table.source_at(*cursor)) =>
+            {
+                TypedLiteral::build(token_table, cursor)
+                    .map(|literal| Expr::TypedLiteral(Box::new(literal)))
+            }
+            // CAST 与 TRY_CAST 不是保留字，只有跟着左括号时才是类型转换
+            Some(TokenKind::Identifier)
+                if token_table.get_kind(*cursor + 1) == Some(&TokenKind::LeftParen)
+                    && (is_word(token_table, *cursor, "CAST")
+                        || is_word(token_table, *cursor, "TRY_CAST")) =>
+            {
+                let kind = if is_word(token_table, *cursor, "CAST") {
+                    CastKind::Cast
+                } else {
+                    CastKind::TryCast
+                };
+                Cast::build(kind, token_table, cursor).map(|cast| Expr::Cast(Box::new(cast)))
+            }
             Some(TokenKind::Identifier) => {
                 if let Some(TokenKind::LeftParen) = token_table.get_kind(*cursor + 1) {
-                    Self::class_function_call(token_table, cursor)
+                    match Self::class_special_function(token_table, cursor)? {
+                        Some(expr) => Ok(expr),
+                        None => Self::class_function_call(token_table, cursor),
+                    }
                 } else if let Some(TokenKind::Dot) = token_table.get_kind(*cursor + 1) {
                     if let Some(TokenKind::Multiply) = token_table.get_kind(*cursor + 2) {
                         Self::class_star(token_table, cursor)
@@ -211,8 +498,21 @@ impl<'a> PrattParserTrait<'a> for Expr<'a> {
                     Self::class_field(token_table, cursor)
                 }
             }
+            Some(TokenKind::QuotedIdentifier) => {
+                if let (Some(TokenKind::Dot), Some(TokenKind::Multiply)) = (
+                    token_table.get_kind(*cursor + 1),
+                    token_table.get_kind(*cursor + 2),
+                ) {
+                    Self::class_star(token_table, cursor)
+                } else {
+                    Self::class_field(token_table, cursor)
+                }
+            }
             Some(TokenKind::Multiply) => Self::class_star(token_table, cursor),
             Some(TokenKind::LeftParen) => {
+                if let Some(subquery) = Subquery::try_build(token_table, cursor)? {
+                    return Ok(Expr::Subquery(Box::new(subquery)));
+                }
                 *cursor += 1;
                 let expr = Self::parse_expression(token_table, cursor)?;
                 expect_kind(token_table, cursor, &TokenKind::RightParen)?;
@@ -221,140 +521,175 @@ impl<'a> PrattParserTrait<'a> for Expr<'a> {
             }
             Some(TokenKind::Keyword(Keyword::Case)) => Self::class_case(token_table, cursor),
             Some(TokenKind::Keyword(Keyword::True)) => {
+                let span = token_table.span_at(*cursor);
                 *cursor += 1;
-                Ok(Expr::BoolLiteral(true))
+                Ok(Expr::BoolLiteral(BoolLiteral { value: true, span }))
             }
             Some(TokenKind::Keyword(Keyword::False)) => {
+                let span = token_table.span_at(*cursor);
                 *cursor += 1;
-                Ok(Expr::BoolLiteral(false))
+                Ok(Expr::BoolLiteral(BoolLiteral { value: false, span }))
             }
             Some(TokenKind::Keyword(Keyword::Null)) => {
+                let span = token_table.span_at(*cursor);
                 *cursor += 1;
-                Ok(Expr::NullLiteral)
+                Ok(Expr::NullLiteral(NullLiteral { span }))
             }
             Some(TokenKind::Keyword(Keyword::Exists)) => {
+                let exists = ExistsExpr::build(false, *cursor, token_table, cursor)?;
+                Ok(Expr::Exists(Box::new(exists)))
+            }
+            Some(TokenKind::Keyword(Keyword::Not)) => {
+                let start = *cursor;
                 *cursor += 1;
-                expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
-                *cursor += 1;
-                let select_stmt = SelectStatement::new(token_table, cursor)?;
-                expect_kind(token_table, cursor, &TokenKind::RightParen)?;
-                *cursor += 1;
-                Ok(Expr::Exists(Box::new(ExistsExpr {
-                    is_not: false,
-                    subquery: Box::new(select_stmt),
-                })))
+                let exists = ExistsExpr::build(true, start, token_table, cursor)?;
+                Ok(Expr::Exists(Box::new(exists)))
             }
-            Some(TokenKind::Keyword(Keyword::If)) if let Some(TokenKind::LeftParen) = token_table.get_kind(*cursor + 1) => {
+            // MySQL 的 IF(...) 与 ON DUPLICATE KEY UPDATE 中的 VALUES(col)
+            Some(TokenKind::Keyword(Keyword::If | Keyword::Values))
+                if matches!(
+                    token_table.get_kind(*cursor + 1),
+                    Some(TokenKind::LeftParen)
+                ) =>
+            {
                 Self::class_function_call(token_table, cursor)
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(syntax_error(token_table, cursor, EXPRESSION_START)),
         }
     }
 
     fn match_item(token_table: &TokenTable, cursor: &mut usize) -> Option<Self::Item> {
-        match token_table.get_kind(*cursor) {
-            Some(TokenKind::Plus) => {
-                *cursor += 1;
-                Some(BinaryOperator::Add)
-            }
-            Some(TokenKind::Subtract) => {
-                *cursor += 1;
-                Some(BinaryOperator::Subtract)
-            }
-            Some(TokenKind::Multiply) => {
-                *cursor += 1;
-                Some(BinaryOperator::Multiply)
-            }
-            Some(TokenKind::Divide) => {
-                *cursor += 1;
-                Some(BinaryOperator::Divide)
-            }
-            Some(TokenKind::Mod) => {
-                *cursor += 1;
-                Some(BinaryOperator::Mod)
-            }
-            Some(TokenKind::Equal) => {
-                *cursor += 1;
-                Some(BinaryOperator::Equal)
-            }
-            Some(TokenKind::NotEqual) => {
-                *cursor += 1;
-                Some(BinaryOperator::NotEqual)
-            }
-            Some(TokenKind::Less) => {
-                *cursor += 1;
-                Some(BinaryOperator::Less)
-            }
-            Some(TokenKind::LessEqual) => {
-                *cursor += 1;
-                Some(BinaryOperator::LessEqual)
-            }
-            Some(TokenKind::Greater) => {
-                *cursor += 1;
-                Some(BinaryOperator::Greater)
-            }
-            Some(TokenKind::GreaterEqual) => {
-                *cursor += 1;
-                Some(BinaryOperator::GreaterEqual)
-            }
-            Some(TokenKind::BitAnd) => {
-                *cursor += 1;
-                Some(BinaryOperator::BitAnd)
-            }
-            Some(TokenKind::Or) => {
-                *cursor += 1;
-                Some(BinaryOperator::Or)
-            }
-            Some(TokenKind::BitXor) => {
-                *cursor += 1;
-                Some(BinaryOperator::BitXor)
-            }
-            Some(TokenKind::LeftShift) => {
-                *cursor += 1;
-                Some(BinaryOperator::LeftShift)
-            }
-            Some(TokenKind::RightShift) => {
-                *cursor += 1;
-                Some(BinaryOperator::RightShift)
-            }
-            Some(TokenKind::Keyword(Keyword::And)) => {
-                *cursor += 1;
-                Some(BinaryOperator::And)
-            }
-            Some(TokenKind::Keyword(Keyword::Or)) => {
-                *cursor += 1;
-                Some(BinaryOperator::Or)
+        let op = BinaryOperator::from_token_kind(token_table.get_kind(*cursor)?)?;
+        *cursor += 1;
+        Some(op)
+    }
+
+    fn match_prefix(token_table: &TokenTable, cursor: &mut usize) -> Option<Self::Prefix> {
+        let op = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Subtract) => UnaryOperator::Minus,
+            Some(TokenKind::Plus) => UnaryOperator::Plus,
+            Some(TokenKind::Tilde) => UnaryOperator::BitNot,
+            // NOT EXISTS 由 parse_primary 解析成 ExistsExpr
+            Some(TokenKind::Keyword(Keyword::Not))
+                if token_table.get_kind(*cursor + 1)
+                    != Some(&TokenKind::Keyword(Keyword::Exists)) =>
+            {
+                UnaryOperator::Not
             }
-            _ => None,
+            _ => return None,
+        };
+        *cursor += 1;
+        Some(op)
+    }
+
+    fn parse_infix(
+        op: Self::Item,
+        left: Self::Output,
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Infix<Self::Item, Self::Output>, ParserError> {
+        let is_comparison = matches!(
+            op,
+            BinaryOperator::Equal
+                | BinaryOperator::NotEqual
+                | BinaryOperator::Less
+                | BinaryOperator::LessEqual
+                | BinaryOperator::Greater
+                | BinaryOperator::GreaterEqual
+        );
+        // ANY/SOME 不是保留字，只有紧跟比较运算符和左括号时才是量词
+        let is_quantifier_word = |word| {
+            is_comparison
+                && token_table.get_kind(*cursor + 1) == Some(&TokenKind::LeftParen)
+                && is_word(token_table, *cursor, word)
+        };
+        let quantifier = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::All)) => Quantifier::All,
+            Some(TokenKind::Identifier) if is_quantifier_word("ANY") => Quantifier::Any,
+            Some(TokenKind::Identifier) if is_quantifier_word("SOME") => Quantifier::Some,
+            _ => return Ok(Infix::Operand(op, left)),
+        };
+        if !is_comparison {
+            return Err(syntax_error(token_table, cursor, EXPRESSION_START));
         }
+        *cursor += 1;
+        let subquery = Subquery::build(token_table, cursor)?;
+        let span = left.span().union(subquery.span);
+        Ok(Infix::Done(Expr::Quantified(Box::new(Quantified {
+            left,
+            op,
+            quantifier,
+            subquery: subquery.query,
+            span,
+        }))))
     }
 
     fn parse_postfix(
         left: Self::Output,
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
+        min_precedence: usize,
     ) -> Result<(Self::Output, Flow), ParserError> {
+        // `IGNORE NULLS OVER` 中的 IGNORE NULLS 属于窗口函数；
+        // 不跟 OVER 时留给外层，例如函数参数 `x IGNORE NULLS`
+        let is_window = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Keyword(Keyword::Over)) => true,
+            Some(TokenKind::Identifier) => {
+                NullTreatment::starts_at(token_table, *cursor)
+                    && token_table.get_kind(*cursor + 2) == Some(&TokenKind::Keyword(Keyword::Over))
+            }
+            _ => false,
+        };
+        // OVER 与 `::` 只作用于紧挨着的操作数，其余后缀运算符与 BETWEEN 同级
+        if min_precedence > BinaryOperator::Between.precedence()
+            && !is_window
+            && token_table.get_kind(*cursor) != Some(&TokenKind::DoubleColon)
+        {
+            return Ok((left, Flow::Run));
+        }
         match token_table.get_kind(*cursor) {
+            Some(&TokenKind::DoubleColon) => {
+                *cursor += 1;
+                let data_type = DataType::parse(token_table, cursor)?;
+                let span = left.span().union(data_type.span);
+                Ok((
+                    Expr::Cast(Box::new(Cast {
+                        kind: CastKind::DoubleColon,
+                        expr: left,
+                        data_type,
+                        span,
+                    })),
+                    Flow::Continue,
+                ))
+            }
+            // `NOT NULL` 是列约束，例如 `DEFAULT 0 NOT NULL`，留给外层
+            Some(&TokenKind::Keyword(Keyword::Not))
+                if token_table.get_kind(*cursor + 1)
+                    == Some(&TokenKind::Keyword(Keyword::Null)) =>
+            {
+                Ok((left, Flow::Run))
+            }
             Some(&TokenKind::Keyword(Keyword::Not)) => {
                 *cursor += 1;
                 match token_table.get_kind(*cursor) {
                     Some(&TokenKind::Keyword(Keyword::Between)) => {
-                        let between = Between::build(true, Box::new(left), token_table, cursor);
-                        between.map(|e| (Expr::Between(e), Flow::Continue))
+                        let between =
+                            Self::class_between(true, Box::new(left), token_table, cursor);
+                        between.map(|e| (e, Flow::Continue))
                     }
                     Some(&TokenKind::Keyword(Keyword::In)) => {
-                        let in_expr = In::build(true, Box::new(left), token_table, cursor);
-                        in_expr.map(|e| (Expr::In(e), Flow::Continue))
+                        let in_expr = Self::class_in(true, Box::new(left), token_table, cursor);
+                        in_expr.map(|e| (e, Flow::Continue))
                     }
                     Some(&TokenKind::Keyword(Keyword::Like)) => {
                         let like = Like::build(true, Box::new(left), token_table, cursor);
                         like.map(|e| (Expr::Like(e), Flow::Continue))
                     }
                     Some(&TokenKind::Keyword(Keyword::Exists)) => {
-                        let exists = ExistsExpr::build(true, token_table, cursor);
+                        let exists = ExistsExpr::build(true, *cursor - 1, token_table, cursor);
                         exists.map(|e| (Expr::Exists(Box::new(e)), Flow::Continue))
                     }
-                    _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+                    _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
                 }
             }
             Some(&TokenKind::Keyword(Keyword::Is)) => {
@@ -365,39 +700,64 @@ impl<'a> PrattParserTrait<'a> for Expr<'a> {
                 } else {
                     false
                 };
+                if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Distinct)) {
+                    *cursor += 1;
+                    expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From))?;
+                    *cursor += 1;
+                    let right = Self::parse_bound(token_table, cursor)?;
+                    let op = if is_not {
+                        BinaryOperator::IsNotDistinctFrom
+                    } else {
+                        BinaryOperator::IsDistinctFrom
+                    };
+                    return Ok((Self::apply(op, left, right), Flow::Continue));
+                }
                 expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Null))?;
+                let span = left.span().union(token_table.span_at(*cursor));
                 *cursor += 1;
                 Ok((
                     Expr::IsNull(IsNull {
                         is_not,
                         field: Box::new(left),
+                        span,
                     }),
                     Flow::Continue,
                 ))
             }
             Some(&TokenKind::Keyword(Keyword::Between)) => {
-                let between = Between::build(false, Box::new(left), token_table, cursor);
-                between.map(|e| (Expr::Between(e), Flow::Continue))
+                let between = Self::class_between(false, Box::new(left), token_table, cursor);
+                between.map(|e| (e, Flow::Continue))
             }
             Some(&TokenKind::Keyword(Keyword::In)) => {
-                let in_ = In::build(false, Box::new(left), token_table, cursor);
-                in_.map(|e| (Expr::In(e), Flow::Continue))
+                let in_ = Self::class_in(false, Box::new(left), token_table, cursor);
+                in_.map(|e| (e, Flow::Continue))
             }
             Some(&TokenKind::Keyword(Keyword::Like)) => {
                 let like = Like::build(false, Box::new(left), token_table, cursor);
                 like.map(|e| (Expr::Like(e), Flow::Continue))
             }
-            Some(&TokenKind::Keyword(Keyword::Over)) => {
+            Some(&TokenKind::Keyword(Keyword::Over) | &TokenKind::Identifier) if is_window => {
+                let null_treatment = NullTreatment::parse(token_table, cursor)?;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Over))?;
                 *cursor += 1;
-                let window_spec = WindowSpec::build(token_table, cursor)?;
+                let window = match token_table.get_kind(*cursor) {
+                    Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                        let name = Ident::from_token(token_table, cursor)?;
+                        let span = token_table.span_at(*cursor);
+                        *cursor += 1;
+                        WindowRef::Named { name, span }
+                    }
+                    _ => WindowRef::Spec(WindowSpec::build(token_table, cursor)?),
+                };
                 let function = match left {
-                    Expr::FunctionCall(fc) => fc,
-                    _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                    Expr::FunctionCall(fc) => *fc,
+                    _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
                 };
                 Ok((
                     Expr::WindowFunction(Box::new(WindowFunction {
                         function,
-                        window_spec,
+                        null_treatment,
+                        window,
                     })),
                     Flow::Continue,
                 ))
@@ -434,10 +794,11 @@ impl<'a> Expr<'a> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Field<'a> {
-    pub prefix: Option<&'a str>,
-    pub name: &'a str,
+    pub prefix: Option<Ident<'a>>,
+    pub name: Ident<'a>,
+    pub span: Span,
 }
 
 impl<'a> Field<'a> {
@@ -447,7 +808,7 @@ impl<'a> Field<'a> {
     ) -> Result<Self, ParserError> {
         let first = token_table
             .get_kind(*cursor)
-            .map(|kind| kind == &TokenKind::Identifier)
+            .map(TokenKind::is_identifier)
             .unwrap_or(false);
         let dot = token_table
             .get_kind(*cursor + 1)
@@ -455,31 +816,33 @@ impl<'a> Field<'a> {
             .unwrap_or(false);
         let second = token_table
             .get_kind(*cursor + 2)
-            .map(|kind| kind == &TokenKind::Identifier)
+            .map(TokenKind::is_identifier)
             .unwrap_or(false);
 
         let (prefix, name, sum) = match (first, dot, second) {
             (true, false, _) => {
-                let name = token_table.source_at(*cursor);
+                let name = Ident::from_token(token_table, cursor)?;
                 (None, name, 1)
             }
             (true, true, true) => {
-                let prefix = token_table.source_at(*cursor);
-                let name = token_table.source_at(*cursor + 2);
+                let prefix = Ident::from_token(token_table, cursor)?;
+                let name = Ident::from_token(token_table, &(*cursor + 2))?;
                 (Some(prefix), name, 3)
             }
-            _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         };
 
+        let span = token_table.span_between(*cursor, *cursor + sum);
         *cursor += sum;
 
-        Ok(Self { prefix, name })
+        Ok(Self { prefix, name, span })
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Star<'a> {
-    pub prefix: Option<&'a str>,
+    pub prefix: Option<Ident<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Star<'a> {
@@ -489,7 +852,7 @@ impl<'a> Star<'a> {
     ) -> Result<Self, ParserError> {
         let first = token_table
             .get_kind(*cursor)
-            .map(|kind| kind == &TokenKind::Identifier)
+            .map(TokenKind::is_identifier)
             .unwrap_or(false);
         let first_star = token_table
             .get_kind(*cursor)
@@ -505,25 +868,67 @@ impl<'a> Star<'a> {
             .unwrap_or(false);
 
         if first_star {
+            let span = token_table.span_at(*cursor);
             *cursor += 1;
-            Ok(Self { prefix: None })
+            Ok(Self { prefix: None, span })
         } else if first && dot && second {
-            let prefix = token_table.source_at(*cursor);
+            let prefix = Ident::from_token(token_table, cursor)?;
+            let span = token_table.span_between(*cursor, *cursor + 3);
             *cursor += 3;
             Ok(Self {
                 prefix: Some(prefix),
+                span,
             })
         } else {
-            Err(ParserError::SyntaxError(*cursor, *cursor))
+            Err(ParserError::SyntaxError(token_table.span_at(*cursor)))
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// `IGNORE NULLS` or `RESPECT NULLS`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum NullTreatment {
+    IgnoreNulls,
+    RespectNulls,
+}
+
+impl NullTreatment {
+    /// IGNORE/RESPECT 不是保留字，后面跟着 NULLS 时才是空值处理
+    fn starts_at(token_table: &TokenTable, cursor: usize) -> bool {
+        (is_word(token_table, cursor, "IGNORE") || is_word(token_table, cursor, "RESPECT"))
+            && token_table.get_kind(cursor + 1) == Some(&TokenKind::Keyword(Keyword::Nulls))
+    }
+
+    fn parse(token_table: &TokenTable, cursor: &mut usize) -> Result<Option<Self>, ParserError> {
+        if !Self::starts_at(token_table, *cursor) {
+            return Ok(None);
+        }
+        let treatment = if is_word(token_table, *cursor, "IGNORE") {
+            Self::IgnoreNulls
+        } else {
+            Self::RespectNulls
+        };
+        *cursor += 2;
+        Ok(Some(treatment))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct FunctionCall<'a> {
     pub name: &'a str,
     pub args: MiniVec<Expr<'a>>,
     pub distinct: bool,
+    /// `IGNORE NULLS` written inside the parentheses, e.g. `FIRST_VALUE(x IGNORE NULLS)`.
+    pub null_treatment: Option<NullTreatment>,
+    /// `ORDER BY` inside the parentheses, e.g. `string_agg(name, ',' ORDER BY name)`.
+    pub order_by: Option<Order<'a>>,
+    /// `LIMIT` inside the parentheses, e.g. `array_agg(x LIMIT 10)`.
+    pub limit: Option<Box<Limit<'a>>>,
+    /// `WITHIN GROUP (ORDER BY ...)` of ordered-set aggregates.
+    pub within_group: Option<Order<'a>>,
+    /// `FILTER (WHERE ...)`
+    pub filter: Option<Box<Expr<'a>>>,
+    pub span: Span,
 }
 
 impl<'a> FunctionCall<'a> {
@@ -533,7 +938,12 @@ impl<'a> FunctionCall<'a> {
     ) -> Result<Self, ParserError> {
         let first = token_table
             .get_kind(*cursor)
-            .map(|kind| kind == &TokenKind::Identifier || kind == &TokenKind::Keyword(Keyword::If))
+            .map(|kind| {
+                matches!(
+                    kind,
+                    TokenKind::Identifier | TokenKind::Keyword(Keyword::If | Keyword::Values)
+                )
+            })
             .unwrap_or(false);
         let second = token_table
             .get_kind(*cursor + 1)
@@ -541,9 +951,10 @@ impl<'a> FunctionCall<'a> {
             .unwrap_or(false);
 
         if !(first && second) {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
 
+        let start = *cursor;
         let name = token_table.source_at(*cursor);
         *cursor += 2;
 
@@ -564,11 +975,17 @@ impl<'a> FunctionCall<'a> {
                     is_comma = true;
                     continue;
                 }
-                Some(TokenKind::RightParen) => {
+                Some(TokenKind::RightParen)
+                | Some(TokenKind::Keyword(Keyword::Order | Keyword::Limit)) => {
                     if is_comma {
-                        return Err(ParserError::SyntaxError(*cursor, *cursor));
+                        return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+                    }
+                    break;
+                }
+                Some(TokenKind::Identifier) if NullTreatment::starts_at(token_table, *cursor) => {
+                    if is_comma {
+                        return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
                     }
-                    *cursor += 1;
                     break;
                 }
                 Some(_) => {
@@ -577,60 +994,612 @@ impl<'a> FunctionCall<'a> {
                     is_comma = false;
                 }
                 _ => {
-                    return Err(ParserError::SyntaxError(*cursor, *cursor));
+                    return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
                 }
             }
         }
 
-        Ok(Self {
-            name,
-            args,
-            distinct,
-        })
+        // 括号内参数之后的修饰：IGNORE NULLS、ORDER BY、LIMIT
+        let null_treatment = NullTreatment::parse(token_table, cursor)?;
+        let order_by = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Order)) {
+            Some(Order::build(token_table, cursor)?)
+        } else {
+            None
+        };
+        let limit = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Limit)) {
+            Some(Box::new(Limit::new(token_table, cursor)?))
+        } else {
+            None
+        };
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+
+        // WITHIN 与 FILTER 不是保留字，只在右括号之后按 `WITHIN GROUP` 与 `FILTER (` 识别，
+        // 否则仍可作为别名，例如 `count(*) filter`
+        let within_group = if is_word(token_table, *cursor, "WITHIN")
+            && token_table.get_kind(*cursor + 1) == Some(&TokenKind::Keyword(Keyword::Group))
+        {
+            *cursor += 2;
+            expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+            *cursor += 1;
+            let order = Order::build(token_table, cursor)?;
+            expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+            *cursor += 1;
+            Some(order)
+        } else {
+            None
+        };
+
+        let filter = if is_word(token_table, *cursor, "FILTER")
+            && token_table.get_kind(*cursor + 1) == Some(&TokenKind::LeftParen)
+        {
+            *cursor += 2;
+            expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Where))?;
+            *cursor += 1;
+            let condition = Expr::parse_expression(token_table, cursor)?;
+            expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+            *cursor += 1;
+            Some(Box::new(condition))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name,
+            args,
+            distinct,
+            null_treatment,
+            order_by,
+            limit,
+            within_group,
+            filter,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StringLiteral<'a> {
+    /// The literal as written, quotes included.
+    pub value: &'a str,
+    pub span: Span,
+}
+
+impl<'a> StringLiteral<'a> {
+    pub(crate) fn from_token(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        if let Some(TokenKind::StringLiteral) = token_table.get_kind(*cursor) {
+            let value = token_table.source_at(*cursor);
+            let span = token_table.span_at(*cursor);
+            *cursor += 1;
+            Ok(Self { value, span })
+        } else {
+            Err(ParserError::SyntaxError(token_table.span_at(*cursor)))
+        }
+    }
+}
+
+/// A string literal preceded by a charset introducer, e.g. `_utf8mb4'abc'`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IntroducedStringLiteral<'a> {
+    /// The charset name without the leading underscore.
+    pub charset: &'a str,
+    pub value: &'a str,
+    pub span: Span,
+}
+
+/// A PostgreSQL dollar-quoted string, e.g. `$body$ SELECT 1 $body$`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DollarQuotedStringLiteral<'a> {
+    /// The tag between the dollar signs, `None` for `$$`.
+    pub tag: Option<&'a str>,
+    /// The text between the opening and closing delimiters, taken verbatim.
+    pub value: &'a str,
+    pub span: Span,
+}
+
+impl<'a> DollarQuotedStringLiteral<'a> {
+    fn from_source(source: &'a str, span: Span) -> Self {
+        // lexer 保证 source 以 $tag$ 开头并以相同的 $tag$ 结尾
+        let tag_len = source[1..].find('$').unwrap_or(0);
+        let delimiter_len = tag_len + 2;
+        Self {
+            tag: (tag_len > 0).then(|| &source[1..=tag_len]),
+            value: &source[delimiter_len..source.len() - delimiter_len],
+            span,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NumericLiteral<'a> {
+    pub value: &'a str,
+    pub span: Span,
+}
+
+impl<'a> NumericLiteral<'a> {
+    pub(crate) fn from_token(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        if let Some(TokenKind::Number) = token_table.get_kind(*cursor) {
+            let value = token_table.source_at(*cursor);
+            let span = token_table.span_at(*cursor);
+            *cursor += 1;
+            Ok(Self { value, span })
+        } else {
+            Err(ParserError::SyntaxError(token_table.span_at(*cursor)))
+        }
+    }
+}
+
+/// A bind parameter, e.g. `?`, `$1`, `:name` or `@name`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Placeholder<'a> {
+    pub style: PlaceholderStyle<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PlaceholderStyle<'a> {
+    /// `?`, bound by its position among the `?` of the input.
+    Positional,
+    /// `$1`, the number after the dollar sign.
+    Numbered(usize),
+    /// `:name` or `@name`, the name without the leading sigil.
+    Named(&'a str),
+}
+
+impl<'a> Placeholder<'a> {
+    pub(crate) fn from_token(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::Placeholder)?;
+        let source = token_table.source_at(*cursor);
+        let span = token_table.span_at(*cursor);
+        // lexer 保证 source 是 `?`、`$` 加数字，或 `:`/`@` 加名字
+        let style = match source.as_bytes()[0] {
+            b'?' => PlaceholderStyle::Positional,
+            b'$' => PlaceholderStyle::Numbered(
+                source[1..]
+                    .parse()
+                    .map_err(|_| ParserError::SyntaxError(span))?,
+            ),
+            _ => PlaceholderStyle::Named(&source[1..]),
+        };
+        *cursor += 1;
+        Ok(Self { style, span })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CastKind {
+    /// `CAST(expr AS type)`
+    Cast,
+    /// `TRY_CAST(expr AS type)`, yields NULL instead of failing.
+    TryCast,
+    /// `expr::type`, the PostgreSQL shorthand.
+    DoubleColon,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cast<'a> {
+    pub kind: CastKind,
+    pub expr: Expr<'a>,
+    pub data_type: DataType<'a>,
+    pub span: Span,
+}
+
+impl<'a> Cast<'a> {
+    fn build(
+        kind: CastKind,
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        let start = *cursor;
+        *cursor += 1;
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let expr = Expr::parse_expression(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::As))?;
+        *cursor += 1;
+        let data_type = DataType::parse(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(Self {
+            kind,
+            expr,
+            data_type,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+/// A date/time field, as used by `INTERVAL` qualifiers and `EXTRACT`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DateTimeField {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    /// `DOW`, day of the week.
+    DayOfWeek,
+    /// `DOY`, day of the year.
+    DayOfYear,
+    /// Seconds since 1970-01-01 00:00:00 UTC.
+    Epoch,
+}
+
+impl DateTimeField {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        const FIELDS: [(&str, DateTimeField); 13] = [
+            ("YEAR", DateTimeField::Year),
+            ("QUARTER", DateTimeField::Quarter),
+            ("MONTH", DateTimeField::Month),
+            ("WEEK", DateTimeField::Week),
+            ("DAY", DateTimeField::Day),
+            ("HOUR", DateTimeField::Hour),
+            ("MINUTE", DateTimeField::Minute),
+            ("SECOND", DateTimeField::Second),
+            ("MILLISECOND", DateTimeField::Millisecond),
+            ("MICROSECOND", DateTimeField::Microsecond),
+            ("DOW", DateTimeField::DayOfWeek),
+            ("DOY", DateTimeField::DayOfYear),
+            ("EPOCH", DateTimeField::Epoch),
+        ];
+        FIELDS
+            .iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, field)| *field)
+    }
+
+    fn from_token(token_table: &TokenTable, cursor: &usize) -> Option<Self> {
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::Identifier) => Self::from_name(token_table.source_at(*cursor)),
+            _ => None,
+        }
+    }
+}
+
+/// The unit after an interval literal, e.g. `DAY` or `YEAR TO MONTH`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IntervalQualifier {
+    pub leading: DateTimeField,
+    pub trailing: Option<DateTimeField>,
+}
+
+/// A string literal preceded by its type, e.g. `DATE '2024-01-01'` or `INTERVAL '7' DAY`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TypedLiteral<'a> {
+    pub data_type: DataType<'a>,
+    /// The literal as written, quotes included.
+    pub value: &'a str,
+    /// Only set for `INTERVAL` literals.
+    pub qualifier: Option<IntervalQualifier>,
+    pub span: Span,
+}
+
+impl<'a> TypedLiteral<'a> {
+    const LITERAL_TYPES: [&'static str; 5] = ["DATE", "TIME", "TIMESTAMP", "DATETIME", "INTERVAL"];
+
+    fn is_literal_type(name: &str) -> bool {
+        Self::LITERAL_TYPES
+            .iter()
+            .any(|literal_type| literal_type.eq_ignore_ascii_case(name))
+    }
+
+    fn build(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
+        let start = *cursor;
+        let data_type = DataType::parse(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::StringLiteral)?;
+        let value = token_table.source_at(*cursor);
+        *cursor += 1;
+
+        let qualifier = if data_type.kind == DataTypeKind::Interval {
+            match DateTimeField::from_token(token_table, cursor) {
+                Some(leading) => {
+                    *cursor += 1;
+                    let trailing =
+                        if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::To)) {
+                            *cursor += 1;
+                            let trailing = DateTimeField::from_token(token_table, cursor)
+                                .ok_or_else(|| {
+                                    ParserError::SyntaxError(token_table.span_at(*cursor))
+                                })?;
+                            *cursor += 1;
+                            Some(trailing)
+                        } else {
+                            None
+                        };
+                    Some(IntervalQualifier { leading, trailing })
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            data_type,
+            value,
+            qualifier,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Extract<'a> {
+    pub field: DateTimeField,
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Substring<'a> {
+    pub expr: Expr<'a>,
+    /// `FROM start`
+    pub start: Option<Expr<'a>>,
+    /// `FOR length`
+    pub length: Option<Expr<'a>>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TrimSide {
+    Leading,
+    Trailing,
+    Both,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Trim<'a> {
+    pub side: Option<TrimSide>,
+    /// The characters to remove, whitespace when omitted.
+    pub characters: Option<Expr<'a>>,
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Position<'a> {
+    pub substring: Expr<'a>,
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Overlay<'a> {
+    pub expr: Expr<'a>,
+    pub replacement: Expr<'a>,
+    pub start: Expr<'a>,
+    pub length: Option<Expr<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Expr<'a> {
+    /// 关键字参数形式的函数：EXTRACT、SUBSTRING、TRIM、POSITION、OVERLAY。
+    /// 写成普通逗号参数时返回 None 并恢复 cursor，交给 FunctionCall 解析。
+    fn class_special_function(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Option<Self>, ParserError> {
+        let start = *cursor;
+        let name = token_table.source_at(*cursor);
+        let parse = if name.eq_ignore_ascii_case("EXTRACT") {
+            Self::parse_extract
+        } else if name.eq_ignore_ascii_case("SUBSTRING") {
+            Self::parse_substring
+        } else if name.eq_ignore_ascii_case("TRIM") {
+            Self::parse_trim
+        } else if name.eq_ignore_ascii_case("POSITION") {
+            Self::parse_position
+        } else if name.eq_ignore_ascii_case("OVERLAY") {
+            Self::parse_overlay
+        } else {
+            return Ok(None);
+        };
+
+        // 跳过函数名与左括号
+        *cursor += 2;
+        let expr = parse(token_table, cursor, start)?;
+        if expr.is_none() {
+            *cursor = start;
+        }
+        Ok(expr)
+    }
+
+    fn finish_special_function(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+    ) -> Result<Span, ParserError> {
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(token_table.span_between(start, *cursor))
     }
-}
 
-#[derive(Debug, PartialEq)]
-pub struct StringLiteral<'a> {
-    pub value: &'a str,
-}
+    fn parse_extract(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+    ) -> Result<Option<Self>, ParserError> {
+        let field = DateTimeField::from_token(token_table, cursor)
+            .ok_or_else(|| syntax_error(token_table, cursor, &[TokenKind::Identifier]))?;
+        *cursor += 1;
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From))?;
+        *cursor += 1;
+        let expr = Self::parse_expression(token_table, cursor)?;
+        let span = Self::finish_special_function(token_table, cursor, start)?;
+        Ok(Some(Expr::Extract(Box::new(Extract { field, expr, span }))))
+    }
 
-impl<'a> StringLiteral<'a> {
-    pub(crate) fn from_token(
+    fn parse_substring(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
-    ) -> Result<Self, ParserError> {
-        if let Some(TokenKind::StringLiteral) = token_table.get_kind(*cursor) {
-            let value = token_table.source_at(*cursor);
+        start: usize,
+    ) -> Result<Option<Self>, ParserError> {
+        let expr = Self::parse_expression(token_table, cursor)?;
+        let substring_start = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From))
+        {
+            *cursor += 1;
+            Some(Self::parse_expression(token_table, cursor)?)
+        } else {
+            None
+        };
+        let length = if is_word(token_table, *cursor, "FOR") {
             *cursor += 1;
-            Ok(Self { value })
+            Some(Self::parse_expression(token_table, cursor)?)
         } else {
-            Err(ParserError::SyntaxError(*cursor, *cursor))
+            None
+        };
+        if substring_start.is_none() && length.is_none() {
+            return Ok(None);
         }
+        let span = Self::finish_special_function(token_table, cursor, start)?;
+        Ok(Some(Expr::Substring(Box::new(Substring {
+            expr,
+            start: substring_start,
+            length,
+            span,
+        }))))
     }
-}
-
-#[derive(Debug, PartialEq)]
-pub struct NumericLiteral<'a> {
-    pub value: &'a str,
-}
 
-impl<'a> NumericLiteral<'a> {
-    pub(crate) fn from_token(
+    fn parse_trim(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
-    ) -> Result<Self, ParserError> {
-        if let Some(TokenKind::Number) = token_table.get_kind(*cursor) {
-            let value = token_table.source_at(*cursor);
+        start: usize,
+    ) -> Result<Option<Self>, ParserError> {
+        // LEADING/TRAILING/BOTH 后面跟着 FROM 或操作数时才是修剪方向，否则按列名处理
+        let is_side = matches!(
+            token_table.get_kind(*cursor + 1),
+            Some(
+                TokenKind::Keyword(Keyword::From)
+                    | TokenKind::StringLiteral
+                    | TokenKind::Number
+                    | TokenKind::Identifier
+                    | TokenKind::QuotedIdentifier
+                    | TokenKind::Placeholder
+            )
+        );
+        let side = if !is_side {
+            None
+        } else if is_word(token_table, *cursor, "LEADING") {
+            Some(TrimSide::Leading)
+        } else if is_word(token_table, *cursor, "TRAILING") {
+            Some(TrimSide::Trailing)
+        } else if is_word(token_table, *cursor, "BOTH") {
+            Some(TrimSide::Both)
+        } else {
+            None
+        };
+        if side.is_some() {
+            *cursor += 1;
+        }
+
+        let (characters, expr) = if side.is_some()
+            && maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From))
+        {
             *cursor += 1;
-            Ok(Self { value })
+            (None, Self::parse_expression(token_table, cursor)?)
         } else {
-            Err(ParserError::SyntaxError(*cursor, *cursor))
+            let first = Self::parse_expression(token_table, cursor)?;
+            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From)) {
+                *cursor += 1;
+                (Some(first), Self::parse_expression(token_table, cursor)?)
+            } else if side.is_none() && maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                return Ok(None);
+            } else {
+                (None, first)
+            }
+        };
+
+        let span = Self::finish_special_function(token_table, cursor, start)?;
+        Ok(Some(Expr::Trim(Box::new(Trim {
+            side,
+            characters,
+            expr,
+            span,
+        }))))
+    }
+
+    fn parse_position(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+    ) -> Result<Option<Self>, ParserError> {
+        // 用 parse_bound 解析，否则 IN 会被当成 `x IN (...)` 吞掉
+        let substring = Self::parse_bound(token_table, cursor)?;
+        if !maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::In)) {
+            return Ok(None);
         }
+        *cursor += 1;
+        let expr = Self::parse_expression(token_table, cursor)?;
+        let span = Self::finish_special_function(token_table, cursor, start)?;
+        Ok(Some(Expr::Position(Box::new(Position {
+            substring,
+            expr,
+            span,
+        }))))
+    }
+
+    fn parse_overlay(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+        start: usize,
+    ) -> Result<Option<Self>, ParserError> {
+        let expr = Self::parse_expression(token_table, cursor)?;
+        if !is_word(token_table, *cursor, "PLACING") {
+            return Ok(None);
+        }
+        *cursor += 1;
+        let replacement = Self::parse_expression(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::From))?;
+        *cursor += 1;
+        let overlay_start = Self::parse_expression(token_table, cursor)?;
+        let length = if is_word(token_table, *cursor, "FOR") {
+            *cursor += 1;
+            Some(Self::parse_expression(token_table, cursor)?)
+        } else {
+            None
+        };
+        let span = Self::finish_special_function(token_table, cursor, start)?;
+        Ok(Some(Expr::Overlay(Box::new(Overlay {
+            expr,
+            replacement,
+            start: overlay_start,
+            length,
+            span,
+        }))))
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct BoolLiteral {
+    pub value: bool,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NullLiteral {
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DefaultValue {
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Between<'a> {
     pub is_not: bool,
     pub field: Box<Expr<'a>>,
@@ -638,6 +1607,12 @@ pub struct Between<'a> {
     pub upper: Box<Expr<'a>>,
 }
 
+impl<'a> Spanned for Between<'a> {
+    fn span(&self) -> Span {
+        self.field.span().union(self.upper.span())
+    }
+}
+
 impl<'a> Between<'a> {
     pub(crate) fn build(
         is_not: bool,
@@ -648,12 +1623,12 @@ impl<'a> Between<'a> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Between))?;
         *cursor += 1;
 
-        let lower = Box::new(Expr::parse_primary(token_table, cursor)?);
+        let lower = Box::new(Expr::parse_bound(token_table, cursor)?);
 
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::And))?;
         *cursor += 1;
 
-        let upper = Box::new(Expr::parse_primary(token_table, cursor)?);
+        let upper = Box::new(Expr::parse_bound(token_table, cursor)?);
 
         Ok(Self {
             is_not,
@@ -664,14 +1639,15 @@ impl<'a> Between<'a> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct In<'a> {
     pub is_not: bool,
     pub field: Box<Expr<'a>>,
     pub in_value: InValue<'a>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum InValue<'a> {
     List(MiniVec<Expr<'a>>),
     Subquery(SubSelectStatement<'a>),
@@ -687,58 +1663,49 @@ impl<'a> In<'a> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::In))?;
         *cursor += 1;
 
-        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
-        *cursor += 1;
-
-        let in_value = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Select)) {
-            InValue::Subquery(Box::new(SelectStatement::new(token_table, cursor)?))
-        } else {
-            let mut values = MiniVec::with_capacity(8);
-            loop {
-                match token_table.get_kind(*cursor) {
-                    Some(TokenKind::Comma) => {
+        let in_value = match Subquery::try_build(token_table, cursor)? {
+            Some(subquery) => InValue::Subquery(Box::new(subquery.query)),
+            None => {
+                expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+                *cursor += 1;
+                let mut values = MiniVec::with_capacity(8);
+                loop {
+                    values.push(Expr::parse_expression(token_table, cursor)?);
+                    if maybe_kind(token_table, cursor, &TokenKind::Comma) {
                         *cursor += 1;
-                        continue;
-                    }
-                    Some(TokenKind::RightParen) => {
-                        break;
-                    }
-                    Some(TokenKind::Keyword(_)) => {
+                    } else {
                         break;
                     }
-                    Some(_) => {
-                        let value = Expr::parse_primary(token_table, cursor)?;
-                        values.push(value);
-                    }
-                    _ => {
-                        return Err(ParserError::SyntaxError(*cursor, *cursor));
-                    }
                 }
+                expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+                *cursor += 1;
+                InValue::List(values)
             }
-            if values.is_empty() {
-                return Err(ParserError::SyntaxError(*cursor, *cursor));
-            }
-            InValue::List(values)
         };
-
-        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
-        *cursor += 1;
+        let span = field.span().union(token_table.span_at(*cursor - 1));
 
         Ok(Self {
             is_not,
             field,
             in_value,
+            span,
         })
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Like<'a> {
     pub is_not: bool,
     pub field: Box<Expr<'a>>,
     pub pattern: Box<Expr<'a>>,
 }
 
+impl<'a> Spanned for Like<'a> {
+    fn span(&self) -> Span {
+        self.field.span().union(self.pattern.span())
+    }
+}
+
 impl<'a> Like<'a> {
     pub(crate) fn build(
         is_not: bool,
@@ -749,7 +1716,7 @@ impl<'a> Like<'a> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Like))?;
         *cursor += 1;
 
-        let pattern = Box::new(Expr::parse_primary(token_table, cursor)?);
+        let pattern = Box::new(Expr::parse_bound(token_table, cursor)?);
 
         Ok(Self {
             is_not,
@@ -759,42 +1726,117 @@ impl<'a> Like<'a> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct IsNull<'a> {
     pub is_not: bool,
     pub field: Box<Expr<'a>>,
+    pub span: Span,
+}
+
+/// A query in parentheses, spanning the parentheses.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Subquery<'a> {
+    pub query: Query<'a>,
+    pub span: Span,
+}
+
+impl<'a> Subquery<'a> {
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        let start = *cursor;
+        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        *cursor += 1;
+        let query = Query::parse(token_table, cursor)?;
+        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
+        *cursor += 1;
+        Ok(Self {
+            query,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+
+    /// `(` 之后紧跟 SELECT/WITH 时一定是子查询；隔着多层括号时可能只是表达式的一部分，
+    /// 例如 `((SELECT 1) + 1)`，解析失败则恢复 cursor 返回 None
+    fn try_build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Option<Self>, ParserError> {
+        let start = *cursor;
+        let mut query_start = start;
+        while let Some(TokenKind::LeftParen) = token_table.get_kind(query_start) {
+            query_start += 1;
+        }
+        if !matches!(
+            token_table.get_kind(query_start),
+            Some(TokenKind::Keyword(Keyword::Select | Keyword::With))
+        ) {
+            return Ok(None);
+        }
+        match Self::build(token_table, cursor) {
+            Ok(subquery) => Ok(Some(subquery)),
+            Err(err) if query_start == start + 1 => Err(err),
+            Err(_) => {
+                *cursor = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Quantifier {
+    Any,
+    All,
+    /// A synonym for `ANY`.
+    Some,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Quantified<'a> {
+    pub left: Expr<'a>,
+    /// One of the comparison operators.
+    pub op: BinaryOperator,
+    pub quantifier: Quantifier,
+    pub subquery: Query<'a>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct ExistsExpr<'a> {
     pub is_not: bool,
     pub subquery: SubSelectStatement<'a>,
+    pub span: Span,
 }
 
 impl<'a> ExistsExpr<'a> {
+    /// `start` 为 `EXISTS`（或其前面的 `NOT`）所在的 token 位置。
     pub(crate) fn build(
         is_not: bool,
+        start: usize,
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Exists))?;
         *cursor += 1;
-        expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
-        *cursor += 1;
-        let select_stmt = SelectStatement::new(token_table, cursor)?;
-        expect_kind(token_table, cursor, &TokenKind::RightParen)?;
-        *cursor += 1;
+        let subquery = Subquery::build(token_table, cursor)?;
         Ok(Self {
             is_not,
-            subquery: Box::new(select_stmt),
+            subquery: Box::new(subquery.query),
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct WindowSpec<'a> {
+    /// The window this one builds on, e.g. `w` in `OVER (w ORDER BY x)`.
+    pub base: Option<Ident<'a>>,
     pub partition_by: Option<MiniVec<Expr<'a>>>,
     pub order_by: Option<Order<'a>>,
+    pub frame: Option<Box<WindowFrame<'a>>>,
+    pub span: Span,
 }
 
 impl<'a> WindowSpec<'a> {
@@ -803,32 +1845,34 @@ impl<'a> WindowSpec<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
+        let start = *cursor;
         *cursor += 1;
 
+        let base = match token_table.get_kind(*cursor) {
+            Some(TokenKind::Identifier) if WindowFrame::starts_at(token_table, *cursor) => None,
+            Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                let name = Ident::from_token(token_table, cursor)?;
+                *cursor += 1;
+                Some(name)
+            }
+            _ => None,
+        };
+
         let partition_by =
             if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Partition)) {
                 *cursor += 1;
                 expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::By))?;
                 *cursor += 1;
-                let mut cols = MiniVec::new();
+                let mut exprs = MiniVec::new();
                 loop {
-                    match token_table.get_kind(*cursor) {
-                        Some(TokenKind::Comma) => {
-                            *cursor += 1;
-                        }
-                        Some(TokenKind::Keyword(Keyword::Order)) | Some(TokenKind::RightParen) => {
-                            break;
-                        }
-                        Some(TokenKind::Identifier)
-                        | Some(TokenKind::Number)
-                        | Some(TokenKind::StringLiteral)
-                        | Some(TokenKind::Multiply) => {
-                            cols.push(Expr::build(token_table, cursor)?);
-                        }
-                        _ => break,
+                    exprs.push(Expr::parse_expression(token_table, cursor)?);
+                    if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                        *cursor += 1;
+                    } else {
+                        break;
                     }
                 }
-                Some(cols)
+                Some(exprs)
             } else {
                 None
             };
@@ -839,40 +1883,288 @@ impl<'a> WindowSpec<'a> {
             None
         };
 
+        let frame = if WindowFrame::starts_at(token_table, *cursor) {
+            Some(Box::new(WindowFrame::build(token_table, cursor)?))
+        } else {
+            None
+        };
+
         expect_kind(token_table, cursor, &TokenKind::RightParen)?;
         *cursor += 1;
         Ok(Self {
+            base,
             partition_by,
             order_by,
+            frame,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+    Groups,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WindowFrameBound<'a> {
+    /// `UNBOUNDED PRECEDING`
+    UnboundedPreceding,
+    /// `offset PRECEDING`
+    Preceding(Expr<'a>),
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `offset FOLLOWING`
+    Following(Expr<'a>),
+    /// `UNBOUNDED FOLLOWING`
+    UnboundedFollowing,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WindowFrameExclusion {
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`
+    Group,
+    /// `EXCLUDE TIES`
+    Ties,
+    /// `EXCLUDE NO OTHERS`
+    NoOthers,
+}
+
+/// `ROWS BETWEEN 1 PRECEDING AND CURRENT ROW` and the like.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WindowFrame<'a> {
+    pub units: WindowFrameUnits,
+    pub start: WindowFrameBound<'a>,
+    /// Only set for the `BETWEEN start AND end` form.
+    pub end: Option<WindowFrameBound<'a>>,
+    pub exclusion: Option<WindowFrameExclusion>,
+    pub span: Span,
+}
+
+impl<'a> WindowFrame<'a> {
+    fn units(token_table: &TokenTable, cursor: usize) -> Option<WindowFrameUnits> {
+        if is_word(token_table, cursor, "ROWS") {
+            Some(WindowFrameUnits::Rows)
+        } else if is_word(token_table, cursor, "RANGE") {
+            Some(WindowFrameUnits::Range)
+        } else if is_word(token_table, cursor, "GROUPS") {
+            Some(WindowFrameUnits::Groups)
+        } else {
+            None
+        }
+    }
+
+    /// ROWS/RANGE/GROUPS 不是保留字，后面跟着 BETWEEN 或边界时才是窗口帧，
+    /// 否则仍是列名或窗口名，例如 `ORDER BY range` 与 `OVER (rows ORDER BY a)`
+    pub(crate) fn starts_at(token_table: &TokenTable, cursor: usize) -> bool {
+        if Self::units(token_table, cursor).is_none() {
+            return false;
+        }
+        match token_table.get_kind(cursor + 1) {
+            Some(
+                TokenKind::Keyword(Keyword::Between) | TokenKind::Number | TokenKind::Placeholder,
+            ) => true,
+            Some(TokenKind::Identifier) => ["UNBOUNDED", "CURRENT", "INTERVAL"]
+                .iter()
+                .any(|word| is_word(token_table, cursor + 1, word)),
+            _ => false,
+        }
+    }
+
+    fn build(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
+        let start = *cursor;
+        let units = Self::units(token_table, *cursor)
+            .ok_or_else(|| syntax_error(token_table, cursor, &[TokenKind::Identifier]))?;
+        *cursor += 1;
+
+        let (start_bound, end_bound) =
+            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Between)) {
+                *cursor += 1;
+                let start_bound = WindowFrameBound::build(token_table, cursor)?;
+                expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::And))?;
+                *cursor += 1;
+                (
+                    start_bound,
+                    Some(WindowFrameBound::build(token_table, cursor)?),
+                )
+            } else {
+                (WindowFrameBound::build(token_table, cursor)?, None)
+            };
+
+        let exclusion = if is_word(token_table, *cursor, "EXCLUDE") {
+            *cursor += 1;
+            Some(WindowFrameExclusion::build(token_table, cursor)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            units,
+            start: start_bound,
+            end: end_bound,
+            exclusion,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+impl<'a> WindowFrameBound<'a> {
+    fn build(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
+        // 帧里的 UNBOUNDED、CURRENT ROW、PRECEDING、FOLLOWING 都按名字匹配
+        // offset 为 None 表示 UNBOUNDED
+        let offset = if is_word(token_table, *cursor, "UNBOUNDED") {
+            *cursor += 1;
+            None
+        } else if is_word(token_table, *cursor, "CURRENT") {
+            *cursor += 1;
+            expect_word(token_table, cursor, "ROW")?;
+            return Ok(Self::CurrentRow);
+        } else {
+            Some(Expr::parse_expression(token_table, cursor)?)
+        };
+        let bound = if is_word(token_table, *cursor, "PRECEDING") {
+            match offset {
+                Some(offset) => Self::Preceding(offset),
+                None => Self::UnboundedPreceding,
+            }
+        } else if is_word(token_table, *cursor, "FOLLOWING") {
+            match offset {
+                Some(offset) => Self::Following(offset),
+                None => Self::UnboundedFollowing,
+            }
+        } else {
+            return Err(syntax_error(token_table, cursor, &[TokenKind::Identifier]));
+        };
+        *cursor += 1;
+        Ok(bound)
+    }
+}
+
+impl WindowFrameExclusion {
+    fn build(token_table: &TokenTable, cursor: &mut usize) -> Result<Self, ParserError> {
+        // GROUP 是保留字，其余都按名字匹配
+        let exclusion = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Group)) {
+            *cursor += 1;
+            Self::Group
+        } else if is_word(token_table, *cursor, "CURRENT") {
+            *cursor += 1;
+            expect_word(token_table, cursor, "ROW")?;
+            Self::CurrentRow
+        } else if is_word(token_table, *cursor, "TIES") {
+            *cursor += 1;
+            Self::Ties
+        } else if is_word(token_table, *cursor, "NO") {
+            *cursor += 1;
+            expect_word(token_table, cursor, "OTHERS")?;
+            Self::NoOthers
+        } else {
+            return Err(syntax_error(
+                token_table,
+                cursor,
+                &[TokenKind::Keyword(Keyword::Group), TokenKind::Identifier],
+            ));
+        };
+        Ok(exclusion)
+    }
+}
+
+/// A window definition in the `WINDOW` clause, e.g. `w AS (PARTITION BY a)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct NamedWindow<'a> {
+    pub name: Ident<'a>,
+    pub spec: WindowSpec<'a>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for NamedWindow<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> NamedWindow<'a> {
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        let start = *cursor;
+        let name = Ident::from_token(token_table, cursor)?;
+        *cursor += 1;
+        expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::As))?;
+        *cursor += 1;
+        let spec = WindowSpec::build(token_table, cursor)?;
+        Ok(Self {
+            name,
+            spec,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// What follows `OVER`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WindowRef<'a> {
+    /// `OVER w`, a window from the `WINDOW` clause.
+    Named { name: Ident<'a>, span: Span },
+    /// `OVER (...)`
+    Spec(WindowSpec<'a>),
+}
+
+impl<'a> Spanned for WindowRef<'a> {
+    fn span(&self) -> Span {
+        match self {
+            WindowRef::Named { span, .. } => *span,
+            WindowRef::Spec(spec) => spec.span,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct WindowFunction<'a> {
     pub function: FunctionCall<'a>,
-    pub window_spec: WindowSpec<'a>,
+    /// `IGNORE NULLS` written between the call and `OVER`, e.g.
+    /// `FIRST_VALUE(x) IGNORE NULLS OVER w`.
+    pub null_treatment: Option<NullTreatment>,
+    pub window: WindowRef<'a>,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for WindowFunction<'a> {
+    fn span(&self) -> Span {
+        self.function.span.union(self.window.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct CaseExpr<'a> {
     pub condition: Option<Box<Expr<'a>>>,
     pub when_clauses: MiniVec<WhenClause<'a>>,
     pub else_result: Option<Box<Expr<'a>>>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct WhenClause<'a> {
     pub condition: Box<Expr<'a>>,
     pub result: Box<Expr<'a>>,
 }
 
+impl<'a> Spanned for WhenClause<'a> {
+    fn span(&self) -> Span {
+        self.condition.span().union(self.result.span())
+    }
+}
+
 impl<'a> CaseExpr<'a> {
     pub(crate) fn build(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Case))?;
+        let start = *cursor;
         *cursor += 1;
 
         let condition = if !maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::When)) {
@@ -915,6 +2207,7 @@ impl<'a> CaseExpr<'a> {
             condition,
             when_clauses,
             else_result,
+            span: token_table.span_between(start, *cursor),
         })
     }
 }
@@ -932,18 +2225,17 @@ mod test {
                 BinaryOp, BinaryOperator, Expr, Field, FunctionCall, NumericLiteral, Star,
                 StringLiteral,
             },
+            ident::Ident,
         },
         keyword::Keyword,
+        span::Span,
         token::{TokenKind, TokenTable},
     };
 
     fn make_table<'a>(source: &'a str, entries: Vec<(TokenKind, usize, usize)>) -> TokenTable<'a> {
         let mut table = TokenTable::with_source(source);
         for (kind, start, end) in entries {
-            table.push(
-                kind,
-                unsafe { str::from_utf8_unchecked(&source.as_bytes()[start..=end])},
-            );
+            table.push(kind, Span::new(start, end + 1));
         }
         table
     }
@@ -964,8 +2256,9 @@ mod test {
         assert_eq!(
             expr,
             Expr::Field(Field {
-                prefix: Some("ab"),
-                name: "cd",
+                prefix: Some(Ident::new("ab")),
+                name: Ident::new("cd"),
+                span: Span::new(0, 5),
             })
         );
         assert_eq!(cursor, 3);
@@ -986,11 +2279,13 @@ mod test {
         assert_eq!(
             alias,
             Alias {
-                name: Some("e"),
+                name: Some(Ident::new("e")),
                 value: Expr::Field(Field {
-                    prefix: Some("ab"),
-                    name: "cd",
-                })
+                    prefix: Some(Ident::new("ab")),
+                    name: Ident::new("cd"),
+                    span: Span::new(0, 5),
+                }),
+                span: Span::new(0, 10),
             }
         );
         assert_eq!(cursor, 5);
@@ -1010,11 +2305,13 @@ mod test {
         assert_eq!(
             alias2,
             Alias {
-                name: Some("e"),
+                name: Some(Ident::new("e")),
                 value: Expr::Field(Field {
-                    prefix: Some("ab"),
-                    name: "cd",
-                })
+                    prefix: Some(Ident::new("ab")),
+                    name: Ident::new("cd"),
+                    span: Span::new(0, 5),
+                }),
+                span: Span::new(0, 7),
             }
         );
         assert_eq!(cursor, 4);
@@ -1036,13 +2333,20 @@ mod test {
         let expr = Expr::class_function_call(&token_table, &mut cursor).unwrap();
         assert_eq!(
             expr,
-            Expr::FunctionCall(FunctionCall {
+            Expr::FunctionCall(Box::new(FunctionCall {
                 distinct: false,
                 name: "foo",
                 args: mini_vec![Expr::StringLiteral(StringLiteral {
                     value: "'hello'",
-                })]
-            })
+                    span: Span::new(4, 11),
+                })],
+                null_treatment: None,
+                order_by: None,
+                limit: None,
+                within_group: None,
+                filter: None,
+                span: Span::new(0, 12),
+            }))
         );
         assert_eq!(cursor, 4);
     }
@@ -1065,18 +2369,26 @@ mod test {
         let expr = Expr::class_function_call(&token_table, &mut cursor).unwrap();
         assert_eq!(
             expr,
-            Expr::FunctionCall(FunctionCall {
+            Expr::FunctionCall(Box::new(FunctionCall {
                 name: "bar",
                 args: mini_vec![
                     Expr::StringLiteral(StringLiteral {
-                        value: "'x'"
+                        value: "'x'",
+                        span: Span::new(4, 7),
                     }),
                     Expr::StringLiteral(StringLiteral {
-                        value: "'y'"
+                        value: "'y'",
+                        span: Span::new(9, 12),
                     }),
                 ],
                 distinct: false,
-            })
+                null_treatment: None,
+                order_by: None,
+                limit: None,
+                within_group: None,
+                filter: None,
+                span: Span::new(0, 13),
+            }))
         );
         assert_eq!(cursor, 6);
     }
@@ -1096,7 +2408,7 @@ mod test {
         );
         let mut cursor = 0;
         let expr = Expr::class_function_call(&token_table, &mut cursor);
-        assert_eq!(expr, Err(ParserError::SyntaxError(4, 4)));
+        assert_eq!(expr, Err(ParserError::SyntaxError(Span::new(6, 7))));
     }
 
     #[test]
@@ -1119,15 +2431,18 @@ mod test {
             Expr::BinaryOp(Box::new(BinaryOp {
                 op: BinaryOperator::Add,
                 left: Expr::NumericLiteral(NumericLiteral {
-                    value: "1"
+                    value: "1",
+                    span: Span::new(0, 1),
                 }),
                 right: Expr::BinaryOp(Box::new(BinaryOp {
                     op: BinaryOperator::Multiply,
                     left: Expr::NumericLiteral(NumericLiteral {
-                        value: "2"
+                        value: "2",
+                        span: Span::new(4, 5),
                     }),
                     right: Expr::NumericLiteral(NumericLiteral {
-                        value: "3"
+                        value: "3",
+                        span: Span::new(8, 9),
                     }),
                 })),
             }))
@@ -1157,14 +2472,17 @@ mod test {
                 left: Expr::BinaryOp(Box::new(BinaryOp {
                     op: BinaryOperator::Multiply,
                     left: Expr::NumericLiteral(NumericLiteral {
-                        value: "1"
+                        value: "1",
+                        span: Span::new(0, 1),
                     }),
                     right: Expr::NumericLiteral(NumericLiteral {
-                        value: "2"
+                        value: "2",
+                        span: Span::new(4, 5),
                     }),
                 })),
                 right: Expr::NumericLiteral(NumericLiteral {
-                    value: "3"
+                    value: "3",
+                    span: Span::new(8, 9),
                 }),
             }))
         );
@@ -1195,14 +2513,17 @@ mod test {
                 left: Expr::BinaryOp(Box::new(BinaryOp {
                     op: BinaryOperator::Add,
                     left: Expr::NumericLiteral(NumericLiteral {
-                        value: "1"
+                        value: "1",
+                        span: Span::new(1, 2),
                     }),
                     right: Expr::NumericLiteral(NumericLiteral {
-                        value: "2"
+                        value: "2",
+                        span: Span::new(5, 6),
                     }),
                 })),
                 right: Expr::NumericLiteral(NumericLiteral {
-                    value: "3"
+                    value: "3",
+                    span: Span::new(10, 11),
                 }),
             }))
         );
@@ -1227,10 +2548,12 @@ mod test {
             Expr::BinaryOp(Box::new(BinaryOp {
                 op: BinaryOperator::Divide,
                 left: Expr::NumericLiteral(NumericLiteral {
-                    value: "1"
+                    value: "1",
+                    span: Span::new(0, 1),
                 }),
                 right: Expr::NumericLiteral(NumericLiteral {
-                    value: "2"
+                    value: "2",
+                    span: Span::new(4, 5),
                 }),
             }))
         );
@@ -1252,10 +2575,12 @@ mod test {
             Expr::BinaryOp(Box::new(BinaryOp {
                 op: BinaryOperator::Mod,
                 left: Expr::NumericLiteral(NumericLiteral {
-                    value: "1"
+                    value: "1",
+                    span: Span::new(0, 1),
                 }),
                 right: Expr::NumericLiteral(NumericLiteral {
-                    value: "2"
+                    value: "2",
+                    span: Span::new(4, 5),
                 }),
             }))
         );
@@ -1280,10 +2605,12 @@ mod test {
                 op: BinaryOperator::Equal,
                 left: Expr::Field(Field {
                     prefix: None,
-                    name: "id"
+                    name: Ident::new("id"),
+                    span: Span::new(0, 2),
                 }),
                 right: Expr::NumericLiteral(NumericLiteral {
-                    value: "1"
+                    value: "1",
+                    span: Span::new(5, 6),
                 }),
             }))
         );
@@ -1306,10 +2633,12 @@ mod test {
                 op: BinaryOperator::NotEqual,
                 left: Expr::Field(Field {
                     prefix: None,
-                    name: "id"
+                    name: Ident::new("id"),
+                    span: Span::new(0, 2),
                 }),
                 right: Expr::StringLiteral(StringLiteral {
-                    value: "'x'"
+                    value: "'x'",
+                    span: Span::new(6, 9),
                 }),
             }))
         );
@@ -1338,16 +2667,19 @@ mod test {
                     op: BinaryOperator::And,
                     left: Expr::Field(Field {
                         prefix: None,
-                        name: "a"
+                        name: Ident::new("a"),
+                        span: Span::new(0, 1),
                     }),
                     right: Expr::Field(Field {
                         prefix: None,
-                        name: "b"
+                        name: Ident::new("b"),
+                        span: Span::new(6, 7),
                     }),
                 })),
                 right: Expr::Field(Field {
                     prefix: None,
-                    name: "c"
+                    name: Ident::new("c"),
+                    span: Span::new(11, 12),
                 }),
             }))
         );
@@ -1363,7 +2695,8 @@ mod test {
         assert_eq!(
             expr,
             Expr::StringLiteral(StringLiteral {
-                value: "'hello'"
+                value: "'hello'",
+                span: Span::new(0, 7),
             })
         );
         assert_eq!(cursor, 1);
@@ -1378,7 +2711,8 @@ mod test {
         assert_eq!(
             expr,
             Expr::NumericLiteral(NumericLiteral {
-                value: "12345"
+                value: "12345",
+                span: Span::new(0, 5),
             })
         );
         assert_eq!(cursor, 1);
@@ -1390,7 +2724,13 @@ mod test {
         let token_table = make_table(source, vec![(TokenKind::Multiply, 0, 0)]);
         let mut cursor = 0;
         let expr = Expr::class_star(&token_table, &mut cursor).unwrap();
-        assert_eq!(expr, Expr::Star(Star { prefix: None }));
+        assert_eq!(
+            expr,
+            Expr::Star(Star {
+                prefix: None,
+                span: Span::new(0, 1)
+            })
+        );
         assert_eq!(cursor, 1);
 
         let source2 = "t.*";
@@ -1407,7 +2747,8 @@ mod test {
         assert_eq!(
             expr,
             Expr::Star(Star {
-                prefix: Some("t")
+                prefix: Some(Ident::new("t")),
+                span: Span::new(0, 3),
             })
         );
         assert_eq!(cursor, 3);
@@ -1423,7 +2764,8 @@ mod test {
             expr,
             Expr::Field(Field {
                 prefix: None,
-                name: "col1"
+                name: Ident::new("col1"),
+                span: Span::new(0, 4),
             })
         );
         assert_eq!(cursor, 1);
@@ -1445,8 +2787,9 @@ mod test {
         assert_eq!(
             expr,
             Expr::Field(Field {
-                prefix: Some("usr"),
-                name: "id"
+                prefix: Some(Ident::new("usr")),
+                name: Ident::new("id"),
+                span: Span::new(0, 6),
             })
         );
         assert_eq!(cursor, 3);
@@ -1481,13 +2824,20 @@ mod test {
         let expr = Expr::class_function_call(&token_table, &mut cursor).unwrap();
         assert_eq!(
             expr,
-            Expr::FunctionCall(FunctionCall {
+            Expr::FunctionCall(Box::new(FunctionCall {
                 distinct: true,
                 name: "c",
                 args: mini_vec![Expr::NumericLiteral(NumericLiteral {
-                    value: "3"
-                })]
-            })
+                    value: "3",
+                    span: Span::new(11, 12),
+                })],
+                null_treatment: None,
+                order_by: None,
+                limit: None,
+                within_group: None,
+                filter: None,
+                span: Span::new(0, 13),
+            }))
         );
     }
 }
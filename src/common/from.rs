@@ -1,17 +1,34 @@
+use minivec::MiniVec;
+
 use crate::{
     ParserError,
     ast::select::SubSelectStatement,
-    common::{alias::Alias, expr::Expr, utils::{expect_kind, maybe_kind}},
+    common::{
+        alias::Alias,
+        expr::Expr,
+        ident::Ident,
+        utils::{expect_kind, maybe_kind},
+    },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Table<'a> {
     Name(Alias<'a, Expr<'a>>),
     SubQuery(Alias<'a, SubSelectStatement<'a>>),
 }
 
+impl<'a> Spanned for Table<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Table::Name(alias) => alias.span,
+            Table::SubQuery(alias) => alias.span,
+        }
+    }
+}
+
 impl<'a> Table<'a> {
     pub(crate) fn class_name_with_single(
         token_table: &TokenTable<'a>,
@@ -20,6 +37,7 @@ impl<'a> Table<'a> {
         let expr = Expr::class_field(token_table, cursor)?;
         Ok(Self::Name(Alias {
             name: None,
+            span: expr.span(),
             value: expr,
         }))
     }
@@ -38,7 +56,7 @@ impl<'a> Table<'a> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum JoinType {
     LeftJoin,
     RightJoin,
@@ -47,7 +65,7 @@ pub enum JoinType {
     FullJoin,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum From<'a> {
     Table(Table<'a>),
     CrossJoin {
@@ -63,7 +81,8 @@ pub enum From<'a> {
         left: Box<From<'a>>,
         right: Box<From<'a>>,
         join_type: JoinType,
-        using: Vec<&'a str>,
+        using: Vec<Ident<'a>>,
+        span: Span,
     },
     LeftJoin {
         left: Box<From<'a>>,
@@ -87,6 +106,38 @@ pub enum From<'a> {
     },
 }
 
+impl<'a> Spanned for From<'a> {
+    fn span(&self) -> Span {
+        match self {
+            From::Table(table) => table.span(),
+            From::CrossJoin { left, right } | From::NaturalJoin { left, right, .. } => {
+                left.span().union(right.span())
+            }
+            From::JoinUsing { span, .. } => *span,
+            From::LeftJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::RightJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::InnerJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::FullJoin {
+                left,
+                right,
+                condition,
+            } => left.span().union(right.span()).union(condition.span()),
+        }
+    }
+}
+
 impl<'a> From<'a> {
     pub(crate) fn parse(
         token_table: &TokenTable<'a>,
@@ -96,11 +147,28 @@ impl<'a> From<'a> {
         Self::parse_joins(token_table, cursor, From::Table(left))
     }
 
+    /// 逗号分隔的表列表，例如 UPDATE ... FROM 与 DELETE ... USING
+    pub(crate) fn parse_list(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<MiniVec<Self>, ParserError> {
+        let mut list = MiniVec::new();
+        loop {
+            list.push(Self::parse(token_table, cursor)?);
+            if maybe_kind(token_table, cursor, &TokenKind::Comma) {
+                *cursor += 1;
+            } else {
+                break;
+            }
+        }
+        Ok(list)
+    }
+
     fn parse_join_on_using(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
         is_natural: bool,
-    ) -> Result<Option<Vec<&'a str>>, ParserError> {
+    ) -> Result<Option<Vec<Ident<'a>>>, ParserError> {
         if is_natural {
             return Ok(None);
         }
@@ -115,15 +183,15 @@ impl<'a> From<'a> {
     fn parse_using_list(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
-    ) -> Result<Vec<&'a str>, ParserError> {
+    ) -> Result<Vec<Ident<'a>>, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::LeftParen)?;
         *cursor += 1;
 
         let mut columns = Vec::new();
         loop {
             match token_table.get_kind(*cursor) {
-                Some(TokenKind::Identifier) => {
-                    columns.push(token_table.source_at(*cursor));
+                Some(TokenKind::Identifier | TokenKind::QuotedIdentifier) => {
+                    columns.push(Ident::from_token(token_table, cursor)?);
                     *cursor += 1;
                 }
                 Some(TokenKind::Comma) => {
@@ -133,12 +201,12 @@ impl<'a> From<'a> {
                     *cursor += 1;
                     break;
                 }
-                _ => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                _ => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
             }
         }
 
         if columns.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
         Ok(columns)
     }
@@ -172,6 +240,7 @@ impl<'a> From<'a> {
                         Self::parse_join_on_using(token_table, cursor, false)?
                     {
                         current = From::JoinUsing {
+                            span: left.span().union(token_table.span_at(*cursor - 1)),
                             left,
                             right,
                             join_type: JoinType::InnerJoin,
@@ -204,6 +273,7 @@ impl<'a> From<'a> {
                         Self::parse_join_on_using(token_table, cursor, false)?
                     {
                         current = From::JoinUsing {
+                            span: left.span().union(token_table.span_at(*cursor - 1)),
                             left,
                             right,
                             join_type: JoinType::InnerJoin,
@@ -239,6 +309,7 @@ impl<'a> From<'a> {
                         Self::parse_join_on_using(token_table, cursor, false)?
                     {
                         current = From::JoinUsing {
+                            span: left.span().union(token_table.span_at(*cursor - 1)),
                             left,
                             right,
                             join_type: JoinType::LeftJoin,
@@ -274,6 +345,7 @@ impl<'a> From<'a> {
                         Self::parse_join_on_using(token_table, cursor, false)?
                     {
                         current = From::JoinUsing {
+                            span: left.span().union(token_table.span_at(*cursor - 1)),
                             left,
                             right,
                             join_type: JoinType::RightJoin,
@@ -309,6 +381,7 @@ impl<'a> From<'a> {
                         Self::parse_join_on_using(token_table, cursor, false)?
                     {
                         current = From::JoinUsing {
+                            span: left.span().union(token_table.span_at(*cursor - 1)),
                             left,
                             right,
                             join_type: JoinType::FullJoin,
@@ -327,21 +400,18 @@ impl<'a> From<'a> {
                 }
                 Some(TokenKind::Keyword(Keyword::Cross)) => {
                     if is_natural {
-                        return Err(ParserError::SyntaxError(*cursor, *cursor));
+                        return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
                     }
                     *cursor += 1;
                     expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Join))?;
                     *cursor += 1;
                     let left = Box::new(Self::parse_joins(token_table, cursor, current)?);
                     let right = Box::new(Self::parse(token_table, cursor)?);
-                    current = From::CrossJoin {
-                        left,
-                        right,
-                    };
+                    current = From::CrossJoin { left, right };
                 }
                 _ => {
                     if is_natural {
-                        return Err(ParserError::SyntaxError(*cursor, *cursor));
+                        return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
                     }
                     break;
                 }
@@ -360,10 +430,7 @@ mod tests {
     fn make_table<'a>(source: &'a str, tokens: Vec<(TokenKind, usize, usize)>) -> TokenTable<'a> {
         let mut table = TokenTable::with_source(source);
         for (kind, start, end) in tokens {
-            table.push(
-                kind,
-                unsafe { str::from_utf8_unchecked(&source.as_bytes()[start..=end]) },
-            );
+            table.push(kind, Span::new(start, end + 1));
         }
         table
     }
@@ -380,8 +447,10 @@ mod tests {
                 name: None,
                 value: Expr::Field(Field {
                     prefix: None,
-                    name: "users",
+                    name: Ident::new("users"),
+                    span: Span::new(0, 5),
                 }),
+                span: Span::new(0, 5),
             }))
         );
     }
@@ -398,11 +467,13 @@ mod tests {
         assert_eq!(
             result,
             From::Table(Table::Name(Alias {
-                name: Some("u"),
+                name: Some(Ident::new("u")),
                 value: Expr::Field(Field {
                     prefix: None,
-                    name: "users",
+                    name: Ident::new("users"),
+                    span: Span::new(0, 5),
                 }),
+                span: Span::new(0, 7),
             }))
         );
     }
@@ -428,19 +499,40 @@ mod tests {
             op: BinaryOperator::Equal,
             left: Expr::Field(Field {
                 prefix: None,
-                name: "user_id",
+                name: Ident::new("user_id"),
+                span: Span::new(21, 28),
             }),
             right: Expr::Field(Field {
                 prefix: None,
-                name: "user_id",
+                name: Ident::new("user_id"),
+                span: Span::new(31, 38),
             }),
         }));
 
-        assert_eq!(result, From::InnerJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            condition: expected_condition,
-        });
+        assert_eq!(
+            result,
+            From::InnerJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(11, 17)
+                    }),
+                    span: Span::new(11, 17)
+                }))),
+                condition: expected_condition,
+            }
+        );
         // if let From::InnerJoin {
         //     left,
         //     right,
@@ -493,19 +585,66 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::InnerJoin {
-            left: Box::new(From::InnerJoin {
-                left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-                right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-                condition: Expr::BinaryOp(Box::new(
-                    BinaryOp { op: BinaryOperator::Equal, left: Expr::Field(Field { prefix: None, name: "user_id" }), right: Expr::Field(Field { prefix: None, name: "user_id" }) },
-                ))
-            }),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "order_items" }) }))),
-            condition: Expr::BinaryOp(Box::new(
-                BinaryOp {op: BinaryOperator::Equal, left: Expr::Field(Field { prefix: None, name: "order_id" }), right: Expr::Field(Field { prefix: None, name: "order_id" })}
-            ))
-        });
+        assert_eq!(
+            result,
+            From::InnerJoin {
+                left: Box::new(From::InnerJoin {
+                    left: Box::new(From::Table(Table::Name(Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("users"),
+                            span: Span::new(0, 5)
+                        }),
+                        span: Span::new(0, 5)
+                    }))),
+                    right: Box::new(From::Table(Table::Name(Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("orders"),
+                            span: Span::new(11, 17)
+                        }),
+                        span: Span::new(11, 17)
+                    }))),
+                    condition: Expr::BinaryOp(Box::new(BinaryOp {
+                        op: BinaryOperator::Equal,
+                        left: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("user_id"),
+                            span: Span::new(21, 28)
+                        }),
+                        right: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("user_id"),
+                            span: Span::new(31, 38)
+                        })
+                    },))
+                }),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("order_items"),
+                        span: Span::new(44, 55)
+                    }),
+                    span: Span::new(44, 55)
+                }))),
+                condition: Expr::BinaryOp(Box::new(BinaryOp {
+                    op: BinaryOperator::Equal,
+                    left: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("order_id"),
+                        span: Span::new(59, 67)
+                    }),
+                    right: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("order_id"),
+                        span: Span::new(70, 78)
+                    })
+                }))
+            }
+        );
     }
 
     #[test]
@@ -528,15 +667,19 @@ mod tests {
                     name: None,
                     value: Expr::Field(Field {
                         prefix: None,
-                        name: "u",
+                        name: Ident::new("u"),
+                        span: Span::new(0, 1),
                     }),
+                    span: Span::new(0, 1),
                 }))),
                 right: Box::new(From::Table(Table::Name(Alias {
                     name: None,
                     value: Expr::Field(Field {
                         prefix: None,
-                        name: "o",
+                        name: Ident::new("o"),
+                        span: Span::new(13, 14),
                     }),
+                    span: Span::new(13, 14),
                 }))),
             }
         );
@@ -560,9 +703,11 @@ mod tests {
             From::Table(Table::Name(Alias {
                 name: None,
                 value: Expr::Field(Field {
-                    prefix: Some("users"),
-                    name: "id"
-                })
+                    prefix: Some(Ident::new("users")),
+                    name: Ident::new("id"),
+                    span: Span::new(0, 8),
+                }),
+                span: Span::new(0, 8),
             }))
         );
     }
@@ -584,11 +729,30 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::NaturalJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::InnerJoin,
-        });
+        assert_eq!(
+            result,
+            From::NaturalJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(19, 25)
+                    }),
+                    span: Span::new(19, 25)
+                }))),
+                join_type: JoinType::InnerJoin,
+            }
+        );
     }
 
     #[test]
@@ -605,11 +769,30 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::NaturalJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::LeftJoin,
-        });
+        assert_eq!(
+            result,
+            From::NaturalJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(24, 30)
+                    }),
+                    span: Span::new(24, 30)
+                }))),
+                join_type: JoinType::LeftJoin,
+            }
+        );
     }
 
     #[test]
@@ -626,11 +809,30 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::NaturalJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::RightJoin,
-        });
+        assert_eq!(
+            result,
+            From::NaturalJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(25, 31)
+                    }),
+                    span: Span::new(25, 31)
+                }))),
+                join_type: JoinType::RightJoin,
+            }
+        );
     }
 
     #[test]
@@ -647,11 +849,30 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::NaturalJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::FullJoin,
-        });
+        assert_eq!(
+            result,
+            From::NaturalJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(24, 30)
+                    }),
+                    span: Span::new(24, 30)
+                }))),
+                join_type: JoinType::FullJoin,
+            }
+        );
     }
 
     #[test]
@@ -673,15 +894,42 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::NaturalJoin {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "a" }) }))),
-            right: Box::new(From::NaturalJoin {
-                left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "b" }) }))),
-                right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "c" }) }))),
+        assert_eq!(
+            result,
+            From::NaturalJoin {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("a"),
+                        span: Span::new(0, 1)
+                    }),
+                    span: Span::new(0, 1)
+                }))),
+                right: Box::new(From::NaturalJoin {
+                    left: Box::new(From::Table(Table::Name(Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("b"),
+                            span: Span::new(15, 16)
+                        }),
+                        span: Span::new(15, 16)
+                    }))),
+                    right: Box::new(From::Table(Table::Name(Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("c"),
+                            span: Span::new(30, 31)
+                        }),
+                        span: Span::new(30, 31)
+                    }))),
+                    join_type: JoinType::InnerJoin,
+                }),
                 join_type: JoinType::InnerJoin,
-            }),
-            join_type: JoinType::InnerJoin,
-        });
+            }
+        );
     }
 
     // ========================================================================
@@ -704,12 +952,32 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::JoinUsing {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::InnerJoin,
-            using: vec!["user_id"],
-        });
+        assert_eq!(
+            result,
+            From::JoinUsing {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(11, 17)
+                    }),
+                    span: Span::new(11, 17)
+                }))),
+                join_type: JoinType::InnerJoin,
+                using: vec![Ident::new("user_id")],
+                span: Span::new(0, 33),
+            }
+        );
     }
 
     #[test]
@@ -730,12 +998,32 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::JoinUsing {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::InnerJoin,
-            using: vec!["user_id", "order_id"],
-        });
+        assert_eq!(
+            result,
+            From::JoinUsing {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(11, 17)
+                    }),
+                    span: Span::new(11, 17)
+                }))),
+                join_type: JoinType::InnerJoin,
+                using: vec![Ident::new("user_id"), Ident::new("order_id")],
+                span: Span::new(0, 43),
+            }
+        );
     }
 
     #[test]
@@ -755,12 +1043,32 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::JoinUsing {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::LeftJoin,
-            using: vec!["id"],
-        });
+        assert_eq!(
+            result,
+            From::JoinUsing {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(16, 22)
+                    }),
+                    span: Span::new(16, 22)
+                }))),
+                join_type: JoinType::LeftJoin,
+                using: vec![Ident::new("id")],
+                span: Span::new(0, 33),
+            }
+        );
     }
 
     #[test]
@@ -781,12 +1089,32 @@ mod tests {
         );
         let mut cursor = 0;
         let result = From::parse(&tokens, &mut cursor).unwrap();
-        assert_eq!(result, From::JoinUsing {
-            left: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "users" }) }))),
-            right: Box::new(From::Table(Table::Name(Alias { name: None, value: Expr::Field(Field { prefix: None, name: "orders" }) }))),
-            join_type: JoinType::RightJoin,
-            using: vec!["id"],
-        });
+        assert_eq!(
+            result,
+            From::JoinUsing {
+                left: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(0, 5)
+                    }),
+                    span: Span::new(0, 5)
+                }))),
+                right: Box::new(From::Table(Table::Name(Alias {
+                    name: None,
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("orders"),
+                        span: Span::new(23, 29)
+                    }),
+                    span: Span::new(23, 29)
+                }))),
+                join_type: JoinType::RightJoin,
+                using: vec![Ident::new("id")],
+                span: Span::new(0, 40),
+            }
+        );
     }
 
     #[test]
@@ -2,12 +2,16 @@ use minivec::MiniVec;
 
 use crate::{
     ParserError,
-    common::{expr::Expr, utils::expect_kind},
+    common::{
+        expr::Expr,
+        utils::{expect_kind, starts_clause},
+    },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum GroupByExpr<'a> {
     Simple(Expr<'a>),
     GroupingSets(Vec<Vec<Expr<'a>>>),
@@ -15,9 +19,16 @@ pub enum GroupByExpr<'a> {
     Rollup(Vec<Expr<'a>>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Group<'a> {
     pub columns: MiniVec<GroupByExpr<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for Group<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> Group<'a> {
@@ -42,12 +53,12 @@ impl<'a> Group<'a> {
                     let expr = Expr::build(token_table, cursor)?;
                     exprs.push(expr);
                 }
-                None => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                None => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
             }
         }
 
         if exprs.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
         Ok(exprs)
     }
@@ -88,7 +99,9 @@ impl<'a> Group<'a> {
                                 let expr = Expr::build(token_table, cursor)?;
                                 exprs.push(expr);
                             }
-                            None => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                            None => {
+                                return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+                            }
                         }
                     }
                     sets.push(exprs);
@@ -97,12 +110,12 @@ impl<'a> Group<'a> {
                     let expr = Expr::build(token_table, cursor)?;
                     sets.push(vec![expr]);
                 }
-                None => return Err(ParserError::SyntaxError(*cursor, *cursor)),
+                None => return Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
             }
         }
 
         if sets.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
         Ok(sets)
     }
@@ -112,6 +125,7 @@ impl<'a> Group<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Group))?;
+        let start = *cursor;
         *cursor += 1;
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::By))?;
         *cursor += 1;
@@ -130,7 +144,7 @@ impl<'a> Group<'a> {
                 )) | Some(TokenKind::RightParen | TokenKind::Delimiter)
                 | None
             );
-            if is_clause_kw {
+            if is_clause_kw || starts_clause(token_table, *cursor) {
                 break;
             }
             match token_table.get_kind(*cursor) {
@@ -160,22 +174,23 @@ impl<'a> Group<'a> {
             }
         }
 
-        Ok(Self { columns })
+        Ok(Self {
+            columns,
+            span: token_table.span_between(start, *cursor),
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::ident::Ident;
     use crate::token::TokenKind;
 
     fn make_table<'a>(source: &'a str, tokens: Vec<(TokenKind, usize, usize)>) -> TokenTable<'a> {
         let mut table = TokenTable::with_source(source);
         for (kind, start, end) in tokens {
-            table.push(
-                kind,
-                unsafe { std::str::from_utf8_unchecked(&source.as_bytes()[start..=end]) },
-            );
+            table.push(kind, Span::new(start, end + 1));
         }
         table
     }
@@ -197,7 +212,8 @@ mod tests {
             result.columns[0],
             GroupByExpr::Simple(Expr::Field(crate::common::expr::Field {
                 prefix: None,
-                name: "a"
+                name: Ident::new("a"),
+                span: Span::new(9, 10),
             }))
         );
     }
@@ -221,14 +237,16 @@ mod tests {
             result.columns[0],
             GroupByExpr::Simple(Expr::Field(crate::common::expr::Field {
                 prefix: None,
-                name: "a"
+                name: Ident::new("a"),
+                span: Span::new(9, 10),
             }))
         );
         assert_eq!(
             result.columns[1],
             GroupByExpr::Simple(Expr::Field(crate::common::expr::Field {
                 prefix: None,
-                name: "b"
+                name: Ident::new("b"),
+                span: Span::new(12, 13),
             }))
         );
     }
@@ -260,11 +278,13 @@ mod tests {
             GroupByExpr::Rollup(vec![
                 Expr::Field(crate::common::expr::Field {
                     prefix: None,
-                    name: "a"
+                    name: Ident::new("a"),
+                    span: Span::new(17, 18),
                 }),
                 Expr::Field(crate::common::expr::Field {
                     prefix: None,
-                    name: "b"
+                    name: Ident::new("b"),
+                    span: Span::new(20, 21),
                 }),
             ])
         );
@@ -294,12 +314,13 @@ mod tests {
         let mut cursor = 0;
         let result = Group::build(&tokens, &mut cursor).unwrap();
         assert_eq!(result.columns.len(), 1);
-        let expected_exprs = vec!["a", "b", "c"]
+        let expected_exprs = vec![("a", 15), ("b", 18), ("c", 21)]
             .into_iter()
-            .map(|name| {
+            .map(|(name, start)| {
                 Expr::Field(crate::common::expr::Field {
                     prefix: None,
-                    name,
+                    name: Ident::new(name),
+                    span: Span::new(start, start + 1),
                 })
             })
             .collect::<Vec<_>>();
@@ -341,14 +362,16 @@ mod tests {
                     sets[0][0],
                     Expr::Field(crate::common::expr::Field {
                         prefix: None,
-                        name: "a"
+                        name: Ident::new("a"),
+                        span: Span::new(25, 26),
                     })
                 );
                 assert_eq!(
                     sets[0][1],
                     Expr::Field(crate::common::expr::Field {
                         prefix: None,
-                        name: "b"
+                        name: Ident::new("b"),
+                        span: Span::new(28, 29),
                     })
                 );
                 assert_eq!(sets[1].len(), 1);
@@ -356,7 +379,8 @@ mod tests {
                     sets[1][0],
                     Expr::Field(crate::common::expr::Field {
                         prefix: None,
-                        name: "c"
+                        name: Ident::new("c"),
+                        span: Span::new(32, 33),
                     })
                 );
             }
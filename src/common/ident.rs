@@ -0,0 +1,127 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    ParserError,
+    common::utils::syntax_error,
+    token::{TokenKind, TokenTable},
+};
+
+/// How an identifier was quoted in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// `"name"`
+    Double,
+    /// `` `name` ``
+    Backtick,
+    /// `[name]`
+    Bracket,
+}
+
+impl QuoteStyle {
+    fn from_open(open: u8) -> Option<Self> {
+        match open {
+            b'"' => Some(QuoteStyle::Double),
+            b'`' => Some(QuoteStyle::Backtick),
+            b'[' => Some(QuoteStyle::Bracket),
+            _ => None,
+        }
+    }
+
+    pub fn open(&self) -> char {
+        match self {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Backtick => '`',
+            QuoteStyle::Bracket => '[',
+        }
+    }
+
+    pub fn close(&self) -> char {
+        match self {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Backtick => '`',
+            QuoteStyle::Bracket => ']',
+        }
+    }
+}
+
+/// A table, column, alias or other object name.
+///
+/// Quoted names keep their case and may contain spaces or reserved words, so
+/// callers that fold case should only do it when `quote_style` is `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ident<'a> {
+    /// The name without its surrounding quotes. Doubled closing quotes are
+    /// left as written; use [`Ident::unescaped`] to collapse them.
+    pub value: &'a str,
+    pub quote_style: Option<QuoteStyle>,
+}
+
+impl<'a> Ident<'a> {
+    /// An unquoted identifier.
+    pub const fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            quote_style: None,
+        }
+    }
+
+    pub const fn quoted(value: &'a str, quote_style: QuoteStyle) -> Self {
+        Self {
+            value,
+            quote_style: Some(quote_style),
+        }
+    }
+
+    pub fn is_quoted(&self) -> bool {
+        self.quote_style.is_some()
+    }
+
+    /// The name with doubled quote characters collapsed, e.g. `"a""b"` gives `a"b`.
+    pub fn unescaped(&self) -> Cow<'a, str> {
+        match self.quote_style {
+            Some(style) => {
+                let close = style.close();
+                let doubled = [close, close].iter().collect::<String>();
+                if self.value.contains(&doubled) {
+                    Cow::Owned(self.value.replace(&doubled, &close.to_string()))
+                } else {
+                    Cow::Borrowed(self.value)
+                }
+            }
+            None => Cow::Borrowed(self.value),
+        }
+    }
+
+    /// 从 Identifier / QuotedIdentifier token 构建，不移动 cursor。
+    pub(crate) fn from_token(
+        token_table: &TokenTable<'a>,
+        cursor: &usize,
+    ) -> Result<Self, ParserError> {
+        match token_table.get_kind(*cursor) {
+            Some(TokenKind::Identifier) => Ok(Self::new(token_table.source_at(*cursor))),
+            Some(TokenKind::QuotedIdentifier) => {
+                let source = token_table.source_at(*cursor);
+                match QuoteStyle::from_open(source.as_bytes()[0]) {
+                    Some(style) => Ok(Self::quoted(&source[1..source.len() - 1], style)),
+                    None => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
+                }
+            }
+            _ => Err(syntax_error(token_table, cursor, &[TokenKind::Identifier])),
+        }
+    }
+}
+
+impl<'a> PartialEq<&str> for Ident<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<'a> fmt::Display for Ident<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.quote_style {
+            Some(style) => write!(f, "{}{}{}", style.open(), self.value, style.close()),
+            None => f.write_str(self.value),
+        }
+    }
+}
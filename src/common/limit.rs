@@ -2,18 +2,27 @@ use crate::{
     ParserError,
     common::{expr::Expr, utils::expect_kind},
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Limit<'a> {
     pub offset: Option<Expr<'a>>,
     pub limit: Expr<'a>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for Limit<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> Limit<'a> {
     pub(crate) fn new(token_table: &TokenTable<'a>, cursor: &mut usize) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Limit))?;
+        let start = *cursor;
         *cursor += 1;
 
         let first = token_table
@@ -41,6 +50,7 @@ impl<'a> Limit<'a> {
                 Ok(Limit {
                     offset: Some(offset),
                     limit,
+                    span: token_table.span_between(start, *cursor),
                 })
             }
             (true, false, true, true) => {
@@ -50,6 +60,7 @@ impl<'a> Limit<'a> {
                 Ok(Limit {
                     offset: Some(offset),
                     limit,
+                    span: token_table.span_between(start, *cursor),
                 })
             }
             (true, false, false, false) => {
@@ -57,9 +68,10 @@ impl<'a> Limit<'a> {
                 Ok(Limit {
                     offset: None,
                     limit,
+                    span: token_table.span_between(start, *cursor),
                 })
             }
-            _ => Err(ParserError::SyntaxError(*cursor, *cursor)),
+            _ => Err(ParserError::SyntaxError(token_table.span_at(*cursor))),
         }
     }
 }
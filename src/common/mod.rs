@@ -1,8 +1,11 @@
 pub mod alias;
+pub mod data_type;
 pub mod expr;
 pub mod from;
 pub mod group;
+pub mod ident;
 pub mod limit;
 pub mod order;
 pub(crate) mod pratt_parser;
 pub(crate) mod utils;
+pub(crate) mod walk;
@@ -3,35 +3,90 @@ use minivec::MiniVec;
 use crate::{
     ParserError,
     common::{
-        expr::Expr,
+        expr::{Expr, WindowFrame},
         utils::{expect_kind, maybe_kind},
     },
     keyword::Keyword,
+    span::{Span, Spanned},
     token::{TokenKind, TokenTable},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum OrderDirection {
     ASC,
     DESC,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum NullsOrder {
     First,
     Last,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct OrderItem<'a> {
     pub expr: Expr<'a>,
     pub direction: OrderDirection,
     pub nulls_order: Option<NullsOrder>,
+    pub span: Span,
 }
 
-#[derive(Debug, PartialEq)]
+impl<'a> Spanned for OrderItem<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> OrderItem<'a> {
+    /// `expr [ASC | DESC] [NULLS FIRST | NULLS LAST]`
+    pub(crate) fn build(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<Self, ParserError> {
+        let start = *cursor;
+        let expr = Expr::build(token_table, cursor)?;
+        let direction = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Asc)) {
+            *cursor += 1;
+            OrderDirection::ASC
+        } else if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Desc)) {
+            *cursor += 1;
+            OrderDirection::DESC
+        } else {
+            OrderDirection::ASC
+        };
+        let nulls_order = if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Nulls)) {
+            *cursor += 1;
+            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::First)) {
+                *cursor += 1;
+                Some(NullsOrder::First)
+            } else if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Last)) {
+                *cursor += 1;
+                Some(NullsOrder::Last)
+            } else {
+                return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
+            }
+        } else {
+            None
+        };
+        Ok(Self {
+            expr,
+            direction,
+            nulls_order,
+            span: token_table.span_between(start, *cursor),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Order<'a> {
     pub columns: MiniVec<OrderItem<'a>>,
+    pub span: Span,
+}
+
+impl<'a> Spanned for Order<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl<'a> Order<'a> {
@@ -40,6 +95,7 @@ impl<'a> Order<'a> {
         cursor: &mut usize,
     ) -> Result<Self, ParserError> {
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Order))?;
+        let start = *cursor;
         *cursor += 1;
         expect_kind(token_table, cursor, &TokenKind::Keyword(Keyword::By))?;
         *cursor += 1;
@@ -60,7 +116,7 @@ impl<'a> Order<'a> {
                         | Keyword::Rollup
                 )) | Some(TokenKind::RightParen | TokenKind::Delimiter)
                 | None
-            );
+            ) || WindowFrame::starts_at(token_table, *cursor);
             if is_clause_kw {
                 break;
             }
@@ -69,46 +125,7 @@ impl<'a> Order<'a> {
                     *cursor += 1;
                 }
                 Some(_) => {
-                    let expr = Expr::build(token_table, cursor)?;
-                    let direction =
-                        if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Asc)) {
-                            *cursor += 1;
-                            OrderDirection::ASC
-                        } else if maybe_kind(
-                            token_table,
-                            cursor,
-                            &TokenKind::Keyword(Keyword::Desc),
-                        ) {
-                            *cursor += 1;
-                            OrderDirection::DESC
-                        } else {
-                            OrderDirection::ASC
-                        };
-                    let nulls_order =
-                        if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::Nulls)) {
-                            *cursor += 1;
-                            if maybe_kind(token_table, cursor, &TokenKind::Keyword(Keyword::First))
-                            {
-                                *cursor += 1;
-                                Some(NullsOrder::First)
-                            } else if maybe_kind(
-                                token_table,
-                                cursor,
-                                &TokenKind::Keyword(Keyword::Last),
-                            ) {
-                                *cursor += 1;
-                                Some(NullsOrder::Last)
-                            } else {
-                                return Err(ParserError::SyntaxError(*cursor, *cursor));
-                            }
-                        } else {
-                            None
-                        };
-                    columns.push(OrderItem {
-                        expr,
-                        direction,
-                        nulls_order,
-                    });
+                    columns.push(OrderItem::build(token_table, cursor)?);
                 }
                 _ => {
                     break;
@@ -117,8 +134,11 @@ impl<'a> Order<'a> {
         }
 
         if columns.is_empty() {
-            return Err(ParserError::SyntaxError(*cursor, *cursor));
+            return Err(ParserError::SyntaxError(token_table.span_at(*cursor)));
         }
-        Ok(Order { columns })
+        Ok(Order {
+            columns,
+            span: token_table.span_between(start, *cursor),
+        })
     }
 }
@@ -1,6 +1,6 @@
-use std::fmt::Debug;
+use std::{convert::Infallible, fmt::Debug};
 
-use crate::{ParserError, token::TokenTable};
+use crate::{ParserError, span::Span, token::TokenTable};
 
 pub(crate) trait PrattOutput<I>
 where
@@ -9,6 +9,34 @@ where
     fn apply(op: I, left: Self, right: Self) -> Self;
 }
 
+pub(crate) trait PrattPrefix<P>
+where
+    P: PrecedenceTrait,
+{
+    fn apply_prefix(op: P, operand: Self, span: Span) -> Self;
+}
+
+// 没有前缀运算符的语法用 Infallible 作为 Prefix
+impl<T> PrattPrefix<Infallible> for T {
+    fn apply_prefix(op: Infallible, _operand: Self, _span: Span) -> Self {
+        match op {}
+    }
+}
+
+impl PrecedenceTrait for Infallible {
+    fn precedence(&self) -> usize {
+        match *self {}
+    }
+
+    fn is_left_associative(&self) -> bool {
+        match *self {}
+    }
+
+    fn min_precedence() -> usize {
+        0
+    }
+}
+
 pub(crate) trait PrecedenceTrait {
     fn precedence(&self) -> usize;
     fn is_left_associative(&self) -> bool;
@@ -17,7 +45,8 @@ pub(crate) trait PrecedenceTrait {
 
 pub(crate) trait PrattParserTrait<'a> {
     type Item: PrecedenceTrait;
-    type Output: PrattOutput<Self::Item> + Debug + 'a;
+    type Prefix: PrecedenceTrait;
+    type Output: PrattOutput<Self::Item> + PrattPrefix<Self::Prefix> + Debug + 'a;
 
     fn parse_primary(
         token_table: &TokenTable<'a>,
@@ -26,11 +55,29 @@ pub(crate) trait PrattParserTrait<'a> {
 
     fn match_item(token_table: &TokenTable, cursor: &mut usize) -> Option<Self::Item>;
 
+    // 前缀运算符，匹配成功时 cursor 已越过运算符
+    fn match_prefix(_token_table: &TokenTable, _cursor: &mut usize) -> Option<Self::Prefix> {
+        None
+    }
+
+    // min_precedence 高于后缀运算符的优先级时不应吞掉后缀运算符
     fn parse_postfix(
         left: Self::Output,
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
+        min_precedence: usize,
     ) -> Result<(Self::Output, Flow), ParserError>;
+
+    // 运算符右侧不是普通操作数时（例如 `> ALL (subquery)`）在这里直接合成结果，
+    // 否则原样交还 op 与 left
+    fn parse_infix(
+        op: Self::Item,
+        left: Self::Output,
+        _token_table: &TokenTable<'a>,
+        _cursor: &mut usize,
+    ) -> Result<Infix<Self::Item, Self::Output>, ParserError> {
+        Ok(Infix::Operand(op, left))
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -39,6 +86,14 @@ pub(crate) enum Flow {
     Run,
 }
 
+#[derive(Debug, PartialEq)]
+pub(crate) enum Infix<I, O> {
+    // 已合成完整的结果
+    Done(O),
+    // 继续按普通二元运算符解析右操作数
+    Operand(I, O),
+}
+
 #[derive(Debug)]
 pub(crate) struct PrattParser;
 
@@ -54,29 +109,36 @@ impl PrattParser {
         )
     }
 
-    fn parse_expression_with_min_precedence<'a, P: PrattParserTrait<'a> + Debug>(
+    /// 只吸收优先级不低于 min_precedence 的运算符，遇到更低的运算符时停在它前面。
+    pub(crate) fn parse_expression_with_min_precedence<'a, P: PrattParserTrait<'a> + Debug>(
         token_table: &TokenTable<'a>,
         cursor: &mut usize,
-        _initial_min_precedence: usize,
+        min_precedence: usize,
     ) -> Result<P::Output, ParserError> {
-        let mut current_left = P::parse_primary(token_table, cursor)?;
+        let mut current_left = Self::parse_operand::<P>(token_table, cursor)?;
 
         let mut stack: Vec<(P::Item, P::Output)> = Vec::new();
 
         loop {
-            let (new_left, flow) = P::parse_postfix(current_left, token_table, cursor)?;
+            let (new_left, flow) =
+                P::parse_postfix(current_left, token_table, cursor, min_precedence)?;
             current_left = new_left;
             match flow {
                 Flow::Continue => continue,
                 Flow::Run => {}
             }
 
+            let op_start = *cursor;
             let op = match P::match_item(token_table, cursor) {
                 Some(op) => op,
                 None => break,
             };
 
             let op_precedence = op.precedence();
+            if op_precedence < min_precedence {
+                *cursor = op_start;
+                break;
+            }
 
             while let Some((stack_op, _)) = stack.last() {
                 let stack_prec = stack_op.precedence();
@@ -95,8 +157,13 @@ impl PrattParser {
                 }
             }
 
-            stack.push((op, current_left));
-            current_left = P::parse_primary(token_table, cursor)?;
+            match P::parse_infix(op, current_left, token_table, cursor)? {
+                Infix::Done(output) => current_left = output,
+                Infix::Operand(op, left) => {
+                    stack.push((op, left));
+                    current_left = Self::parse_operand::<P>(token_table, cursor)?;
+                }
+            }
         }
 
         while let Some((op, left)) = stack.pop() {
@@ -105,4 +172,25 @@ impl PrattParser {
 
         Ok(current_left)
     }
+
+    // 前缀运算符的操作数只吸收优先级不低于它自己的运算符，例如 `-a * b` 是 `(-a) * b`，
+    // 而 `NOT a = b` 是 `NOT (a = b)`
+    fn parse_operand<'a, P: PrattParserTrait<'a> + Debug>(
+        token_table: &TokenTable<'a>,
+        cursor: &mut usize,
+    ) -> Result<P::Output, ParserError> {
+        let op_start = *cursor;
+        match P::match_prefix(token_table, cursor) {
+            Some(op) => {
+                let operand = Self::parse_expression_with_min_precedence::<P>(
+                    token_table,
+                    cursor,
+                    op.precedence(),
+                )?;
+                let span = token_table.span_between(op_start, *cursor);
+                Ok(P::Output::apply_prefix(op, operand, span))
+            }
+            None => P::parse_primary(token_table, cursor),
+        }
+    }
 }
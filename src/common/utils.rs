@@ -1,5 +1,6 @@
 use crate::{
     ParserError,
+    keyword::Keyword,
     token::{TokenKind, TokenTable},
 };
 
@@ -8,23 +9,93 @@ pub(crate) fn expect_kind(
     cursor: &usize,
     token_kind: &TokenKind,
 ) -> Result<(), ParserError> {
+    token_table.record_expected(*cursor, token_kind);
     match token_table.get_kind(*cursor) {
         Some(kind) if kind != token_kind => Err(ParserError::UnexpectedToken {
             expected: token_kind.clone(),
             found: kind.clone(),
+            span: token_table.span_at(*cursor),
         }),
         None => Err(ParserError::UnexpectedToken {
             expected: token_kind.clone(),
             found: TokenKind::Unknown,
+            span: token_table.span_at(*cursor),
         }),
         _ => Ok(()),
     }
 }
 
 pub(crate) fn maybe_kind(token_table: &TokenTable, cursor: &usize, token_kind: &TokenKind) -> bool {
+    token_table.record_expected(*cursor, token_kind);
     if let Some(kind) = token_table.get_kind(*cursor) {
         kind == token_kind
     } else {
         false
     }
 }
+
+/// 在 cursor 处生成语法错误，同时记录这里本可以接受的 token 种类。
+pub(crate) fn syntax_error(
+    token_table: &TokenTable,
+    cursor: &usize,
+    expected: &[TokenKind],
+) -> ParserError {
+    for kind in expected {
+        token_table.record_expected(*cursor, kind);
+    }
+    ParserError::SyntaxError(token_table.span_at(*cursor))
+}
+
+/// cursor 处是否为按名字匹配的非保留字，例如 `CAST`、`ROWS`；这些词不进 Keyword，
+/// 在其他位置仍可作为列名或表名。
+pub(crate) fn is_word(token_table: &TokenTable, cursor: usize, word: &str) -> bool {
+    token_table.get_kind(cursor) == Some(&TokenKind::Identifier)
+        && token_table.source_at(cursor).eq_ignore_ascii_case(word)
+}
+
+/// 要求 cursor 处是按名字匹配的 `word`，并移过它。
+pub(crate) fn expect_word(
+    token_table: &TokenTable,
+    cursor: &mut usize,
+    word: &str,
+) -> Result<(), ParserError> {
+    if !is_word(token_table, *cursor, word) {
+        return Err(syntax_error(token_table, cursor, &[TokenKind::Identifier]));
+    }
+    *cursor += 1;
+    Ok(())
+}
+
+/// cursor 处是否为按名字匹配的子句开头，例如 `WINDOW w AS (...)`、`RETURNING *`。
+/// 这时的词不能当作省略 AS 的别名，也不能当作 SELECT 列表里的下一项。
+pub(crate) fn starts_clause(token_table: &TokenTable, cursor: usize) -> bool {
+    if is_word(token_table, cursor, "WINDOW") {
+        return token_table
+            .get_kind(cursor + 1)
+            .is_some_and(TokenKind::is_identifier)
+            && token_table.get_kind(cursor + 2) == Some(&TokenKind::Keyword(Keyword::As));
+    }
+    // RETURNING 后面紧跟一个能开始表达式的 token 时才是子句
+    is_word(token_table, cursor, "RETURNING")
+        && matches!(
+            token_table.get_kind(cursor + 1),
+            Some(
+                TokenKind::Multiply
+                    | TokenKind::Identifier
+                    | TokenKind::QuotedIdentifier
+                    | TokenKind::Number
+                    | TokenKind::StringLiteral
+                    | TokenKind::Placeholder
+                    | TokenKind::LeftParen
+                    | TokenKind::Subtract
+                    | TokenKind::Keyword(
+                        Keyword::Case
+                            | Keyword::Not
+                            | Keyword::Null
+                            | Keyword::True
+                            | Keyword::False
+                            | Keyword::Exists
+                    )
+            )
+        )
+}
@@ -0,0 +1,520 @@
+use minivec::MiniVec;
+
+use crate::{
+    ast::{
+        cte::CteBinding,
+        ddl::{
+            AlterColumnAction, AlterTableOperation, ColumnDef, CreateTable, DdlStatement,
+            GeneratedColumn, TableConstraint, TableConstraintKind,
+        },
+        delete::DeleteStatement,
+        insert::{InsertStatement, InsertValue, OnConflict},
+        merge::{MergeAction, MergeClause, MergeStatement},
+        query::Query,
+        select::SelectStatement,
+        statement::{Statement, StatementInner},
+        update::{Assignment, UpdateStatement},
+    },
+    common::{
+        alias::Alias,
+        expr::{
+            BinaryOp, CaseExpr, Expr, FunctionCall, InValue, NamedWindow, WhenClause, WindowFrame,
+            WindowFrameBound, WindowRef, WindowSpec,
+        },
+        from::{From, Table},
+        group::{Group, GroupByExpr},
+        limit::Limit,
+        order::{Order, OrderItem},
+    },
+};
+
+/// 深度优先遍历语法树中的所有表达式，父节点先于子节点交给回调。
+pub(crate) trait WalkExpr<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>));
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for Option<T> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        if let Some(inner) = self {
+            inner.walk_expr(f);
+        }
+    }
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for Box<T> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.as_ref().walk_expr(f);
+    }
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for [T] {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        for item in self {
+            item.walk_expr(f);
+        }
+    }
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for Vec<T> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.as_slice().walk_expr(f);
+    }
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for MiniVec<T> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.as_slice().walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for Expr<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        f(self);
+        match self {
+            Expr::FunctionCall(call) => call.walk_expr(f),
+            Expr::UnaryOp(unary_op) => unary_op.expr.walk_expr(f),
+            Expr::Cast(cast) => cast.expr.walk_expr(f),
+            Expr::Extract(extract) => extract.expr.walk_expr(f),
+            Expr::Substring(substring) => {
+                substring.expr.walk_expr(f);
+                substring.start.walk_expr(f);
+                substring.length.walk_expr(f);
+            }
+            Expr::Trim(trim) => {
+                trim.characters.walk_expr(f);
+                trim.expr.walk_expr(f);
+            }
+            Expr::Position(position) => {
+                position.substring.walk_expr(f);
+                position.expr.walk_expr(f);
+            }
+            Expr::Overlay(overlay) => {
+                overlay.expr.walk_expr(f);
+                overlay.replacement.walk_expr(f);
+                overlay.start.walk_expr(f);
+                overlay.length.walk_expr(f);
+            }
+            Expr::BinaryOp(binary_op) => binary_op.walk_expr(f),
+            Expr::Between(between) => {
+                between.field.walk_expr(f);
+                between.lower.walk_expr(f);
+                between.upper.walk_expr(f);
+            }
+            Expr::In(in_expr) => {
+                in_expr.field.walk_expr(f);
+                match &in_expr.in_value {
+                    InValue::List(list) => list.walk_expr(f),
+                    InValue::Subquery(subquery) => subquery.walk_expr(f),
+                }
+            }
+            Expr::Case(case) => case.walk_expr(f),
+            Expr::Like(like) => {
+                like.field.walk_expr(f);
+                like.pattern.walk_expr(f);
+            }
+            Expr::IsNull(is_null) => is_null.field.walk_expr(f),
+            Expr::Exists(exists) => exists.subquery.walk_expr(f),
+            Expr::Subquery(subquery) => subquery.query.walk_expr(f),
+            Expr::Quantified(quantified) => {
+                quantified.left.walk_expr(f);
+                quantified.subquery.walk_expr(f);
+            }
+            Expr::WindowFunction(window_function) => {
+                window_function.function.walk_expr(f);
+                window_function.window.walk_expr(f);
+            }
+            Expr::Field(_)
+            | Expr::Star(_)
+            | Expr::StringLiteral(_)
+            | Expr::EscapedStringLiteral(_)
+            | Expr::NationalStringLiteral(_)
+            | Expr::HexStringLiteral(_)
+            | Expr::BitStringLiteral(_)
+            | Expr::IntroducedStringLiteral(_)
+            | Expr::DollarQuotedStringLiteral(_)
+            | Expr::NumericLiteral(_)
+            | Expr::BoolLiteral(_)
+            | Expr::NullLiteral(_)
+            | Expr::Default(_)
+            | Expr::Placeholder(_)
+            | Expr::TypedLiteral(_) => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for BinaryOp<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.left.walk_expr(f);
+        self.right.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for FunctionCall<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.args.walk_expr(f);
+        self.order_by.walk_expr(f);
+        self.limit.walk_expr(f);
+        self.within_group.walk_expr(f);
+        self.filter.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for WindowSpec<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.partition_by.walk_expr(f);
+        self.order_by.walk_expr(f);
+        self.frame.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for WindowFrame<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.start.walk_expr(f);
+        self.end.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for WindowFrameBound<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            WindowFrameBound::Preceding(offset) | WindowFrameBound::Following(offset) => {
+                offset.walk_expr(f)
+            }
+            WindowFrameBound::UnboundedPreceding
+            | WindowFrameBound::CurrentRow
+            | WindowFrameBound::UnboundedFollowing => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for WindowRef<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            WindowRef::Named { .. } => {}
+            WindowRef::Spec(spec) => spec.walk_expr(f),
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for NamedWindow<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.spec.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for CaseExpr<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.condition.walk_expr(f);
+        self.when_clauses.walk_expr(f);
+        self.else_result.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for WhenClause<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.condition.walk_expr(f);
+        self.result.walk_expr(f);
+    }
+}
+
+impl<'a, T: WalkExpr<'a>> WalkExpr<'a> for Alias<'a, T> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.value.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for Table<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            Table::Name(name) => name.walk_expr(f),
+            Table::SubQuery(subquery) => subquery.walk_expr(f),
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for From<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            From::Table(table) => table.walk_expr(f),
+            From::CrossJoin { left, right }
+            | From::NaturalJoin { left, right, .. }
+            | From::JoinUsing { left, right, .. } => {
+                left.walk_expr(f);
+                right.walk_expr(f);
+            }
+            From::LeftJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::RightJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::InnerJoin {
+                left,
+                right,
+                condition,
+            }
+            | From::FullJoin {
+                left,
+                right,
+                condition,
+            } => {
+                left.walk_expr(f);
+                right.walk_expr(f);
+                condition.walk_expr(f);
+            }
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for Group<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.columns.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for GroupByExpr<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            GroupByExpr::Simple(expr) => expr.walk_expr(f),
+            GroupByExpr::GroupingSets(sets) => sets.walk_expr(f),
+            GroupByExpr::Cube(list) | GroupByExpr::Rollup(list) => list.walk_expr(f),
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for Order<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.columns.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for OrderItem<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.expr.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for Limit<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.offset.walk_expr(f);
+        self.limit.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for SelectStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.columns.walk_expr(f);
+        self.from.walk_expr(f);
+        self.where_statement.walk_expr(f);
+        self.group_by.walk_expr(f);
+        self.having_statement.walk_expr(f);
+        self.window.walk_expr(f);
+        self.order_by.walk_expr(f);
+        self.limit.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for CteBinding<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.query.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for Query<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            Query::Select(select) => select.walk_expr(f),
+            Query::Cte { ctes, query, .. } => {
+                ctes.walk_expr(f);
+                query.walk_expr(f);
+            }
+            Query::SetOperation {
+                left,
+                right,
+                order_by,
+                limit,
+                ..
+            } => {
+                left.walk_expr(f);
+                right.walk_expr(f);
+                order_by.walk_expr(f);
+                limit.walk_expr(f);
+            }
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for InsertStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.table.walk_expr(f);
+        self.insert_value.walk_expr(f);
+        self.on_conflict.walk_expr(f);
+        self.returning.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for InsertValue<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            InsertValue::AllSelect { select } => select.walk_expr(f),
+            InsertValue::PartOfSelect { select, columns } => {
+                columns.walk_expr(f);
+                select.walk_expr(f);
+            }
+            InsertValue::Values { columns, values } => {
+                columns.walk_expr(f);
+                values.walk_expr(f);
+            }
+            InsertValue::DefaultValues => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for OnConflict<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            OnConflict::DoNothing { .. } => {}
+            OnConflict::DoUpdate {
+                assignments,
+                where_statement,
+                ..
+            } => {
+                assignments.walk_expr(f);
+                where_statement.walk_expr(f);
+            }
+            OnConflict::DuplicateKeyUpdate { assignments, .. } => assignments.walk_expr(f),
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for UpdateStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.table.walk_expr(f);
+        self.assignments.walk_expr(f);
+        self.from.walk_expr(f);
+        self.where_statement.walk_expr(f);
+        self.returning.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for Assignment<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.value.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for DeleteStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.delete_tables.walk_expr(f);
+        self.from.walk_expr(f);
+        self.using.walk_expr(f);
+        self.conditions.walk_expr(f);
+        self.returning.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for MergeStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.target.walk_expr(f);
+        self.source.walk_expr(f);
+        self.on.walk_expr(f);
+        self.clauses.walk_expr(f);
+    }
+}
+
+impl<'a> WalkExpr<'a> for MergeClause<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.condition.walk_expr(f);
+        match &self.action {
+            MergeAction::Update { assignments } => assignments.walk_expr(f),
+            MergeAction::Insert { insert_value } => insert_value.walk_expr(f),
+            MergeAction::Delete | MergeAction::DoNothing => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for DdlStatement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            DdlStatement::CreateTable(CreateTable::AsSelect { select, .. }) => select.walk_expr(f),
+            DdlStatement::CreateTable(CreateTable::Table {
+                columns,
+                constraints,
+                ..
+            }) => {
+                columns.walk_expr(f);
+                constraints.walk_expr(f);
+            }
+            DdlStatement::AlterTable(alter) => alter.operations.walk_expr(f),
+            DdlStatement::CreateIndex(create) => {
+                create.columns.walk_expr(f);
+                create.where_statement.walk_expr(f);
+            }
+            DdlStatement::DropTable(_) | DdlStatement::DropIndex(_) => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for AlterTableOperation<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            AlterTableOperation::AddColumn { column, .. }
+            | AlterTableOperation::ModifyColumn { column }
+            | AlterTableOperation::ChangeColumn { column, .. } => column.walk_expr(f),
+            AlterTableOperation::AlterColumn { action, .. } => match action {
+                AlterColumnAction::SetDefault(expr) => expr.walk_expr(f),
+                AlterColumnAction::SetDataType { using, .. } => using.walk_expr(f),
+                AlterColumnAction::DropDefault
+                | AlterColumnAction::SetNotNull
+                | AlterColumnAction::DropNotNull => {}
+            },
+            AlterTableOperation::AddConstraint(constraint) => constraint.walk_expr(f),
+            AlterTableOperation::DropColumn { .. }
+            | AlterTableOperation::RenameTo(_)
+            | AlterTableOperation::RenameColumn { .. }
+            | AlterTableOperation::DropConstraint { .. } => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for ColumnDef<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.constraint.default.walk_expr(f);
+        self.constraint.check.walk_expr(f);
+        if let Some(GeneratedColumn::Expr { expr, .. }) = &self.constraint.generated {
+            expr.walk_expr(f);
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for TableConstraint<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        if let TableConstraintKind::Check(expr) = &self.kind {
+            expr.walk_expr(f);
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for StatementInner<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        match self {
+            StatementInner::Query(query) => query.walk_expr(f),
+            StatementInner::Insert(insert) => insert.walk_expr(f),
+            StatementInner::Update(update) => update.walk_expr(f),
+            StatementInner::Delete(delete) => delete.walk_expr(f),
+            StatementInner::Merge(merge) => merge.walk_expr(f),
+            StatementInner::Ddl(ddl) => ddl.walk_expr(f),
+            StatementInner::Error(_) => {}
+        }
+    }
+}
+
+impl<'a> WalkExpr<'a> for Statement<'a> {
+    fn walk_expr<'s>(&'s self, f: &mut dyn FnMut(&'s Expr<'a>)) {
+        self.list.walk_expr(f);
+    }
+}
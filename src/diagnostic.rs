@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::{error::ParserError, span::Span, token::TokenKind};
+
+/// A parse failure resolved against the source text: where it happened, what
+/// the parser found there and what it would have accepted instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub error: ParserError,
+    pub message: String,
+    pub span: Span,
+    /// 1-based line of `span.start`.
+    pub line: usize,
+    /// 1-based column of `span.start`, counted in characters.
+    pub column: usize,
+    /// The full source line containing `span.start`, without the line break.
+    pub source_line: String,
+    /// Token kinds the parser tried at the failing position, in the order it tried them.
+    pub expected: Vec<TokenKind>,
+}
+
+impl Diagnostic {
+    /// Builds a diagnostic for `error` without any recorded expectations.
+    pub fn from_error(error: ParserError, source: &str) -> Self {
+        Self::new(error, source, Vec::new())
+    }
+
+    pub(crate) fn new(error: ParserError, source: &str, mut expected: Vec<TokenKind>) -> Self {
+        let span = error
+            .span()
+            .unwrap_or(Span::new(source.len(), source.len()));
+        // 字节范围可能落在多字节字符中间，向外扩到完整字符再切片
+        let mut start = span.start.min(source.len());
+        while !source.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = span.end.clamp(start, source.len());
+        while !source.is_char_boundary(end) {
+            end += 1;
+        }
+        let span = Span::new(start, end);
+        let found = &source[start..end];
+
+        let message = match &error {
+            ParserError::InvalidToken { kind, .. } => kind.to_string(),
+            ParserError::SyntaxError(_) | ParserError::UnexpectedToken { .. }
+                if found.is_empty() =>
+            {
+                "unexpected end of input".to_string()
+            }
+            ParserError::SyntaxError(_) | ParserError::UnexpectedToken { .. } => {
+                format!("unexpected `{found}`")
+            }
+            other => other.to_string(),
+        };
+
+        if let ParserError::UnexpectedToken {
+            expected: kind,
+            found,
+            ..
+        } = &error
+        {
+            if !expected.contains(kind) {
+                expected.push(kind.clone());
+            }
+            expected.retain(|kind| kind != found);
+        }
+
+        let line_start = source[..start].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |pos| start + pos);
+        let source_line = source[line_start..line_end].trim_end_matches('\r');
+
+        Self {
+            message,
+            span,
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            source_line: source_line.to_string(),
+            expected,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        // 下划线只画在出错的这一行内，至少一个字符；span 按字节计，这里换算成字符数
+        let mut bytes = 0;
+        let underline_width = self
+            .source_line
+            .chars()
+            .skip(self.column - 1)
+            .take_while(|c| {
+                bytes += c.len_utf8();
+                bytes <= self.span.len()
+            })
+            .count()
+            .max(1);
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{gutter}--> {}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {}", self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(underline_width)
+        )?;
+        if !self.expected.is_empty() {
+            let expected = self
+                .expected
+                .iter()
+                .map(describe_kind)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "\n{gutter} = expected one of: {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+fn describe_kind(kind: &TokenKind) -> String {
+    let text = match kind {
+        TokenKind::Number => return "number".to_string(),
+        TokenKind::StringLiteral => return "string literal".to_string(),
+        TokenKind::Identifier => return "identifier".to_string(),
+        TokenKind::QuotedIdentifier => return "quoted identifier".to_string(),
+        TokenKind::Placeholder => return "placeholder".to_string(),
+        TokenKind::Unknown => return "end of input".to_string(),
+        TokenKind::Keyword(keyword) => return format!("`{}`", keyword.as_ref().to_uppercase()),
+        TokenKind::Delimiter => ";",
+        TokenKind::Dot => ".",
+        TokenKind::LeftParen => "(",
+        TokenKind::RightParen => ")",
+        TokenKind::LeftBracket => "[",
+        TokenKind::RightBracket => "]",
+        TokenKind::LeftShift => "<<",
+        TokenKind::RightShift => ">>",
+        TokenKind::Comma => ",",
+        TokenKind::Less => "<",
+        TokenKind::LessEqual => "<=",
+        TokenKind::Greater => ">",
+        TokenKind::GreaterEqual => ">=",
+        TokenKind::Equal => "=",
+        TokenKind::NotEqual => "<>",
+        TokenKind::Plus => "+",
+        TokenKind::Subtract => "-",
+        TokenKind::Multiply => "*",
+        TokenKind::Divide => "/",
+        TokenKind::Mod => "%",
+        TokenKind::BitXor => "^",
+        TokenKind::BitAnd => "&",
+        TokenKind::BitOr => "|",
+        TokenKind::Concat => "||",
+        TokenKind::DoubleColon => "::",
+        TokenKind::Arrow => "->",
+        TokenKind::LongArrow => "->>",
+        TokenKind::HashArrow => "#>",
+        TokenKind::HashLongArrow => "#>>",
+        TokenKind::AtArrow => "@>",
+        TokenKind::ArrowAt => "<@",
+        TokenKind::Spaceship => "<=>",
+        TokenKind::Tilde => "~",
+        TokenKind::TildeStar => "~*",
+        TokenKind::ExclamationTilde => "!~",
+        TokenKind::ExclamationTildeStar => "!~*",
+    };
+    format!("`{text}`")
+}
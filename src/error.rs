@@ -1,9 +1,9 @@
 use simdutf8::basic::Utf8Error;
 use thiserror::Error;
 
-use crate::token::TokenKind;
+use crate::{span::Span, token::TokenKind};
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum ParserError {
     #[error("invalid utf-8")]
     InvalidUtf8(#[from] Utf8Error),
@@ -11,15 +11,67 @@ pub enum ParserError {
     #[error("ahocorasick error `{0}`")]
     AhoCorasickBuild(String),
 
-    #[error("invalid token from `{0}` to `{1}`")]
-    InvalidToken(usize, usize),
+    #[error("{kind} at `{span}`")]
+    InvalidToken { kind: LexErrorKind, span: Span },
 
-    #[error("syntax error from `{0}` to `{1}`")]
-    SyntaxError(usize, usize),
+    #[error("syntax error at `{0}`")]
+    SyntaxError(Span),
 
-    #[error("unexpected token: expected `{expected}`, found `{found}`")]
+    #[error("unexpected token at `{span}`: expected `{expected}`, found `{found}`")]
     UnexpectedToken {
         expected: TokenKind,
         found: TokenKind,
+        span: Span,
     },
 }
+
+/// Why the lexer rejected a piece of input.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    #[error("unterminated string literal")]
+    UnterminatedString,
+
+    #[error("unterminated quoted identifier")]
+    UnterminatedQuotedIdentifier,
+
+    #[error("unterminated block comment")]
+    UnterminatedBlockComment,
+
+    #[error("malformed hexadecimal literal")]
+    MalformedHexLiteral,
+
+    #[error("malformed octal literal")]
+    MalformedOctalLiteral,
+
+    #[error("malformed binary literal")]
+    MalformedBinaryLiteral,
+
+    #[error("repeated exponent in number")]
+    RepeatedExponent,
+
+    #[error("repeated decimal point in number")]
+    RepeatedDecimalPoint,
+
+    /// Digits followed by something that cannot continue a number, e.g. `1.` or `12abc`.
+    #[error("malformed number")]
+    MalformedNumber,
+
+    #[error("unknown operator")]
+    UnknownOperator,
+
+    /// A `!` that is not part of `!=`, `!~` or `!~*`.
+    #[error("unexpected `!`")]
+    StrayBang,
+}
+
+impl ParserError {
+    /// The byte range the error points at, if it refers to a place in the source.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            ParserError::InvalidToken { span, .. }
+            | ParserError::SyntaxError(span)
+            | ParserError::UnexpectedToken { span, .. } => Some(*span),
+            ParserError::InvalidUtf8(_) | ParserError::AhoCorasickBuild(_) => None,
+        }
+    }
+}
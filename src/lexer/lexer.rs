@@ -1,10 +1,11 @@
 use crate::{
-    error::ParserError,
+    error::{LexErrorKind, ParserError},
     keyword::{Keyword, KeywordMap},
     simd_common::{
         find_consecutive_in_range, is_escaped, longest_consecutive_matching, mixed_match,
         skip_until_match, skip_until_sequence,
     },
+    span::Span,
     token::{TokenKind, TokenTable},
 };
 
@@ -17,7 +18,7 @@ const C_UNK: u8 = 0; // 未知/非法
 const C_WSP: u8 = 1 << 0; // 空白 (Whitespace)
 const C_DIG: u8 = 1 << 1; // 数字 (0-9)
 const C_ALP: u8 = 1 << 2; // 字母 (a-z, A-Z, _)
-const C_QUO: u8 = 1 << 3; // 引号 (' " `)
+const C_QUO: u8 = 1 << 3; // 引号 (' " ` [)
 const C_SYM: u8 = 1 << 4; // 符号 (+ - * / etc)
 
 // 256字节的映射表，将 byte 映射到分类
@@ -53,9 +54,11 @@ const fn char_table() -> [u8; 256] {
     // 设置引号
     t[b'\'' as usize] = C_QUO;
     t[b'"' as usize] = C_QUO;
+    t[b'`' as usize] = C_QUO;
+    t[b'[' as usize] = C_QUO;
 
     // 设置符号
-    let syms = b"+-*/%()<>=,;.\\!&|^~";
+    let syms = b"+-*/%()]<>=,;.\\!&|^~#";
     let mut j = 0;
     while j < syms.len() {
         t[syms[j] as usize] = C_SYM;
@@ -66,6 +69,23 @@ const fn char_table() -> [u8; 256] {
 
 const CHAR_TABLE: [u8; 256] = char_table();
 
+fn lex_error(kind: LexErrorKind, start: usize, end: usize) -> ParserError {
+    ParserError::InvalidToken {
+        kind,
+        span: Span::new(start, end),
+    }
+}
+
+// UTF-8 首字节对应的字符字节数
+const fn utf8_char_len(first: u8) -> usize {
+    match first {
+        0xF0..=0xFF => 4,
+        0xE0..=0xEF => 3,
+        0xC0..=0xDF => 2,
+        _ => 1,
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Lexer<'a> {
     inner: &'a [u8],
@@ -98,10 +118,6 @@ impl<'a> Lexer<'a> {
     // #[inline]
     fn scan_number(&mut self) -> Result<(TokenKind, usize, usize), ParserError> {
         let start = self.position;
-        if let Some(b'-') = self.inner.get(self.position) {
-            self.position += 1;
-        }
-
         let (kind, _, end) = self.scan_digit_number()?;
         self.position = end;
 
@@ -112,7 +128,7 @@ impl<'a> Lexer<'a> {
         let start = self.position;
         match self.scan_unsigned_number()? {
             Some((_kind, _, end)) => self.position = end,
-            None => return Err(ParserError::InvalidToken(start, start)),
+            None => return Err(lex_error(LexErrorKind::MalformedNumber, start, start + 1)),
         }
 
         // 检测十六进制/八进制/二进制前缀 (仅前导 '0')
@@ -128,7 +144,11 @@ impl<'a> Lexer<'a> {
                         hex_start,
                     );
                     if end == -1 {
-                        return Err(ParserError::InvalidToken(hex_start, hex_start));
+                        return Err(lex_error(
+                            LexErrorKind::MalformedHexLiteral,
+                            start,
+                            hex_start,
+                        ));
                     }
                     self.position = end.cast_unsigned();
                     return Ok((TokenKind::Number, start, self.position));
@@ -138,7 +158,11 @@ impl<'a> Lexer<'a> {
                     let oct_start = self.position;
                     let (_, end) = mixed_match(self.inner, [(b'0', b'7')], [b'_'], oct_start);
                     if end == -1 {
-                        return Err(ParserError::InvalidToken(oct_start, oct_start));
+                        return Err(lex_error(
+                            LexErrorKind::MalformedOctalLiteral,
+                            start,
+                            oct_start,
+                        ));
                     }
                     self.position = end.cast_unsigned();
                     return Ok((TokenKind::Number, start, self.position));
@@ -148,7 +172,11 @@ impl<'a> Lexer<'a> {
                     let bin_start = self.position;
                     let (_, end) = mixed_match(self.inner, [(b'0', b'1')], [b'_'], bin_start);
                     if end == -1 {
-                        return Err(ParserError::InvalidToken(bin_start, bin_start));
+                        return Err(lex_error(
+                            LexErrorKind::MalformedBinaryLiteral,
+                            start,
+                            bin_start,
+                        ));
                     }
                     self.position = end.cast_unsigned();
                     return Ok((TokenKind::Number, start, self.position));
@@ -169,15 +197,19 @@ impl<'a> Lexer<'a> {
                             self.position = next;
                             match self.scan_unsigned_number()? {
                                 Some((_kind, _, end)) => self.position = end,
-                                None => return Err(ParserError::InvalidToken(start, start)),
+                                None => return Err(self.malformed_number(start)),
                             }
                             exists_dot = true;
                         }
-                        _ => return Err(ParserError::InvalidToken(self.position, self.position)),
+                        _ => return Err(self.malformed_number(start)),
                     }
                 }
                 Some(b'.') if exists_dot => {
-                    return Err(ParserError::InvalidToken(self.position, self.position));
+                    return Err(lex_error(
+                        LexErrorKind::RepeatedDecimalPoint,
+                        self.position + 1,
+                        self.position + 2,
+                    ));
                 }
                 Some(b'_') => {
                     let next = self.position + 2;
@@ -186,10 +218,10 @@ impl<'a> Lexer<'a> {
                             self.position = next;
                             match self.scan_unsigned_number()? {
                                 Some((_kind, _, end)) => self.position = end,
-                                None => return Err(ParserError::InvalidToken(start, start)),
+                                None => return Err(self.malformed_number(start)),
                             }
                         }
-                        _ => return Err(ParserError::InvalidToken(self.position, self.position)),
+                        _ => return Err(self.malformed_number(start)),
                     }
                 }
                 Some(b'E') | Some(b'e') if !exists_log => {
@@ -200,18 +232,22 @@ impl<'a> Lexer<'a> {
                             self.position = next;
                             match self.scan_unsigned_number()? {
                                 Some((_kind, _, end)) => self.position = end,
-                                None => return Err(ParserError::InvalidToken(start, start)),
+                                None => return Err(self.malformed_number(start)),
                             }
                             exists_log = true;
                         }
-                        _ => return Err(ParserError::InvalidToken(self.position, self.position)),
+                        _ => return Err(self.malformed_number(start)),
                     }
                 }
                 Some(b'E') | Some(b'e') if exists_log => {
-                    return Err(ParserError::InvalidToken(self.position, self.position));
+                    return Err(lex_error(
+                        LexErrorKind::RepeatedExponent,
+                        self.position + 1,
+                        self.position + 2,
+                    ));
                 }
                 Some(n) if CHAR_TABLE[*n as usize] & C_ALP != 0 => {
-                    return Err(ParserError::InvalidToken(self.position, self.position));
+                    return Err(self.malformed_number(start));
                 }
                 _ => break,
             }
@@ -220,6 +256,12 @@ impl<'a> Lexer<'a> {
         Ok((TokenKind::Number, start, self.position))
     }
 
+    // 数字从 start 开始，到当前检查的下一个字符（含）为止都算作出错范围
+    fn malformed_number(&self, start: usize) -> ParserError {
+        let end = (self.position + 2).min(self.inner.len());
+        lex_error(LexErrorKind::MalformedNumber, start, end)
+    }
+
     fn scan_unsigned_number(&mut self) -> Result<Option<(TokenKind, usize, usize)>, ParserError> {
         let (start, end) = find_consecutive_in_range(self.inner, (b'0', b'9'), self.position);
         if end == -1 {
@@ -249,10 +291,27 @@ impl<'a> Lexer<'a> {
         };
         let end = self.position;
 
-        let source = match self.inner.get(start..=end) {
-            Some(s) => s,
-            None => return Err(ParserError::InvalidToken(start, end)),
-        };
+        // mixed_match 保证 end 不会越界
+        let source = &self.inner[start..=end];
+
+        // 紧跟引号的前缀：E'..' N'..' X'..' B'..' 以及 MySQL 的 _charset'..'
+        if self.inner.get(end + 1) == Some(&b'\'') {
+            let prefix = match source {
+                [b'e' | b'E' | b'n' | b'N'] => Some(None),
+                [b'x' | b'X'] => Some(Some(LexErrorKind::MalformedHexLiteral)),
+                [b'b' | b'B'] => Some(Some(LexErrorKind::MalformedBinaryLiteral)),
+                [b'_', ..] => Some(None),
+                _ => None,
+            };
+            if let Some(digits) = prefix {
+                self.position = end + 1;
+                let (kind, _, end) = self.scan_string(b'\'')?;
+                if let Some(error_kind) = digits {
+                    self.check_string_digits(error_kind, start, end)?;
+                }
+                return Ok((kind, start, end));
+            }
+        }
 
         if let Some(keyword) = self.maybe_keyword(source) {
             Ok((TokenKind::Keyword(keyword), start, end))
@@ -270,7 +329,11 @@ impl<'a> Lexer<'a> {
     fn skip_block_comment(&mut self) -> Result<(), ParserError> {
         let (_, end) = skip_until_sequence(self.inner, [b'*', b'/'], self.position + 2);
         if end == -1 {
-            return Err(ParserError::InvalidToken(self.position, self.position));
+            return Err(lex_error(
+                LexErrorKind::UnterminatedBlockComment,
+                self.position,
+                self.inner.len(),
+            ));
         }
         self.position = end as usize + 2;
         Ok(())
@@ -294,11 +357,20 @@ impl<'a> Lexer<'a> {
         loop {
             let (_, next) = skip_until_match(self.inner, [terminator], pos);
             if next == -1 {
-                return Err(ParserError::InvalidToken(start, self.position));
+                return Err(lex_error(
+                    LexErrorKind::UnterminatedString,
+                    start,
+                    self.inner.len(),
+                ));
             }
 
             let candidate = next as usize;
             if !is_escaped(self.inner, candidate, start) {
+                // SQL 标准写法：连续两个引号表示一个字面的引号，例如 'it''s'
+                if self.inner.get(candidate + 1) == Some(&terminator) {
+                    pos = candidate + 2;
+                    continue;
+                }
                 self.position = candidate;
                 return Ok((TokenKind::StringLiteral, start, self.position));
             }
@@ -306,51 +378,195 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    // PostgreSQL 的 $$...$$ 与 $tag$...$tag$，返回 None 表示这里不是美元引号的开头
+    fn scan_dollar_string(&mut self) -> Result<Option<(TokenKind, usize, usize)>, ParserError> {
+        let inner = self.inner;
+        let start = self.position;
+        let mut tag_end = start + 1;
+        while let Some(c) = inner.get(tag_end) {
+            match c {
+                b'$' => break,
+                b'a'..=b'z' | b'A'..=b'Z' | b'_' => tag_end += 1,
+                b'0'..=b'9' if tag_end > start + 1 => tag_end += 1,
+                _ => return Ok(None),
+            }
+        }
+        if inner.get(tag_end) != Some(&b'$') {
+            return Ok(None);
+        }
+
+        // 结束符的前两个字节一定是 '$' 加上 tag 的首字节（或第二个 '$'），先用 SIMD 找到候选位置再比较整个结束符
+        let delimiter = &inner[start..=tag_end];
+        let mut pos = tag_end + 1;
+        loop {
+            let (_, next) = skip_until_sequence(inner, [b'$', delimiter[1]], pos);
+            if next == -1 {
+                return Err(lex_error(
+                    LexErrorKind::UnterminatedString,
+                    start,
+                    inner.len(),
+                ));
+            }
+
+            let candidate = next as usize;
+            if inner[candidate..].starts_with(delimiter) {
+                self.position = candidate + delimiter.len() - 1;
+                return Ok(Some((TokenKind::StringLiteral, start, self.position)));
+            }
+            pos = candidate + 1;
+        }
+    }
+
+    // 绑定参数：`?`、`$1`、`:name`、`@name`，返回 None 表示这里不是绑定参数
+    fn scan_placeholder(&mut self) -> Option<(TokenKind, usize, usize)> {
+        let inner = self.inner;
+        let start = self.position;
+        let mut end = start + 1;
+        match inner[start] {
+            b'?' => {}
+            b'$' => {
+                while inner.get(end).is_some_and(u8::is_ascii_digit) {
+                    end += 1;
+                }
+            }
+            _ => {
+                if inner
+                    .get(end)
+                    .is_none_or(|c| !c.is_ascii_alphabetic() && *c != b'_')
+                {
+                    return None;
+                }
+                while inner
+                    .get(end)
+                    .is_some_and(|c| (CHAR_TABLE[*c as usize] & C_ALP) != 0)
+                {
+                    end += 1;
+                }
+            }
+        }
+        if end == start + 1 && inner[start] != b'?' {
+            return None;
+        }
+        self.position = end - 1;
+        Some((TokenKind::Placeholder, start, self.position))
+    }
+
+    // 检查 X'..' / B'..' 引号内只包含十六进制或二进制数字
+    fn check_string_digits(
+        &self,
+        error_kind: LexErrorKind,
+        start: usize,
+        end: usize,
+    ) -> Result<(), ParserError> {
+        let quote = start + 2;
+        let valid = self.inner[quote..end].iter().all(|c| match error_kind {
+            LexErrorKind::MalformedHexLiteral => c.is_ascii_hexdigit(),
+            _ => matches!(c, b'0' | b'1'),
+        });
+        if valid {
+            Ok(())
+        } else {
+            Err(lex_error(error_kind, start, end + 1))
+        }
+    }
+
+    // `[` 紧贴在名字、`)` 或 `]` 之后时是数组后缀的方括号，例如 `INT[3]`、`a[1]`；
+    // 其他位置仍是 `[name]` 形式的标识符
+    fn follows_operand(table: &TokenTable<'a>, position: usize) -> bool {
+        table.spans.last().is_some_and(|span| span.end == position)
+            && matches!(
+                table.tokens.last(),
+                Some(
+                    TokenKind::Identifier
+                        | TokenKind::QuotedIdentifier
+                        | TokenKind::RightParen
+                        | TokenKind::RightBracket
+                )
+            )
+    }
+
+    // 匹配带引号的标识符，连续两个结束符表示一个字面的结束符，例如 "a""b"
+    fn scan_quoted_identifier(
+        &mut self,
+        terminator: u8,
+    ) -> Result<(TokenKind, usize, usize), ParserError> {
+        let start = self.position;
+        let mut pos = self.position + 1;
+
+        loop {
+            let (_, next) = skip_until_match(self.inner, [terminator], pos);
+            if next == -1 {
+                return Err(lex_error(
+                    LexErrorKind::UnterminatedQuotedIdentifier,
+                    start,
+                    self.inner.len(),
+                ));
+            }
+
+            let candidate = next as usize;
+            if self.inner.get(candidate + 1) == Some(&terminator) {
+                pos = candidate + 2;
+                continue;
+            }
+            self.position = candidate;
+            return Ok((TokenKind::QuotedIdentifier, start, self.position));
+        }
+    }
+
     fn scan_symbol(&mut self, table: &mut TokenTable<'a>) -> Result<(), ParserError> {
         let start = self.position;
         let end = self.position;
         match self.inner.get(self.position) {
             Some(b'(') => {
-                table.push(
-                    TokenKind::LeftParen,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::LeftParen, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b')') => {
-                table.push(
-                    TokenKind::RightParen,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::RightParen, Span::new(start, end + 1));
+                self.position += 1;
+            }
+            Some(b']') => {
+                table.push(TokenKind::RightBracket, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'<') => match self.inner.get(self.position + 1) {
+                Some(b'=') if self.inner.get(self.position + 2) == Some(&b'>') => {
+                    table.push(
+                        TokenKind::Spaceship,
+                        Span::new(self.position, self.position + 3),
+                    );
+                    self.position += 3;
+                }
                 Some(b'=') => {
                     table.push(
                         TokenKind::LessEqual,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
+                    );
+                    self.position += 2;
+                }
+                Some(b'@') => {
+                    table.push(
+                        TokenKind::ArrowAt,
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 Some(b'>') => {
                     table.push(
                         TokenKind::NotEqual,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 Some(b'<') => {
                     table.push(
                         TokenKind::LeftShift,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 _ => {
-                    table.push(
-                        TokenKind::Less,
-                        self.save_str(start, end),
-                    );
+                    table.push(TokenKind::Less, Span::new(start, end + 1));
                     self.position += 1;
                 }
             },
@@ -358,77 +574,63 @@ impl<'a> Lexer<'a> {
                 Some(b'=') => {
                     table.push(
                         TokenKind::GreaterEqual,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 Some(b'>') => {
                     table.push(
                         TokenKind::RightShift,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 _ => {
-                    table.push(
-                        TokenKind::Greater,
-                        self.save_str(start, end),
-                    );
+                    table.push(TokenKind::Greater, Span::new(start, end + 1));
                     self.position += 1;
                 }
             },
             Some(b'=') => {
-                table.push(
-                    TokenKind::Equal,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Equal, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'.') => {
-                table.push(
-                    TokenKind::Dot,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Dot, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b',') => {
-                table.push(
-                    TokenKind::Comma,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Comma, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'+') => {
-                table.push(
-                    TokenKind::Plus,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Plus, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'-') => match self.inner.get(self.position + 1) {
                 Some(b'-') => {
                     self.skip_line_comment();
                 }
-                Some(b'0'..=b'9') => {
-                    let start = self.position;
-                    self.position += 1;
-                    let (kind, _, end) = self.scan_number()?;
-                    table.push(kind, self.save_str(start, end));
-                    self.position += 1;
+                Some(b'>') if self.inner.get(self.position + 2) == Some(&b'>') => {
+                    table.push(
+                        TokenKind::LongArrow,
+                        Span::new(self.position, self.position + 3),
+                    );
+                    self.position += 3;
                 }
-                _ => {
+                Some(b'>') => {
                     table.push(
-                        TokenKind::Subtract,
-                        self.save_str(start, end),
+                        TokenKind::Arrow,
+                        Span::new(self.position, self.position + 2),
                     );
+                    self.position += 2;
+                }
+                _ => {
+                    table.push(TokenKind::Subtract, Span::new(start, end + 1));
                     self.position += 1;
                 }
             },
             Some(b'*') => {
-                table.push(
-                    TokenKind::Multiply,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Multiply, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'/') => match self.inner.get(self.position + 1) {
@@ -439,67 +641,118 @@ impl<'a> Lexer<'a> {
                     self.skip_line_comment();
                 }
                 _ => {
-                    table.push(
-                        TokenKind::Divide,
-                        self.save_str(start, end),
-                    );
+                    table.push(TokenKind::Divide, Span::new(start, end + 1));
                     self.position += 1;
                 }
             },
             Some(b'%') => {
-                table.push(
-                    TokenKind::Mod,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::Mod, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b';') => {
-                table.push(
-                    TokenKind::Delimiter,
-                    self.save_str(start,end),
-                );
+                table.push(TokenKind::Delimiter, Span::new(start, end + 1));
                 self.position += 1;
             }
             Some(b'&') => {
-                table.push(
-                    TokenKind::BitAnd,
-                    self.save_str(start,end),
-                );
-                self.position += 1;
-            }
-            Some(b'|') => {
-                table.push(
-                    TokenKind::Or,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::BitAnd, Span::new(start, end + 1));
                 self.position += 1;
             }
+            Some(b'|') => match self.inner.get(self.position + 1) {
+                Some(b'|') => {
+                    table.push(
+                        TokenKind::Concat,
+                        Span::new(self.position, self.position + 2),
+                    );
+                    self.position += 2;
+                }
+                _ => {
+                    table.push(TokenKind::BitOr, Span::new(start, end + 1));
+                    self.position += 1;
+                }
+            },
             Some(b'^') => {
-                table.push(
-                    TokenKind::BitXor,
-                    self.save_str(start, end),
-                );
+                table.push(TokenKind::BitXor, Span::new(start, end + 1));
                 self.position += 1;
             }
+            Some(b'~') => match self.inner.get(self.position + 1) {
+                Some(b'*') => {
+                    table.push(
+                        TokenKind::TildeStar,
+                        Span::new(self.position, self.position + 2),
+                    );
+                    self.position += 2;
+                }
+                _ => {
+                    table.push(TokenKind::Tilde, Span::new(start, end + 1));
+                    self.position += 1;
+                }
+            },
+            Some(b'#') => match (
+                self.inner.get(self.position + 1),
+                self.inner.get(self.position + 2),
+            ) {
+                (Some(b'>'), Some(b'>')) => {
+                    table.push(
+                        TokenKind::HashLongArrow,
+                        Span::new(self.position, self.position + 3),
+                    );
+                    self.position += 3;
+                }
+                (Some(b'>'), _) => {
+                    table.push(
+                        TokenKind::HashArrow,
+                        Span::new(self.position, self.position + 2),
+                    );
+                    self.position += 2;
+                }
+                _ => return Err(lex_error(LexErrorKind::UnknownOperator, start, end + 1)),
+            },
             Some(b'!') => match self.inner.get(self.position + 1) {
                 Some(b'=') => {
                     table.push(
                         TokenKind::NotEqual,
-                        self.save_str(self.position, self.position + 1),
+                        Span::new(self.position, self.position + 2),
+                    );
+                    self.position += 2;
+                }
+                Some(b'~') if self.inner.get(self.position + 2) == Some(&b'*') => {
+                    table.push(
+                        TokenKind::ExclamationTildeStar,
+                        Span::new(self.position, self.position + 3),
+                    );
+                    self.position += 3;
+                }
+                Some(b'~') => {
+                    table.push(
+                        TokenKind::ExclamationTilde,
+                        Span::new(self.position, self.position + 2),
                     );
                     self.position += 2;
                 }
                 _ => {
-                    return Err(ParserError::InvalidToken(start, end));
+                    return Err(lex_error(LexErrorKind::StrayBang, start, end + 1));
                 }
             },
-            _ => return Err(ParserError::InvalidToken(start, end)),
+            _ => return Err(lex_error(LexErrorKind::UnknownOperator, start, end + 1)),
         };
         Ok(())
     }
 
-    fn save_str(&self, start: usize, end: usize) -> &'a str {
-        unsafe { str::from_utf8_unchecked(&self.inner[start..=end]) }
+    /// 出错时把出错范围记为 Unknown token 并继续扫描，收集所有词法错误。
+    pub(crate) fn tokenize_recovering(
+        &mut self,
+        table: &mut TokenTable<'a>,
+        errors: &mut Vec<ParserError>,
+    ) {
+        while let Err(err) = self.tokenize(table) {
+            let Some(span) = err.span() else {
+                errors.push(err);
+                return;
+            };
+            table.push(TokenKind::Unknown, span);
+            self.position = span.end.max(self.position + 1);
+            errors.push(err);
+        }
     }
 
     pub(crate) fn tokenize(&mut self, table: &mut TokenTable<'a>) -> Result<(), ParserError> {
@@ -516,25 +769,76 @@ impl<'a> Lexer<'a> {
             if (char_class & C_ALP) != 0 {
                 if (char_class & C_DIG) != 0 {
                     let (kind, start, end) = self.scan_number()?;
-                    table.push(kind, self.save_str(start, end));
+                    table.push(kind, Span::new(start, end + 1));
                     self.position += 1;
                 } else {
                     let (kind, start, end) = self.scan_identify()?;
-                    table.push(kind, self.save_str(start, end));
+                    table.push(kind, Span::new(start, end + 1));
                     self.position += 1;
                 }
             } else if (char_class & C_SYM) != 0 {
                 self.scan_symbol(&mut *table)?;
-            } else if (char_class & C_QUO) != 0 {
-                let (kind, start, end) = self.scan_string(c)?;
-                table.push(kind, self.save_str(start, end));
-                self.position += 1;
-            } else {
+            } else if c == b'[' && Self::follows_operand(table, self.position) {
                 table.push(
-                    TokenKind::Unknown,
-                    self.save_str(self.position, self.position),
+                    TokenKind::LeftBracket,
+                    Span::new(self.position, self.position + 1),
                 );
                 self.position += 1;
+            } else if (char_class & C_QUO) != 0 {
+                let (kind, start, end) = match c {
+                    b'\'' => self.scan_string(c)?,
+                    b'[' => self.scan_quoted_identifier(b']')?,
+                    _ => self.scan_quoted_identifier(c)?,
+                };
+                table.push(kind, Span::new(start, end + 1));
+                self.position += 1;
+            } else if c == b'$' {
+                let scanned = match self.scan_dollar_string()? {
+                    Some(token) => Some(token),
+                    None => self.scan_placeholder(),
+                };
+                match scanned {
+                    Some((kind, start, end)) => table.push(kind, Span::new(start, end + 1)),
+                    None => table.push(
+                        TokenKind::Unknown,
+                        Span::new(self.position, self.position + 1),
+                    ),
+                }
+                self.position += 1;
+            } else if matches!(c, b'?' | b':' | b'@') {
+                // `::` 与 `@>` 是运算符，不是参数的开头
+                match (c, self.inner.get(self.position + 1)) {
+                    (b':', Some(b':')) => {
+                        table.push(
+                            TokenKind::DoubleColon,
+                            Span::new(self.position, self.position + 2),
+                        );
+                        self.position += 2;
+                        continue;
+                    }
+                    (b'@', Some(b'>')) => {
+                        table.push(
+                            TokenKind::AtArrow,
+                            Span::new(self.position, self.position + 2),
+                        );
+                        self.position += 2;
+                        continue;
+                    }
+                    _ => {}
+                }
+                match self.scan_placeholder() {
+                    Some((kind, start, end)) => table.push(kind, Span::new(start, end + 1)),
+                    None => table.push(
+                        TokenKind::Unknown,
+                        Span::new(self.position, self.position + 1),
+                    ),
+                }
+                self.position += 1;
+            } else {
+                // 非 ASCII 字符整体作为一个 Unknown，span 不能落在字符中间
+                let end = (self.position + utf8_char_len(c)).min(self.inner.len());
+                table.push(TokenKind::Unknown, Span::new(self.position, end));
+                self.position = end;
             }
         }
 
@@ -552,7 +856,7 @@ mod tests {
         sql: &'a str,
     ) -> Result<(Vec<TokenKind>, Vec<&'a str>), ParserError> {
         let mut table = TokenTable::with_source(sql);
-        let mut lexer = Lexer::new(sql, &keyword_map).unwrap();
+        let mut lexer = Lexer::new(sql, keyword_map).unwrap();
         lexer.tokenize(&mut table)?;
         Ok((table.tokens, table.source_ref_list))
     }
@@ -564,6 +868,16 @@ mod tests {
         lexer.tokenize(&mut table).is_err()
     }
 
+    fn tokenize_error(sql: &str) -> (LexErrorKind, &str) {
+        let keyword_map = KeywordMap::new().unwrap();
+        let mut table = TokenTable::with_source(sql);
+        let mut lexer = Lexer::new(sql, &keyword_map).unwrap();
+        match lexer.tokenize(&mut table) {
+            Err(ParserError::InvalidToken { kind, span }) => (kind, span.source_text(sql)),
+            other => panic!("expected lexer error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_skip_whitespace() {
         let keyword_map = KeywordMap::new().unwrap();
@@ -601,11 +915,17 @@ mod tests {
         );
         assert_eq!(
             tokenize(&keyword_map, "-123").unwrap(),
-            (vec![TokenKind::Number], vec!["-123"])
+            (
+                vec![TokenKind::Subtract, TokenKind::Number],
+                vec!["-", "123"]
+            )
         );
         assert_eq!(
             tokenize(&keyword_map, "-123.456").unwrap(),
-            (vec![TokenKind::Number], vec!["-123.456"])
+            (
+                vec![TokenKind::Subtract, TokenKind::Number],
+                vec!["-", "123.456"]
+            )
         );
         assert_eq!(
             tokenize(&keyword_map, "123_456_7890").unwrap(),
@@ -613,13 +933,16 @@ mod tests {
         );
         assert_eq!(
             tokenize(&keyword_map, "-123.456E10").unwrap(),
-            (vec![TokenKind::Number], vec!["-123.456E10"])
+            (
+                vec![TokenKind::Subtract, TokenKind::Number],
+                vec!["-", "123.456E10"]
+            )
         );
         assert_eq!(
             tokenize(&keyword_map, "-123.456_789E10").unwrap(),
             (
-                vec![TokenKind::Number],
-                vec!["-123.456_789E10"]
+                vec![TokenKind::Subtract, TokenKind::Number],
+                vec!["-", "123.456_789E10"]
             )
         );
         assert_eq!(
@@ -640,7 +963,10 @@ mod tests {
         );
         assert_eq!(
             tokenize(&keyword_map, "-0xDEAD").unwrap(),
-            (vec![TokenKind::Number], vec!["-0xDEAD"])
+            (
+                vec![TokenKind::Subtract, TokenKind::Number],
+                vec!["-", "0xDEAD"]
+            )
         );
         assert_eq!(
             tokenize(&keyword_map, "0o777").unwrap(),
@@ -690,19 +1016,7 @@ mod tests {
         assert_eq!(
             positions,
             vec![
-                "a",
-                ">",
-                "b",
-                ">=",
-                "c",
-                "<",
-                "d",
-                "<=",
-                "e",
-                "<>",
-                "f",
-                "=",
-                "g"
+                "a", ">", "b", ">=", "c", "<", "d", "<=", "e", "<>", "f", "=", "g"
             ]
         );
     }
@@ -716,17 +1030,11 @@ mod tests {
         );
         assert_eq!(
             tokenize(&keyword_map, "'helloWorld'").unwrap(),
-            (
-                vec![TokenKind::StringLiteral],
-                vec!["'helloWorld'"]
-            )
+            (vec![TokenKind::StringLiteral], vec!["'helloWorld'"])
         );
         assert_eq!(
             tokenize(&keyword_map, r#"'hello\\'"#).unwrap(),
-            (
-                vec![TokenKind::StringLiteral],
-                vec![r#"'hello\\'"#]
-            )
+            (vec![TokenKind::StringLiteral], vec![r#"'hello\\'"#])
         );
         assert_eq!(
             tokenize(
@@ -736,9 +1044,7 @@ mod tests {
             .unwrap(),
             (
                 vec![TokenKind::StringLiteral],
-                vec![
-                    "'aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa'"
-                ]
+                vec!["'aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa'"]
             )
         );
         assert_eq!(
@@ -749,9 +1055,7 @@ mod tests {
             .unwrap(),
             (
                 vec![TokenKind::StringLiteral],
-                vec![
-                    "\'aaaaaaaaaaaaa\\'aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\'"
-                ]
+                vec!["\'aaaaaaaaaaaaa\\'aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\'"]
             )
         );
     }
@@ -771,10 +1075,7 @@ mod tests {
             .unwrap(),
             (
                 vec![TokenKind::Identifier, TokenKind::Number],
-                vec![
-                    "qwertyuiopASDFGHJKL1234567890_zxcvbnm",
-                    "1234567890"
-                ]
+                vec!["qwertyuiopASDFGHJKL1234567890_zxcvbnm", "1234567890"]
             )
         );
     }
@@ -790,10 +1091,7 @@ mod tests {
                 TokenKind::Keyword(Keyword::From)
             ]
         );
-        assert_eq!(
-            positions,
-            vec!["select", "from"]
-        );
+        assert_eq!(positions, vec!["select", "from"]);
     }
 
     #[test]
@@ -809,15 +1107,7 @@ mod tests {
                 TokenKind::Identifier,
             ]
         );
-        assert_eq!(
-            positions,
-            vec![
-                "select",
-                "*",
-                "from",
-                "a"
-            ]
-        );
+        assert_eq!(positions, vec!["select", "*", "from", "a"]);
     }
 
     #[test]
@@ -851,25 +1141,304 @@ mod tests {
         assert_eq!(
             positions,
             vec![
-                "select",
-                "*",
-                "from",
-                "a",
-                "where",
-                "b",
-                "in",
-                "(",
-                "1",
-                ",",
-                "2",
-                ",",
-                "3",
-                ")",
-                "and",
-                "c",
-                "=",
-                "1",
+                "select", "*", "from", "a", "where", "b", "in", "(", "1", ",", "2", ",", "3", ")",
+                "and", "c", "=", "1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_error_kinds() {
+        assert_eq!(
+            tokenize_error("select 'abc"),
+            (LexErrorKind::UnterminatedString, "'abc")
+        );
+        assert_eq!(
+            tokenize_error("select 1 /* abc"),
+            (LexErrorKind::UnterminatedBlockComment, "/* abc")
+        );
+        assert_eq!(
+            tokenize_error("0x"),
+            (LexErrorKind::MalformedHexLiteral, "0x")
+        );
+        assert_eq!(
+            tokenize_error("0o8"),
+            (LexErrorKind::MalformedOctalLiteral, "0o")
+        );
+        assert_eq!(
+            tokenize_error("0b2"),
+            (LexErrorKind::MalformedBinaryLiteral, "0b")
+        );
+        assert_eq!(
+            tokenize_error("1e5e3"),
+            (LexErrorKind::RepeatedExponent, "e")
+        );
+        assert_eq!(
+            tokenize_error("1.5.3"),
+            (LexErrorKind::RepeatedDecimalPoint, ".")
+        );
+        assert_eq!(
+            tokenize_error("12abc"),
+            (LexErrorKind::MalformedNumber, "12a")
+        );
+        assert_eq!(tokenize_error("1e"), (LexErrorKind::MalformedNumber, "1e"));
+        assert_eq!(
+            tokenize_error(r"a \ b"),
+            (LexErrorKind::UnknownOperator, r"\")
+        );
+        assert_eq!(tokenize_error("!a"), (LexErrorKind::StrayBang, "!"));
+    }
+
+    #[test]
+    fn test_match_quoted_identifier() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(&keyword_map, r#""Order Details" `select` [my col]"#).unwrap(),
+            (
+                vec![
+                    TokenKind::QuotedIdentifier,
+                    TokenKind::QuotedIdentifier,
+                    TokenKind::QuotedIdentifier,
+                ],
+                vec![r#""Order Details""#, "`select`", "[my col]"]
+            )
+        );
+        assert_eq!(
+            tokenize(&keyword_map, r#""a""b" [x]]y]"#).unwrap(),
+            (
+                vec![TokenKind::QuotedIdentifier, TokenKind::QuotedIdentifier],
+                vec![r#""a""b""#, "[x]]y]"]
+            )
+        );
+        assert_eq!(
+            tokenize_error(r#"select "abc"#),
+            (LexErrorKind::UnterminatedQuotedIdentifier, r#""abc"#)
+        );
+    }
+
+    #[test]
+    fn test_match_array_brackets() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(&keyword_map, "int[ 3 ] a [b]").unwrap(),
+            (
+                vec![
+                    TokenKind::Identifier,
+                    TokenKind::LeftBracket,
+                    TokenKind::Number,
+                    TokenKind::RightBracket,
+                    TokenKind::Identifier,
+                    TokenKind::QuotedIdentifier,
+                ],
+                vec!["int", "[", "3", "]", "a", "[b]"]
+            )
+        );
+        assert_eq!(
+            tokenize(&keyword_map, "text[3][]").unwrap(),
+            (
+                vec![
+                    TokenKind::Identifier,
+                    TokenKind::LeftBracket,
+                    TokenKind::Number,
+                    TokenKind::RightBracket,
+                    TokenKind::LeftBracket,
+                    TokenKind::RightBracket,
+                ],
+                vec!["text", "[", "3", "]", "[", "]"]
+            )
+        );
+    }
+
+    #[test]
+    fn test_match_prefixed_string() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(&keyword_map, "'it''s' ''''").unwrap(),
+            (
+                vec![TokenKind::StringLiteral, TokenKind::StringLiteral],
+                vec!["'it''s'", "''''"]
+            )
+        );
+        assert_eq!(
+            tokenize(
+                &keyword_map,
+                r"E'a\\nb' N'abc' X'DEADbeef' b'1010' _utf8mb4'x' e"
+            )
+            .unwrap(),
+            (
+                vec![
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                    TokenKind::Identifier,
+                ],
+                vec![
+                    r"E'a\\nb'",
+                    "N'abc'",
+                    "X'DEADbeef'",
+                    "b'1010'",
+                    "_utf8mb4'x'",
+                    "e"
+                ]
+            )
+        );
+        assert_eq!(
+            tokenize_error("X'0G'"),
+            (LexErrorKind::MalformedHexLiteral, "X'0G'")
+        );
+        assert_eq!(
+            tokenize_error("B'102'"),
+            (LexErrorKind::MalformedBinaryLiteral, "B'102'")
+        );
+    }
+
+    #[test]
+    fn test_match_dollar_quoted_string() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(
+                &keyword_map,
+                "$$ it's $ here $$ $body$ a $$ b $bod $body$ $$$$"
+            )
+            .unwrap(),
+            (
+                vec![
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                    TokenKind::StringLiteral,
+                ],
+                vec!["$$ it's $ here $$", "$body$ a $$ b $bod $body$", "$$$$"]
+            )
+        );
+        assert_eq!(
+            tokenize_error("select $fn$ body $fn"),
+            (LexErrorKind::UnterminatedString, "$fn$ body $fn")
+        );
+    }
+
+    #[test]
+    fn test_match_placeholder() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(&keyword_map, "a = ? and b = $12 or c = :name_1 and d = @p").unwrap(),
+            (
+                vec![
+                    TokenKind::Identifier,
+                    TokenKind::Equal,
+                    TokenKind::Placeholder,
+                    TokenKind::Keyword(Keyword::And),
+                    TokenKind::Identifier,
+                    TokenKind::Equal,
+                    TokenKind::Placeholder,
+                    TokenKind::Keyword(Keyword::Or),
+                    TokenKind::Identifier,
+                    TokenKind::Equal,
+                    TokenKind::Placeholder,
+                    TokenKind::Keyword(Keyword::And),
+                    TokenKind::Identifier,
+                    TokenKind::Equal,
+                    TokenKind::Placeholder,
+                ],
+                vec![
+                    "a", "=", "?", "and", "b", "=", "$12", "or", "c", "=", ":name_1", "and", "d",
+                    "=", "@p"
+                ]
+            )
+        );
+        assert_eq!(
+            tokenize(&keyword_map, "(?,?) : @ $").unwrap(),
+            (
+                vec![
+                    TokenKind::LeftParen,
+                    TokenKind::Placeholder,
+                    TokenKind::Comma,
+                    TokenKind::Placeholder,
+                    TokenKind::RightParen,
+                    TokenKind::Unknown,
+                    TokenKind::Unknown,
+                    TokenKind::Unknown,
+                ],
+                vec!["(", "?", ",", "?", ")", ":", "@", "$"]
+            )
+        );
+    }
+
+    #[test]
+    fn test_match_extended_operators() {
+        let keyword_map = KeywordMap::new().unwrap();
+        let (kinds, sources) = tokenize(
+            &keyword_map,
+            "|| | :: -> ->> #> #>> @> <@ <=> <= < ~ ~* !~ !~* != - >",
+        )
+        .unwrap();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Concat,
+                TokenKind::BitOr,
+                TokenKind::DoubleColon,
+                TokenKind::Arrow,
+                TokenKind::LongArrow,
+                TokenKind::HashArrow,
+                TokenKind::HashLongArrow,
+                TokenKind::AtArrow,
+                TokenKind::ArrowAt,
+                TokenKind::Spaceship,
+                TokenKind::LessEqual,
+                TokenKind::Less,
+                TokenKind::Tilde,
+                TokenKind::TildeStar,
+                TokenKind::ExclamationTilde,
+                TokenKind::ExclamationTildeStar,
+                TokenKind::NotEqual,
+                TokenKind::Subtract,
+                TokenKind::Greater,
             ]
         );
+        assert_eq!(
+            sources,
+            vec![
+                "||", "|", "::", "->", "->>", "#>", "#>>", "@>", "<@", "<=>", "<=", "<", "~", "~*",
+                "!~", "!~*", "!=", "-", ">"
+            ]
+        );
+        assert_eq!(
+            tokenize(&keyword_map, "a::int @b :c").unwrap(),
+            (
+                vec![
+                    TokenKind::Identifier,
+                    TokenKind::DoubleColon,
+                    TokenKind::Identifier,
+                    TokenKind::Placeholder,
+                    TokenKind::Placeholder,
+                ],
+                vec!["a", "::", "int", "@b", ":c"]
+            )
+        );
+        assert_eq!(
+            tokenize_error("a # b"),
+            (LexErrorKind::UnknownOperator, "#")
+        );
+    }
+
+    #[test]
+    fn test_match_non_ascii_unknown() {
+        let keyword_map = KeywordMap::new().unwrap();
+        assert_eq!(
+            tokenize(&keyword_map, "select é, 中 from t").unwrap(),
+            (
+                vec![
+                    TokenKind::Keyword(Keyword::Select),
+                    TokenKind::Unknown,
+                    TokenKind::Comma,
+                    TokenKind::Unknown,
+                    TokenKind::Keyword(Keyword::From),
+                    TokenKind::Identifier,
+                ],
+                vec!["select", "é", ",", "中", "from", "t"]
+            )
+        );
     }
 }
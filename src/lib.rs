@@ -1,15 +1,30 @@
 pub mod ast;
 pub mod common;
+pub mod diagnostic;
 mod error;
 mod keyword;
 mod lexer;
 pub mod parser;
 mod simd_common;
+pub mod span;
 mod token;
 
 pub use ast::{
-    ddl::{AlterTable, AlterTableOperation, ColumnDef, CreateTable, DdlStatement, DropTable},
-    insert::InsertStatement, query::Query, select::SelectStatement, statement::Statement,
+    ddl::{
+        AlterTable, AlterTableOperation, ColumnConstraint, ColumnDef, CreateIndex, CreateTable,
+        DdlStatement, DropIndex, DropTable, TableConstraint,
+    },
+    insert::InsertStatement,
+    merge::MergeStatement,
+    query::Query,
+    select::SelectStatement,
+    statement::Statement,
+};
+pub use {
+    diagnostic::Diagnostic,
+    error::{LexErrorKind, ParserError},
+    keyword::Keyword,
+    parser::{Parser, Recovered},
+    span::{Span, Spanned},
+    token::TokenKind,
 };
-pub use ast::ddl::ColumnConstraint;
-pub use {error::ParserError, parser::Parser};
@@ -1,6 +1,23 @@
-use crate::{Statement, error::ParserError, keyword::KeywordMap, lexer::Lexer, token::TokenTable};
+use crate::{
+    Statement, diagnostic::Diagnostic, error::ParserError, keyword::KeywordMap, lexer::Lexer,
+    token::TokenTable,
+};
 use simdutf8::basic::from_utf8;
 
+/// Output of [`Parser::parse_recovering`]: every statement that parsed and
+/// every error met on the way, in source order.
+#[derive(Debug, PartialEq)]
+pub struct Recovered<'a> {
+    pub statement: Statement<'a>,
+    pub errors: Vec<ParserError>,
+}
+
+impl<'a> Recovered<'a> {
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
 #[derive(Debug)]
 pub struct Parser {
     keyword_map: KeywordMap,
@@ -17,12 +34,71 @@ impl Parser {
     pub fn parse<'a>(&'a self, text: &'a str) -> Result<Statement<'a>, ParserError> {
         let text = from_utf8(text.as_bytes())?;
         let mut tokentable = TokenTable::with_source(text);
+        self.parse_table(text, &mut tokentable)
+    }
+
+    /// Same as [`Parser::parse`], but failures come back as a [`Diagnostic`]
+    /// carrying the line, column, source snippet and the tokens the parser
+    /// would have accepted at that point.
+    pub fn parse_with_diagnostics<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Result<Statement<'a>, Box<Diagnostic>> {
+        let text = from_utf8(text.as_bytes())
+            .map_err(|err| Box::new(Diagnostic::from_error(err.into(), text)))?;
+        let mut tokentable = TokenTable::with_source(text);
+        self.parse_table(text, &mut tokentable).map_err(|err| {
+            // 只在失败时带着期望记录重新解析一遍，成功路径不受影响
+            let mut tokentable = TokenTable::with_expected(text);
+            let _ = self.parse_table(text, &mut tokentable);
+            let expected = err
+                .span()
+                .map(|span| tokentable.expected_at(span))
+                .unwrap_or_default();
+            Box::new(Diagnostic::new(err, text, expected))
+        })
+    }
+
+    /// Parses the whole input without stopping at the first error. A statement
+    /// that fails is skipped up to the next `;` and parsing carries on; with
+    /// `with_placeholders` it leaves a [`StatementInner::Error`] in its place.
+    ///
+    /// [`StatementInner::Error`]: crate::ast::statement::StatementInner::Error
+    pub fn parse_recovering<'a>(&'a self, text: &'a str, with_placeholders: bool) -> Recovered<'a> {
+        let mut errors = Vec::new();
+        let text = match from_utf8(text.as_bytes()) {
+            Ok(text) => text,
+            Err(err) => {
+                errors.push(err.into());
+                return Recovered {
+                    statement: Statement { list: Vec::new() },
+                    errors,
+                };
+            }
+        };
+        let mut tokentable = TokenTable::with_source(text);
+        match Lexer::new(text, &self.keyword_map) {
+            Ok(mut lexer) => lexer.tokenize_recovering(&mut tokentable, &mut errors),
+            Err(err) => errors.push(err),
+        }
+        let mut cursor = 0;
+        let statement =
+            Statement::new_recovering(&tokentable, &mut cursor, with_placeholders, &mut errors);
+        errors.sort_by_key(|err| err.span().map(|span| span.start));
+        Recovered { statement, errors }
+    }
+
+    fn parse_table<'a>(
+        &'a self,
+        text: &'a str,
+        tokentable: &mut TokenTable<'a>,
+    ) -> Result<Statement<'a>, ParserError> {
         {
             let mut lexer = Lexer::new(text, &self.keyword_map)?;
-            lexer.tokenize(&mut tokentable)?;
+            lexer.tokenize(tokentable)?;
         }
         let mut cursor = 0;
-        let statement = Statement::new(&tokentable, &mut cursor)?;
+        let statement = Statement::new(tokentable, &mut cursor)?;
         Ok(statement)
     }
 }
@@ -228,6 +228,7 @@ fn mixed_match_basic<const N1: usize, const N2: usize>(
     (start_pos, end_pos)
 }
 
+#[cfg(not(target_feature = "sse2"))]
 fn skip_until_match_basic<const N: usize>(
     slice: &[u8],
     matches: [u8; N],
@@ -244,6 +245,7 @@ fn skip_until_match_basic<const N: usize>(
     (start_pos, -1)
 }
 
+#[cfg(not(target_feature = "sse2"))]
 fn skip_until_sequence_basic<const N: usize>(
     slice: &[u8],
     sequence: [u8; N],
@@ -301,7 +301,7 @@ mod test {
         assert_eq!(end, 5);
 
         let slice = b"no match";
-        let (start, end) = Sse::skip_until_match(slice, [b'\''], 0);
+        let (_start, end) = Sse::skip_until_match(slice, [b'\''], 0);
         assert_eq!(end, -1);
 
         let slice = b"a\tb\nc\rd";
@@ -311,7 +311,7 @@ mod test {
 
         // start at later position
         let slice = b"abc'def'ghi";
-        let (start, end) = Sse::skip_until_match(slice, [b'\''], 4);
+        let (_start, end) = Sse::skip_until_match(slice, [b'\''], 4);
         assert_eq!(end, 7);
     }
 
@@ -323,13 +323,13 @@ mod test {
         assert_eq!(end, 7);
 
         let slice = b"no match";
-        let (start, end) = Sse::skip_until_sequence(slice, [b'*', b'/'], 0);
+        let (_start, end) = Sse::skip_until_sequence(slice, [b'*', b'/'], 0);
         assert_eq!(end, -1);
 
         // 跨 SSE 16 字节边界
         let mut data = vec![b'a'; 15];
         data.extend_from_slice(b"*/");
-        let (start, end) = Sse::skip_until_sequence(&data, [b'*', b'/'], 0);
+        let (_start, end) = Sse::skip_until_sequence(&data, [b'*', b'/'], 0);
         assert_eq!(end, 15);
     }
 }
@@ -1,8 +1,8 @@
-use std::{slice::SliceIndex};
+use std::{cell::RefCell, slice::SliceIndex};
 
 use strum::Display;
 
-use crate::keyword::Keyword;
+use crate::{keyword::Keyword, span::Span};
 
 #[derive(Debug, Clone, PartialEq, Eq, Display)]
 #[repr(u16)]
@@ -10,10 +10,17 @@ pub enum TokenKind {
     Number,
     StringLiteral,
     Identifier,
+    /// `"name"`、`` `name` `` 或 `[name]`，source 中包含引号
+    QuotedIdentifier,
+    /// 绑定参数 `?`、`$1`、`:name` 或 `@name`
+    Placeholder,
     Delimiter,
     Dot,
     LeftParen,
     RightParen,
+    /// 紧跟在名字或 `)` 后面的 `[`，用于数组类型后缀
+    LeftBracket,
+    RightBracket,
     LeftShift,
     RightShift,
     Comma,
@@ -31,34 +38,142 @@ pub enum TokenKind {
     Mod,
     BitXor,
     BitAnd,
-    Or,
+    BitOr,
+    /// `||`
+    Concat,
+    /// `::`
+    DoubleColon,
+    /// `->`
+    Arrow,
+    /// `->>`
+    LongArrow,
+    /// `#>`
+    HashArrow,
+    /// `#>>`
+    HashLongArrow,
+    /// `@>`
+    AtArrow,
+    /// `<@`
+    ArrowAt,
+    /// `<=>`
+    Spaceship,
+    Tilde,
+    /// `~*`
+    TildeStar,
+    /// `!~`
+    ExclamationTilde,
+    /// `!~*`
+    ExclamationTildeStar,
     Keyword(Keyword),
 }
 
+impl TokenKind {
+    /// 普通标识符或带引号的标识符
+    pub(crate) fn is_identifier(&self) -> bool {
+        matches!(self, TokenKind::Identifier | TokenKind::QuotedIdentifier)
+    }
+}
+
 #[derive(Debug)]
 pub struct TokenTable<'a> {
+    source: &'a str,
     pub tokens: Vec<TokenKind>,
     pub source_ref_list: Vec<&'a str>,
+    pub spans: Vec<Span>,
+    // 只有生成诊断信息时才记录，正常解析不付出这部分开销
+    record_expected: bool,
+    expected: RefCell<Expected>,
+}
+
+/// 解析器在最远位置上尝试匹配过的 token 种类，用于生成诊断信息。
+#[derive(Debug, Default)]
+struct Expected {
+    cursor: usize,
+    kinds: Vec<TokenKind>,
 }
 
 impl<'a> TokenTable<'a> {
     pub(crate) fn with_source(source: &'a str) -> Self {
         let cap = source.len() / 4;
         Self {
+            source,
             tokens: Vec::with_capacity(cap),
             source_ref_list: Vec::with_capacity(cap),
+            spans: Vec::with_capacity(cap),
+            record_expected: false,
+            expected: RefCell::new(Expected::default()),
         }
     }
 
-    pub(crate) fn push(&mut self, kind: TokenKind, source_ref: &'a str) {
+    /// 与 [`TokenTable::with_source`] 相同，但解析时会记录期望过的 token 种类。
+    pub(crate) fn with_expected(source: &'a str) -> Self {
+        Self {
+            record_expected: true,
+            ..Self::with_source(source)
+        }
+    }
+
+    pub(crate) fn push(&mut self, kind: TokenKind, span: Span) {
+        debug_assert!(
+            self.source.is_char_boundary(span.start) && self.source.is_char_boundary(span.end)
+        );
+        let source_ref =
+            unsafe { str::from_utf8_unchecked(&self.source.as_bytes()[span.start..span.end]) };
         self.tokens.push(kind);
         self.source_ref_list.push(source_ref);
+        self.spans.push(span);
     }
 
     pub(crate) fn source_at(&self, cursor: usize) -> &'a str {
         self.source_ref_list[cursor]
     }
 
+    /// 返回 cursor 处 token 的字节范围；越过末尾时返回源文本末尾的空范围。
+    pub(crate) fn span_at(&self, cursor: usize) -> Span {
+        match self.spans.get(cursor) {
+            Some(span) => *span,
+            None => Span::new(self.source.len(), self.source.len()),
+        }
+    }
+
+    /// 覆盖 `[start, end)` 这段 token 的字节范围。
+    pub(crate) fn span_between(&self, start: usize, end: usize) -> Span {
+        if end <= start {
+            return self.span_at(start);
+        }
+        self.span_at(start).union(self.span_at(end - 1))
+    }
+
+    /// 记录解析器在 cursor 处尝试过的 token 种类；只保留走得最远的位置。
+    #[inline]
+    pub(crate) fn record_expected(&self, cursor: usize, kind: &TokenKind) {
+        if self.record_expected {
+            self.push_expected(cursor, kind);
+        }
+    }
+
+    #[cold]
+    fn push_expected(&self, cursor: usize, kind: &TokenKind) {
+        let mut expected = self.expected.borrow_mut();
+        if cursor > expected.cursor {
+            expected.cursor = cursor;
+            expected.kinds.clear();
+        }
+        if cursor == expected.cursor && !expected.kinds.contains(kind) {
+            expected.kinds.push(kind.clone());
+        }
+    }
+
+    /// 如果错误发生在最远的尝试位置，返回该位置上期望过的 token 种类。
+    pub(crate) fn expected_at(&self, span: Span) -> Vec<TokenKind> {
+        let expected = self.expected.borrow();
+        if self.span_at(expected.cursor) == span {
+            expected.kinds.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
     pub(crate) fn get_kind<I>(&self, index: I) -> Option<&I::Output>
     where
         I: SliceIndex<[TokenKind]>,
@@ -1,17 +1,24 @@
 use minivec::mini_vec;
 use simd_sql::{
-    Parser, Query, SelectStatement, InsertStatement, Statement,
-    ast::insert::InsertValue,
+    Diagnostic, InsertStatement, Keyword, LexErrorKind, Parser, ParserError, Query,
+    SelectStatement, Span, Spanned, Statement, TokenKind,
+    ast::insert::{ConflictTarget, InsertValue, OnConflict},
+    ast::merge::{MergeAction, MergeMatch},
     ast::statement::StatementInner,
+    ast::update::AssignmentTarget,
     common::{
         alias::Alias,
+        data_type::{DataType, DataTypeKind},
         expr::{
-            BinaryOp, BinaryOperator, Expr, Field, FunctionCall, NumericLiteral, Star,
-            StringLiteral,
+            BinaryOp, BinaryOperator, Cast, CastKind, DateTimeField, DollarQuotedStringLiteral,
+            Expr, Extract, Field, FunctionCall, InValue, IntervalQualifier,
+            IntroducedStringLiteral, NullTreatment, NumericLiteral, Placeholder, PlaceholderStyle,
+            Position, Quantifier, Star, StringLiteral, TrimSide, TypedLiteral, UnaryOp,
+            UnaryOperator, WindowFrameBound, WindowFrameExclusion, WindowFrameUnits, WindowRef,
         },
         from::{From, Table},
         group::{Group, GroupByExpr},
-        limit::Limit,
+        ident::{Ident, QuoteStyle},
         order::{Order, OrderDirection, OrderItem},
     },
 };
@@ -42,38 +49,72 @@ fn test_p0_expect_kind_catches_eof() {
 #[test]
 fn test_p0_order_by_numeric_ordinal() {
     let p = Parser::new().unwrap();
-    let result = p.parse("SELECT id, name FROM users ORDER BY 1, 2 DESC").unwrap();
+    let result = p
+        .parse("SELECT id, name FROM users ORDER BY 1, 2 DESC")
+        .unwrap();
     assert_eq!(
         result,
         Statement {
             list: vec![StatementInner::Query(Query::Select(SelectStatement {
                 distinct: false,
                 columns: vec![
-                    Alias { name: None, value: Expr::Field(Field { prefix: None, name: "id" }) },
-                    Alias { name: None, value: Expr::Field(Field { prefix: None, name: "name" }) },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("id"),
+                            span: Span::new(7, 9)
+                        }),
+                        span: Span::new(7, 9)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("name"),
+                            span: Span::new(11, 15)
+                        }),
+                        span: Span::new(11, 15)
+                    },
                 ],
                 from: Some(mini_vec![From::Table(Table::Name(Alias {
                     name: None,
-                    value: Expr::Field(Field { prefix: None, name: "users" })
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(21, 26)
+                    }),
+                    span: Span::new(21, 26),
                 }))]),
                 where_statement: None,
                 group_by: None,
                 having_statement: None,
+                window: None,
                 order_by: Some(Order {
                     columns: mini_vec![
                         OrderItem {
-                            expr: Expr::NumericLiteral(NumericLiteral { value: "1" }),
+                            expr: Expr::NumericLiteral(NumericLiteral {
+                                value: "1",
+                                span: Span::new(36, 37)
+                            }),
                             direction: OrderDirection::ASC,
                             nulls_order: None,
+                            span: Span::new(36, 37),
                         },
                         OrderItem {
-                            expr: Expr::NumericLiteral(NumericLiteral { value: "2" }),
+                            expr: Expr::NumericLiteral(NumericLiteral {
+                                value: "2",
+                                span: Span::new(39, 40)
+                            }),
                             direction: OrderDirection::DESC,
                             nulls_order: None,
+                            span: Span::new(39, 45),
                         },
-                    ]
+                    ],
+                    span: Span::new(27, 45),
                 }),
                 limit: None,
+                span: Span::new(0, 45),
             }))]
         }
     );
@@ -84,26 +125,26 @@ fn test_p0_order_and_group_ordinal() {
     let p = Parser::new().unwrap();
     // ORDER BY 1 DESC silently skipped before the fix
     let r = p.parse("SELECT id FROM users ORDER BY 1 DESC").unwrap();
-    match r {
-        Statement { list } => match &list[0] {
-            StatementInner::Query(Query::Select(stmt)) => {
-                assert!(stmt.order_by.is_some(), "ORDER BY should not be empty");
-                let order = stmt.order_by.as_ref().unwrap();
-                assert_eq!(order.columns.len(), 1);
-                assert_eq!(order.columns[0].direction, OrderDirection::DESC);
-            }
-            _ => panic!("expected Select"),
-        },
+    let Statement { list } = r;
+    match &list[0] {
+        StatementInner::Query(Query::Select(stmt)) => {
+            assert!(stmt.order_by.is_some(), "ORDER BY should not be empty");
+            let order = stmt.order_by.as_ref().unwrap();
+            assert_eq!(order.columns.len(), 1);
+            assert_eq!(order.columns[0].direction, OrderDirection::DESC);
+        }
+        _ => panic!("expected Select"),
     }
     // GROUP BY 2 should not be silently skipped
-    let r = p.parse("SELECT COUNT(*), dept FROM emp GROUP BY 2").unwrap();
-    match r {
-        Statement { list } => match &list[0] {
-            StatementInner::Query(Query::Select(stmt)) => {
-                assert!(stmt.group_by.is_some(), "GROUP BY should not be empty");
-            }
-            _ => panic!("expected Select"),
-        },
+    let r = p
+        .parse("SELECT COUNT(*), dept FROM emp GROUP BY 2")
+        .unwrap();
+    let Statement { list } = r;
+    match &list[0] {
+        StatementInner::Query(Query::Select(stmt)) => {
+            assert!(stmt.group_by.is_some(), "GROUP BY should not be empty");
+        }
+        _ => panic!("expected Select"),
     }
 }
 
@@ -114,31 +155,65 @@ fn test_p0_order_and_group_ordinal() {
 #[test]
 fn test_p0_group_by_numeric_ordinal() {
     let p = Parser::new().unwrap();
-    let result = p.parse("SELECT COUNT(*), dept FROM emp GROUP BY 2").unwrap();
+    let result = p
+        .parse("SELECT COUNT(*), dept FROM emp GROUP BY 2")
+        .unwrap();
     assert_eq!(
         result,
         Statement {
             list: vec![StatementInner::Query(Query::Select(SelectStatement {
                 distinct: false,
                 columns: vec![
-                    Alias { name: None, value: Expr::FunctionCall(FunctionCall {
-                        name: "COUNT",
-                        args: mini_vec![Expr::Star(Star { prefix: None })],
-                        distinct: false,
-                    })},
-                    Alias { name: None, value: Expr::Field(Field { prefix: None, name: "dept" })},
+                    Alias {
+                        name: None,
+                        value: Expr::FunctionCall(Box::new(FunctionCall {
+                            name: "COUNT",
+                            args: mini_vec![Expr::Star(Star {
+                                prefix: None,
+                                span: Span::new(13, 14)
+                            })],
+                            distinct: false,
+                            null_treatment: None,
+                            order_by: None,
+                            limit: None,
+                            within_group: None,
+                            filter: None,
+                            span: Span::new(7, 15),
+                        })),
+                        span: Span::new(7, 15)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("dept"),
+                            span: Span::new(17, 21)
+                        }),
+                        span: Span::new(17, 21)
+                    },
                 ],
                 from: Some(mini_vec![From::Table(Table::Name(Alias {
                     name: None,
-                    value: Expr::Field(Field { prefix: None, name: "emp" })
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("emp"),
+                        span: Span::new(27, 30)
+                    }),
+                    span: Span::new(27, 30),
                 }))]),
                 where_statement: None,
                 group_by: Some(Group {
-                    columns: mini_vec![GroupByExpr::Simple(Expr::NumericLiteral(NumericLiteral { value: "2" }))],
+                    columns: mini_vec![GroupByExpr::Simple(Expr::NumericLiteral(NumericLiteral {
+                        value: "2",
+                        span: Span::new(40, 41)
+                    }))],
+                    span: Span::new(31, 41),
                 }),
                 having_statement: None,
+                window: None,
                 order_by: None,
                 limit: None,
+                span: Span::new(0, 41),
             }))]
         }
     );
@@ -158,15 +233,29 @@ fn test_p1_insert_without_column_list() {
             list: vec![StatementInner::Insert(InsertStatement {
                 table: Table::Name(Alias {
                     name: None,
-                    value: Expr::Field(Field { prefix: None, name: "users" }),
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(12, 17)
+                    }),
+                    span: Span::new(12, 17),
                 }),
                 insert_value: InsertValue::Values {
                     columns: mini_vec![],
                     values: mini_vec![mini_vec![
-                        Expr::NumericLiteral(NumericLiteral { value: "1" }),
-                        Expr::StringLiteral(StringLiteral { value: "'Alice'" }),
+                        Expr::NumericLiteral(NumericLiteral {
+                            value: "1",
+                            span: Span::new(26, 27)
+                        }),
+                        Expr::StringLiteral(StringLiteral {
+                            value: "'Alice'",
+                            span: Span::new(29, 36)
+                        }),
                     ]],
                 },
+                on_conflict: None,
+                returning: None,
+                span: Span::new(0, 37),
             })]
         }
     );
@@ -186,35 +275,63 @@ fn test_p1_insert_without_column_list_multiple_rows() {
 #[test]
 fn test_p1_bitwise_and_or_xor() {
     let p = Parser::new().unwrap();
+    // `&`、`|`、`^` 同级且左结合：((a & b) | c) ^ d
     let result = p.parse("SELECT a & b | c ^ d FROM t").unwrap();
     let expected_expr = Expr::BinaryOp(Box::new(BinaryOp {
-        op: BinaryOperator::Or,
+        op: BinaryOperator::BitXor,
         left: Expr::BinaryOp(Box::new(BinaryOp {
-            op: BinaryOperator::BitAnd,
-            left: Expr::Field(Field { prefix: None, name: "a" }),
-            right: Expr::Field(Field { prefix: None, name: "b" }),
-        })),
-        right: Expr::BinaryOp(Box::new(BinaryOp {
-            op: BinaryOperator::BitXor,
-            left: Expr::Field(Field { prefix: None, name: "c" }),
-            right: Expr::Field(Field { prefix: None, name: "d" }),
+            op: BinaryOperator::BitOr,
+            left: Expr::BinaryOp(Box::new(BinaryOp {
+                op: BinaryOperator::BitAnd,
+                left: Expr::Field(Field {
+                    prefix: None,
+                    name: Ident::new("a"),
+                    span: Span::new(7, 8),
+                }),
+                right: Expr::Field(Field {
+                    prefix: None,
+                    name: Ident::new("b"),
+                    span: Span::new(11, 12),
+                }),
+            })),
+            right: Expr::Field(Field {
+                prefix: None,
+                name: Ident::new("c"),
+                span: Span::new(15, 16),
+            }),
         })),
+        right: Expr::Field(Field {
+            prefix: None,
+            name: Ident::new("d"),
+            span: Span::new(19, 20),
+        }),
     }));
     assert_eq!(
         result,
         Statement {
             list: vec![StatementInner::Query(Query::Select(SelectStatement {
                 distinct: false,
-                columns: vec![Alias { name: None, value: expected_expr }],
+                columns: vec![Alias {
+                    name: None,
+                    value: expected_expr,
+                    span: Span::new(7, 20)
+                }],
                 from: Some(mini_vec![From::Table(Table::Name(Alias {
                     name: None,
-                    value: Expr::Field(Field { prefix: None, name: "t" })
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("t"),
+                        span: Span::new(26, 27)
+                    }),
+                    span: Span::new(26, 27),
                 }))]),
                 where_statement: None,
                 group_by: None,
                 having_statement: None,
+                window: None,
                 order_by: None,
                 limit: None,
+                span: Span::new(0, 27),
             }))]
         }
     );
@@ -235,7 +352,10 @@ fn test_p1_shift_operators() {
 fn test_regression_basic_select() {
     let p = Parser::new().unwrap();
     assert!(p.parse("SELECT 1").is_ok(), "basic SELECT should parse");
-    assert!(p.parse("SELECT * FROM users").is_ok(), "SELECT * should parse");
+    assert!(
+        p.parse("SELECT * FROM users").is_ok(),
+        "SELECT * should parse"
+    );
     assert!(
         p.parse("SELECT id, name FROM users WHERE age > 18").is_ok(),
         "SELECT with WHERE should parse"
@@ -251,16 +371,30 @@ fn test_regression_select_star_from_table() {
         Statement {
             list: vec![StatementInner::Query(Query::Select(SelectStatement {
                 distinct: false,
-                columns: vec![Alias { name: None, value: Expr::Star(Star { prefix: None }) }],
+                columns: vec![Alias {
+                    name: None,
+                    value: Expr::Star(Star {
+                        prefix: None,
+                        span: Span::new(7, 8)
+                    }),
+                    span: Span::new(7, 8)
+                }],
                 from: Some(mini_vec![From::Table(Table::Name(Alias {
                     name: None,
-                    value: Expr::Field(Field { prefix: None, name: "users" })
+                    value: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("users"),
+                        span: Span::new(14, 19)
+                    }),
+                    span: Span::new(14, 19),
                 }))]),
                 where_statement: None,
                 group_by: None,
                 having_statement: None,
+                window: None,
                 order_by: None,
                 limit: None,
+                span: Span::new(0, 19),
             }))]
         }
     );
@@ -278,22 +412,21 @@ fn test_full_select_all_clauses_with_ast() {
                ORDER BY cnt DESC LIMIT 5";
     let result = p.parse(sql).unwrap();
     // Verify the structure
-    match result {
-        Statement { list } => {
-            assert_eq!(list.len(), 1);
-            match &list[0] {
-                StatementInner::Query(Query::Select(stmt)) => {
-                    assert_eq!(stmt.columns.len(), 2);
-                    assert!(stmt.from.is_some());
-                    assert!(stmt.where_statement.is_some());
-                    assert!(stmt.group_by.is_some());
-                    assert!(stmt.having_statement.is_some());
-                    assert!(stmt.order_by.is_some());
-                    assert!(stmt.limit.is_some());
-                    assert_eq!(stmt.distinct, false);
-                }
-                _ => panic!("Expected Query::Select"),
+    let Statement { list } = result;
+    {
+        assert_eq!(list.len(), 1);
+        match &list[0] {
+            StatementInner::Query(Query::Select(stmt)) => {
+                assert_eq!(stmt.columns.len(), 2);
+                assert!(stmt.from.is_some());
+                assert!(stmt.where_statement.is_some());
+                assert!(stmt.group_by.is_some());
+                assert!(stmt.having_statement.is_some());
+                assert!(stmt.order_by.is_some());
+                assert!(stmt.limit.is_some());
+                assert!(!stmt.distinct);
             }
+            _ => panic!("Expected Query::Select"),
         }
     }
 }
@@ -306,11 +439,23 @@ fn test_full_select_all_clauses_with_ast() {
 fn test_all_join_types() {
     let p = Parser::new().unwrap();
     assert!(p.parse("SELECT * FROM a JOIN b ON a.id = b.id").is_ok());
-    assert!(p.parse("SELECT * FROM a LEFT JOIN b ON a.id = b.id").is_ok());
-    assert!(p.parse("SELECT * FROM a RIGHT JOIN b ON a.id = b.id").is_ok());
-    assert!(p.parse("SELECT * FROM a INNER JOIN b ON a.id = b.id").is_ok());
+    assert!(
+        p.parse("SELECT * FROM a LEFT JOIN b ON a.id = b.id")
+            .is_ok()
+    );
+    assert!(
+        p.parse("SELECT * FROM a RIGHT JOIN b ON a.id = b.id")
+            .is_ok()
+    );
+    assert!(
+        p.parse("SELECT * FROM a INNER JOIN b ON a.id = b.id")
+            .is_ok()
+    );
     assert!(p.parse("SELECT * FROM a CROSS JOIN b").is_ok());
-    assert!(p.parse("SELECT * FROM a FULL JOIN b ON a.id = b.id").is_ok());
+    assert!(
+        p.parse("SELECT * FROM a FULL JOIN b ON a.id = b.id")
+            .is_ok()
+    );
 }
 
 // ============================================================================
@@ -321,7 +466,8 @@ fn test_all_join_types() {
 fn test_subquery_in_from() {
     let p = Parser::new().unwrap();
     assert!(
-        p.parse("SELECT * FROM (SELECT id, name FROM users) AS u").is_ok(),
+        p.parse("SELECT * FROM (SELECT id, name FROM users) AS u")
+            .is_ok(),
         "subquery in FROM should parse"
     );
     assert!(
@@ -331,6 +477,69 @@ fn test_subquery_in_from() {
     );
 }
 
+#[test]
+fn test_set_operation_and_cte_in_subquery_positions() {
+    let p = Parser::new().unwrap();
+
+    let result = p
+        .parse("SELECT * FROM t WHERE a IN (SELECT a FROM x UNION SELECT a FROM y)")
+        .unwrap();
+    let Expr::In(in_expr) = where_clause(&result) else {
+        panic!("expected In");
+    };
+    let InValue::Subquery(subquery) = &in_expr.in_value else {
+        panic!("expected subquery");
+    };
+    assert!(matches!(**subquery, Query::SetOperation { .. }));
+
+    let result = p
+        .parse("SELECT * FROM (WITH t AS (SELECT 1) SELECT * FROM t) s")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let Some(From::Table(Table::SubQuery(derived))) = stmt.from.as_ref().map(|from| &from[0])
+    else {
+        panic!("expected derived table");
+    };
+    assert!(matches!(*derived.value, Query::Cte { .. }));
+
+    let result = p
+        .parse("SELECT * FROM ((SELECT a FROM x) UNION (SELECT a FROM y)) s")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let Some(From::Table(Table::SubQuery(derived))) = stmt.from.as_ref().map(|from| &from[0])
+    else {
+        panic!("expected derived table");
+    };
+    assert!(matches!(*derived.value, Query::SetOperation { .. }));
+
+    let result = p
+        .parse("SELECT * FROM t WHERE EXISTS (SELECT 1 EXCEPT SELECT 2)")
+        .unwrap();
+    let Expr::Exists(exists) = where_clause(&result) else {
+        panic!("expected Exists");
+    };
+    assert!(matches!(*exists.subquery, Query::SetOperation { .. }));
+}
+
+#[test]
+fn test_in_list_with_parenthesized_values() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT * FROM t WHERE a IN ((1), (SELECT 2))";
+    let result = p.parse(sql).unwrap();
+    let Expr::In(in_expr) = where_clause(&result) else {
+        panic!("expected In");
+    };
+    let InValue::List(values) = &in_expr.in_value else {
+        panic!("expected value list");
+    };
+    assert!(matches!(values[1], Expr::Subquery(_)));
+    assert_eq!(in_expr.span.source_text(sql), "a IN ((1), (SELECT 2))");
+}
+
 // ============================================================================
 // 表达式验证
 // ============================================================================
@@ -345,9 +554,15 @@ fn test_complex_boolean_expression() {
 #[test]
 fn test_not_between_not_in_not_like() {
     let p = Parser::new().unwrap();
-    assert!(p.parse("SELECT * FROM t WHERE price NOT BETWEEN 10 AND 20").is_ok());
+    assert!(
+        p.parse("SELECT * FROM t WHERE price NOT BETWEEN 10 AND 20")
+            .is_ok()
+    );
     assert!(p.parse("SELECT * FROM t WHERE id NOT IN (1, 2, 3)").is_ok());
-    assert!(p.parse("SELECT * FROM t WHERE name NOT LIKE 'Test%'").is_ok());
+    assert!(
+        p.parse("SELECT * FROM t WHERE name NOT LIKE 'Test%'")
+            .is_ok()
+    );
 }
 
 #[test]
@@ -356,25 +571,36 @@ fn test_bitwise_expression_precedence() {
     // & has precedence 6, + has precedence 7, so + binds tighter
     // a + b & c → (a + b) & c
     let result = p.parse("SELECT a + b & c FROM t").unwrap();
-    match result {
-        Statement { list } => {
-            match &list[0] {
-                StatementInner::Query(Query::Select(stmt)) => {
-                    assert_eq!(stmt.columns.len(), 1);
-                    // verify the outermost op is BitAnd with Add on the left
-                    let expected = Expr::BinaryOp(Box::new(BinaryOp {
-                        op: BinaryOperator::BitAnd,
-                        left: Expr::BinaryOp(Box::new(BinaryOp {
-                            op: BinaryOperator::Add,
-                            left: Expr::Field(Field { prefix: None, name: "a" }),
-                            right: Expr::Field(Field { prefix: None, name: "b" }),
-                        })),
-                        right: Expr::Field(Field { prefix: None, name: "c" }),
-                    }));
-                    assert_eq!(&stmt.columns[0].value, &expected);
-                }
-                _ => panic!("expected Select"),
+    let Statement { list } = result;
+    {
+        match &list[0] {
+            StatementInner::Query(Query::Select(stmt)) => {
+                assert_eq!(stmt.columns.len(), 1);
+                // verify the outermost op is BitAnd with Add on the left
+                let expected = Expr::BinaryOp(Box::new(BinaryOp {
+                    op: BinaryOperator::BitAnd,
+                    left: Expr::BinaryOp(Box::new(BinaryOp {
+                        op: BinaryOperator::Add,
+                        left: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("a"),
+                            span: Span::new(7, 8),
+                        }),
+                        right: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("b"),
+                            span: Span::new(11, 12),
+                        }),
+                    })),
+                    right: Expr::Field(Field {
+                        prefix: None,
+                        name: Ident::new("c"),
+                        span: Span::new(15, 16),
+                    }),
+                }));
+                assert_eq!(&stmt.columns[0].value, &expected);
             }
+            _ => panic!("expected Select"),
         }
     }
 }
@@ -386,8 +612,14 @@ fn test_bitwise_expression_precedence() {
 #[test]
 fn test_dml_statements() {
     let p = Parser::new().unwrap();
-    assert!(p.parse("INSERT INTO users (id, name) VALUES (1, 'Alice')").is_ok());
-    assert!(p.parse("UPDATE users SET name = 'Bob' WHERE id = 1").is_ok());
+    assert!(
+        p.parse("INSERT INTO users (id, name) VALUES (1, 'Alice')")
+            .is_ok()
+    );
+    assert!(
+        p.parse("UPDATE users SET name = 'Bob' WHERE id = 1")
+            .is_ok()
+    );
     assert!(p.parse("DELETE FROM users WHERE id = 1").is_ok());
     assert!(p.parse("DELETE FROM users").is_ok());
 }
@@ -396,36 +628,381 @@ fn test_dml_statements() {
 fn test_insert_select() {
     let p = Parser::new().unwrap();
     let result = p.parse("INSERT INTO t SELECT * FROM s").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Insert(insert_stmt) => match &insert_stmt.insert_value {
-                InsertValue::AllSelect { select } => {
-                    assert_eq!(select.columns.len(), 1);
-                }
-                _ => panic!("expected AllSelect"),
-            },
-            _ => panic!("expected Insert"),
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Insert(insert_stmt) => match &insert_stmt.insert_value {
+            InsertValue::AllSelect {
+                select: Query::Select(select),
+            } => {
+                assert_eq!(select.columns.len(), 1);
+            }
+            _ => panic!("expected AllSelect"),
         },
+        _ => panic!("expected Insert"),
     }
 }
 
 #[test]
 fn test_insert_partof_select() {
     let p = Parser::new().unwrap();
-    let result = p.parse("INSERT INTO t (id, name) SELECT id, name FROM s").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Insert(insert_stmt) => match &insert_stmt.insert_value {
-                InsertValue::PartOfSelect { columns, .. } => {
-                    assert_eq!(columns.len(), 2);
-                }
-                _ => panic!("expected PartOfSelect"),
-            },
-            _ => panic!("expected Insert"),
+    let result = p
+        .parse("INSERT INTO t (id, name) SELECT id, name FROM s")
+        .unwrap();
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Insert(insert_stmt) => match &insert_stmt.insert_value {
+            InsertValue::PartOfSelect { columns, .. } => {
+                assert_eq!(columns.len(), 2);
+            }
+            _ => panic!("expected PartOfSelect"),
         },
+        _ => panic!("expected Insert"),
     }
 }
 
+#[test]
+fn test_insert_query_with_cte_and_set_operation() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse("INSERT INTO t (id) WITH s AS (SELECT 1) SELECT * FROM s")
+        .unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let InsertValue::PartOfSelect { select, columns } = &insert_stmt.insert_value else {
+        panic!("expected PartOfSelect");
+    };
+    assert_eq!(columns.len(), 1);
+    assert!(matches!(select, Query::Cte { .. }));
+
+    let result = p
+        .parse("INSERT INTO t SELECT a FROM x UNION ALL SELECT a FROM y")
+        .unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    assert!(matches!(
+        insert_stmt.insert_value,
+        InsertValue::AllSelect {
+            select: Query::SetOperation { .. }
+        }
+    ));
+}
+
+#[test]
+fn test_insert_default_values() {
+    let p = Parser::new().unwrap();
+    let result = p.parse("INSERT INTO t DEFAULT VALUES").unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    assert_eq!(insert_stmt.insert_value, InsertValue::DefaultValues);
+
+    let sql = "INSERT INTO t (a, b) VALUES (1, DEFAULT), (DEFAULT, 2)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let InsertValue::Values { values, .. } = &insert_stmt.insert_value else {
+        panic!("expected Values");
+    };
+    let Expr::Default(default) = &values[0][1] else {
+        panic!("expected DEFAULT, got {:?}", values[0][1]);
+    };
+    assert_eq!(default.span.source_text(sql), "DEFAULT");
+    assert!(matches!(values[1][0], Expr::Default(_)));
+
+    assert!(p.parse("INSERT INTO t DEFAULT").is_err());
+}
+
+#[test]
+fn test_insert_on_conflict() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse("INSERT INTO t (id, n) VALUES (1, 2) ON CONFLICT (id) DO NOTHING")
+        .unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let Some(OnConflict::DoNothing {
+        target: Some(ConflictTarget::Columns(columns)),
+        ..
+    }) = insert_stmt.on_conflict.as_deref()
+    else {
+        panic!("expected DO NOTHING, got {:?}", insert_stmt.on_conflict);
+    };
+    assert_eq!(columns.as_slice(), &[Ident::new("id")]);
+
+    let sql = "INSERT INTO t (id, n) VALUES (1, 2) ON CONFLICT ON CONSTRAINT t_pkey \
+               DO UPDATE SET n = excluded.n, m = t.m + 1 WHERE t.n < excluded.n";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let Some(OnConflict::DoUpdate {
+        target: Some(ConflictTarget::Constraint(name)),
+        assignments,
+        where_statement: Some(_),
+        span,
+    }) = insert_stmt.on_conflict.as_deref()
+    else {
+        panic!("expected DO UPDATE, got {:?}", insert_stmt.on_conflict);
+    };
+    assert_eq!(*name, "t_pkey");
+    assert_eq!(assignments.len(), 2);
+    assert!(span.source_text(sql).starts_with("ON CONFLICT"));
+    assert_eq!(insert_stmt.span.source_text(sql), sql);
+
+    let result = p
+        .parse("INSERT INTO t VALUES (1) ON CONFLICT DO NOTHING")
+        .unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    assert!(matches!(
+        insert_stmt.on_conflict.as_deref(),
+        Some(OnConflict::DoNothing { target: None, .. })
+    ));
+
+    assert!(
+        p.parse("INSERT INTO t VALUES (1) ON CONFLICT (id) DO")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_insert_on_duplicate_key_update() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "INSERT INTO t (a, b) VALUES (1, 2) ON DUPLICATE KEY UPDATE b = VALUES(b), c = c + 1",
+        )
+        .unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let Some(OnConflict::DuplicateKeyUpdate { assignments, .. }) =
+        insert_stmt.on_conflict.as_deref()
+    else {
+        panic!("expected ON DUPLICATE KEY UPDATE");
+    };
+    assert_eq!(assignments.len(), 2);
+    let Expr::FunctionCall(values) = &assignments[0].value else {
+        panic!("expected VALUES(b)");
+    };
+    assert_eq!(values.name, "VALUES");
+
+    assert!(
+        p.parse("INSERT INTO t VALUES (1) ON DUPLICATE UPDATE b = 1")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_update_from_and_tuple_assignment() {
+    let p = Parser::new().unwrap();
+    let sql = "UPDATE t SET a = 1, (b, t.c) = (SELECT x, y FROM u WHERE u.id = t.id), d = DEFAULT \
+               FROM u, v WHERE t.id = u.id";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Update(update_stmt) = &result.list[0] else {
+        panic!("expected Update");
+    };
+    let assignments = &update_stmt.assignments;
+    assert_eq!(assignments.len(), 3);
+
+    let AssignmentTarget::Column(column) = &assignments[0].target else {
+        panic!("expected column target");
+    };
+    assert_eq!(column.name, "a");
+    assert!(matches!(assignments[0].value, Expr::NumericLiteral(_)));
+    assert_eq!(assignments[0].span.source_text(sql), "a = 1");
+
+    let AssignmentTarget::Tuple(columns) = &assignments[1].target else {
+        panic!("expected tuple target");
+    };
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[1].prefix, Some(Ident::new("t")));
+    assert!(matches!(assignments[1].value, Expr::Subquery(_)));
+
+    assert!(matches!(assignments[2].value, Expr::Default(_)));
+    assert_eq!(update_stmt.from.as_ref().map(|from| from.len()), Some(2));
+    assert!(update_stmt.where_statement.is_some());
+
+    assert!(p.parse("UPDATE t SET a + 1 = 2").is_err());
+    assert!(p.parse("UPDATE t SET (a, b) 1").is_err());
+}
+
+#[test]
+fn test_delete_using() {
+    let p = Parser::new().unwrap();
+    let sql = "DELETE FROM t USING u JOIN v ON u.a = v.a, w WHERE t.id = u.id";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Delete(delete_stmt) = &result.list[0] else {
+        panic!("expected Delete");
+    };
+    let using = delete_stmt.using.as_ref().expect("expected USING");
+    assert_eq!(using.len(), 2);
+    assert!(matches!(using[0], From::InnerJoin { .. }));
+    assert!(delete_stmt.conditions.is_some());
+    assert_eq!(delete_stmt.span.source_text(sql), sql);
+
+    assert!(p.parse("DELETE FROM t USING WHERE a = 1").is_err());
+}
+
+#[test]
+fn test_returning_clause() {
+    let p = Parser::new().unwrap();
+    let sql = "INSERT INTO t (a) VALUES (1) ON CONFLICT DO NOTHING RETURNING id, created_at AS ts";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Insert(insert_stmt) = &result.list[0] else {
+        panic!("expected Insert");
+    };
+    let returning = insert_stmt.returning.as_ref().expect("expected RETURNING");
+    assert_eq!(returning.len(), 2);
+    assert_eq!(returning[1].name, Some(Ident::new("ts")));
+    assert_eq!(insert_stmt.span.source_text(sql), sql);
+
+    let result = p
+        .parse("UPDATE t SET a = 1 WHERE id = 2 RETURNING *")
+        .unwrap();
+    let StatementInner::Update(update_stmt) = &result.list[0] else {
+        panic!("expected Update");
+    };
+    let returning = update_stmt.returning.as_ref().expect("expected RETURNING");
+    assert!(matches!(returning[0].value, Expr::Star(_)));
+
+    let result = p
+        .parse("DELETE FROM t WHERE id = 2 RETURNING id, a + 1; SELECT 1")
+        .unwrap();
+    assert_eq!(result.list.len(), 2);
+    let StatementInner::Delete(delete_stmt) = &result.list[0] else {
+        panic!("expected Delete");
+    };
+    let returning = delete_stmt.returning.as_ref().expect("expected RETURNING");
+    assert!(matches!(returning[1].value, Expr::BinaryOp(_)));
+
+    let result = p.parse("DELETE FROM t").unwrap();
+    let StatementInner::Delete(delete_stmt) = &result.list[0] else {
+        panic!("expected Delete");
+    };
+    assert_eq!(delete_stmt.returning, None);
+
+    assert!(p.parse("DELETE FROM t x RETURNING").is_err());
+
+    // RETURNING 不是保留字：后面没有返回列时按别名或列名处理
+    let result = p
+        .parse("DELETE FROM t returning WHERE returning.id = 1")
+        .unwrap();
+    let StatementInner::Delete(delete_stmt) = &result.list[0] else {
+        panic!("expected Delete");
+    };
+    assert_eq!(delete_stmt.returning, None);
+    let result = p.parse("DELETE FROM t r RETURNING returning").unwrap();
+    let StatementInner::Delete(delete_stmt) = &result.list[0] else {
+        panic!("expected Delete");
+    };
+    assert_eq!(delete_stmt.returning.as_ref().map(Vec::len), Some(1));
+}
+
+#[test]
+fn test_merge_statement() {
+    let p = Parser::new().unwrap();
+    let sql = "MERGE INTO t AS tg USING (SELECT id, v FROM s) AS src ON tg.id = src.id \
+               WHEN MATCHED AND src.v IS NULL THEN DELETE \
+               WHEN MATCHED THEN UPDATE SET v = src.v, n = n + 1 \
+               WHEN NOT MATCHED THEN INSERT (id, v) VALUES (src.id, src.v)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Merge(merge_stmt) = &result.list[0] else {
+        panic!("expected Merge");
+    };
+    assert!(matches!(merge_stmt.target, Table::Name(_)));
+    let Table::SubQuery(source) = &merge_stmt.source else {
+        panic!("expected subquery source");
+    };
+    assert_eq!(source.name, Some(Ident::new("src")));
+    assert!(matches!(merge_stmt.on, Expr::BinaryOp(_)));
+    assert_eq!(merge_stmt.clauses.len(), 3);
+
+    let delete = &merge_stmt.clauses[0];
+    assert_eq!(delete.kind, MergeMatch::Matched);
+    assert!(delete.condition.is_some());
+    assert_eq!(delete.action, MergeAction::Delete);
+
+    let MergeAction::Update { assignments } = &merge_stmt.clauses[1].action else {
+        panic!("expected UPDATE action");
+    };
+    assert_eq!(assignments.len(), 2);
+    assert_eq!(merge_stmt.clauses[1].condition, None);
+
+    let insert = &merge_stmt.clauses[2];
+    assert_eq!(insert.kind, MergeMatch::NotMatched);
+    let MergeAction::Insert { insert_value } = &insert.action else {
+        panic!("expected INSERT action");
+    };
+    let InsertValue::Values { columns, values } = insert_value.as_ref() else {
+        panic!("expected VALUES");
+    };
+    assert_eq!(columns.len(), 2);
+    assert_eq!(values.len(), 1);
+    assert_eq!(merge_stmt.span.source_text(sql), sql);
+}
+
+#[test]
+fn test_merge_statement_variants() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "MERGE INTO t USING s ON t.id = s.id \
+             WHEN NOT MATCHED AND s.v > 0 THEN INSERT DEFAULT VALUES \
+             WHEN MATCHED THEN DO NOTHING; SELECT 1",
+        )
+        .unwrap();
+    assert_eq!(result.list.len(), 2);
+    let StatementInner::Merge(merge_stmt) = &result.list[0] else {
+        panic!("expected Merge");
+    };
+    assert_eq!(
+        merge_stmt.clauses[0].action,
+        MergeAction::Insert {
+            insert_value: Box::new(InsertValue::DefaultValues)
+        }
+    );
+    assert_eq!(merge_stmt.clauses[1].action, MergeAction::DoNothing);
+
+    let placeholders = p
+        .parse("MERGE INTO t USING s ON t.id = ? WHEN MATCHED THEN UPDATE SET v = ?")
+        .unwrap()
+        .placeholders()
+        .len();
+    assert_eq!(placeholders, 2);
+
+    // 没有 WHEN 子句，或动作与匹配类型不符
+    assert!(p.parse("MERGE INTO t USING s ON t.id = s.id").is_err());
+    assert!(
+        p.parse("MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED THEN DELETE")
+            .is_err()
+    );
+    assert!(
+        p.parse("MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN INSERT VALUES (1)")
+            .is_err()
+    );
+    assert!(
+        p.parse("MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED THEN INSERT SELECT 1")
+            .is_err()
+    );
+    assert!(
+        p.parse("MERGE t USING s ON t.id = s.id WHEN MATCHED THEN DELETE")
+            .is_err()
+    );
+
+    // MERGE、MATCHED 不是保留字
+    assert!(p.parse("select * from merge").is_ok());
+    assert!(
+        p.parse("MERGE INTO merge USING matched ON merge.id = matched.id WHEN MATCHED THEN DELETE")
+            .is_ok()
+    );
+}
+
 // ============================================================================
 // CTE 验证
 // ============================================================================
@@ -433,7 +1010,10 @@ fn test_insert_partof_select() {
 #[test]
 fn test_cte_basic() {
     let p = Parser::new().unwrap();
-    assert!(p.parse("WITH cte AS (SELECT id FROM users) SELECT * FROM cte").is_ok());
+    assert!(
+        p.parse("WITH cte AS (SELECT id FROM users) SELECT * FROM cte")
+            .is_ok()
+    );
 }
 
 #[test]
@@ -452,9 +1032,18 @@ fn test_cte_recursive() {
 #[test]
 fn test_set_operations() {
     let p = Parser::new().unwrap();
-    assert!(p.parse("SELECT id FROM users UNION SELECT id FROM admins").is_ok());
-    assert!(p.parse("SELECT id FROM users INTERSECT SELECT id FROM admins").is_ok());
-    assert!(p.parse("SELECT id FROM users EXCEPT SELECT id FROM admins").is_ok());
+    assert!(
+        p.parse("SELECT id FROM users UNION SELECT id FROM admins")
+            .is_ok()
+    );
+    assert!(
+        p.parse("SELECT id FROM users INTERSECT SELECT id FROM admins")
+            .is_ok()
+    );
+    assert!(
+        p.parse("SELECT id FROM users EXCEPT SELECT id FROM admins")
+            .is_ok()
+    );
 }
 
 // ============================================================================
@@ -464,7 +1053,10 @@ fn test_set_operations() {
 #[test]
 fn test_window_functions() {
     let p = Parser::new().unwrap();
-    assert!(p.parse("SELECT ROW_NUMBER() OVER (ORDER BY id) FROM users").is_ok());
+    assert!(
+        p.parse("SELECT ROW_NUMBER() OVER (ORDER BY id) FROM users")
+            .is_ok()
+    );
     assert!(
         p.parse("SELECT RANK() OVER (PARTITION BY dept ORDER BY salary DESC) as r FROM emp")
             .is_ok(),
@@ -516,7 +1108,10 @@ fn test_error_handling() {
     let p = Parser::new().unwrap();
     assert!(p.parse("").is_err(), "empty input should fail");
     assert!(p.parse("   ").is_err(), "whitespace only should fail");
-    assert!(p.parse("SELECT 'hello").is_err(), "unterminated string should fail");
+    assert!(
+        p.parse("SELECT 'hello").is_err(),
+        "unterminated string should fail"
+    );
 }
 
 // ============================================================================
@@ -562,14 +1157,15 @@ fn test_function_calls() {
 #[test]
 fn test_distinct() {
     let p = Parser::new().unwrap();
-    let result = p.parse("SELECT DISTINCT category, region FROM sales").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Query(Query::Select(stmt)) => {
-                assert_eq!(stmt.distinct, true);
-            }
-            _ => panic!("expected Select"),
-        },
+    let result = p
+        .parse("SELECT DISTINCT category, region FROM sales")
+        .unwrap();
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Query(Query::Select(stmt)) => {
+            assert!(stmt.distinct);
+        }
+        _ => panic!("expected Select"),
     }
 }
 
@@ -587,7 +1183,10 @@ JOIN orders o ON u.id = o.user_id \
 WHERE u.active = 1 AND o.total > 0 \
 ORDER BY o.created_at DESC \
 LIMIT 20";
-    assert!(p.parse(sql).is_ok(), "complex real-world query should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "complex real-world query should parse"
+    );
 }
 
 // ============================================================================
@@ -599,7 +1198,1467 @@ fn test_operator_precedence_comprehensive() {
     let p = Parser::new().unwrap();
     // OR < AND < = <> < NOT/BETWEEN/IN/LIKE < < <= > >= < << >> & | ^ < + - < * / %
     // Test a few key precedences:
-    assert!(p.parse("SELECT * FROM t WHERE a = 1 OR b = 2 AND c = 3").is_ok());
+    assert!(
+        p.parse("SELECT * FROM t WHERE a = 1 OR b = 2 AND c = 3")
+            .is_ok()
+    );
     assert!(p.parse("SELECT * FROM t WHERE a + b * c > 10").is_ok());
     assert!(p.parse("SELECT a & b + c FROM t").is_ok());
 }
+
+// ============================================================================
+// Span 验证
+// ============================================================================
+
+#[test]
+fn test_span_covers_source_text() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a + 1 AS x, COUNT(*) FROM users u WHERE u.id = 3; DELETE FROM t";
+    let result = p.parse(sql).unwrap();
+    assert_eq!(result.list.len(), 2);
+    assert_eq!(
+        result.list[0].span().source_text(sql),
+        "SELECT a + 1 AS x, COUNT(*) FROM users u WHERE u.id = 3"
+    );
+    assert_eq!(result.list[1].span().source_text(sql), "DELETE FROM t");
+    match &result.list[0] {
+        StatementInner::Query(Query::Select(stmt)) => {
+            assert_eq!(stmt.columns[0].span().source_text(sql), "a + 1 AS x");
+            assert_eq!(stmt.columns[0].value.span().source_text(sql), "a + 1");
+            assert_eq!(stmt.columns[1].span().source_text(sql), "COUNT(*)");
+            let from = stmt.from.as_ref().unwrap();
+            assert_eq!(from[0].span().source_text(sql), "users u");
+            let where_statement = stmt.where_statement.as_ref().unwrap();
+            assert_eq!(where_statement.span(), Span::new(47, 55));
+        }
+        _ => panic!("expected Select"),
+    }
+}
+
+#[test]
+fn test_span_on_syntax_error() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a FROM t WHERE )";
+    match p.parse(sql) {
+        Err(simd_sql::ParserError::SyntaxError(span)) => {
+            assert_eq!(span, Span::new(22, 23));
+            assert_eq!(span.source_text(sql), ")");
+        }
+        other => panic!("expected SyntaxError, got {:?}", other),
+    }
+}
+
+// ============================================================================
+// 诊断信息验证
+// ============================================================================
+
+#[test]
+fn test_diagnostic_points_at_offending_token() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a,\n  b FROM t\nWHERE a = )";
+    let diagnostic = p.parse_with_diagnostics(sql).unwrap_err();
+    assert_eq!(diagnostic.line, 3);
+    assert_eq!(diagnostic.column, 11);
+    assert_eq!(diagnostic.span, Span::new(31, 32));
+    assert_eq!(diagnostic.source_line, "WHERE a = )");
+    assert_eq!(diagnostic.message, "unexpected `)`");
+    assert!(diagnostic.expected.contains(&TokenKind::Identifier));
+    assert_eq!(
+        diagnostic.to_string(),
+        "\
+error: unexpected `)`
+ --> 3:11
+  |
+3 | WHERE a = )
+  |           ^
+  = expected one of: identifier, number, string literal, placeholder, `(`, `*`, `-`, `+`, `~`, `NOT`, `CASE`, `EXISTS`, `NULL`, `TRUE`, `FALSE`"
+    );
+}
+
+#[test]
+fn test_diagnostic_non_ascii_source() {
+    let p = Parser::new().unwrap();
+    let diagnostic = p.parse_with_diagnostics("select é from t").unwrap_err();
+    assert_eq!(diagnostic.span, Span::new(7, 9));
+    assert_eq!(diagnostic.column, 8);
+    assert_eq!(diagnostic.message, "unexpected `é`");
+    assert!(
+        diagnostic
+            .to_string()
+            .contains("1 | select é from t\n  |        ^\n")
+    );
+
+    // 落在字符中间的范围扩到完整字符
+    let diagnostic = Diagnostic::from_error(
+        ParserError::SyntaxError(Span::new(9, 14)),
+        "select 'é', 中 from t",
+    );
+    assert_eq!(diagnostic.span, Span::new(8, 16));
+    assert_eq!(diagnostic.column, 9);
+    assert_eq!(diagnostic.message, "unexpected `é', 中`");
+}
+
+#[test]
+fn test_diagnostic_expected_from_expect_kind() {
+    let p = Parser::new().unwrap();
+    let diagnostic = p
+        .parse_with_diagnostics("SELECT a FROM t ORDER name")
+        .unwrap_err();
+    assert_eq!(diagnostic.column, 23);
+    assert_eq!(diagnostic.expected, vec![TokenKind::Keyword(Keyword::By)]);
+    assert!(
+        diagnostic
+            .to_string()
+            .ends_with("^^^^\n  = expected one of: `BY`")
+    );
+}
+
+#[test]
+fn test_diagnostic_at_end_of_input() {
+    let p = Parser::new().unwrap();
+    let diagnostic = p
+        .parse_with_diagnostics("SELECT (a + 1 FROM t")
+        .unwrap_err();
+    assert_eq!(diagnostic.message, "unexpected `FROM`");
+    assert!(diagnostic.expected.contains(&TokenKind::RightParen));
+
+    let diagnostic = p.parse_with_diagnostics("SELECT (a + 1").unwrap_err();
+    assert_eq!(diagnostic.message, "unexpected end of input");
+    assert_eq!(diagnostic.column, 14);
+    assert_eq!(diagnostic.expected, vec![TokenKind::RightParen]);
+}
+
+#[test]
+fn test_diagnostic_from_error() {
+    let p = Parser::new().unwrap();
+    let sql = "SELEC 1";
+    let error = p.parse(sql).unwrap_err();
+    let diagnostic = Diagnostic::from_error(error, sql);
+    assert_eq!((diagnostic.line, diagnostic.column), (1, 1));
+    assert_eq!(diagnostic.message, "unexpected `SELEC`");
+    assert!(diagnostic.expected.is_empty());
+}
+
+// ============================================================================
+// 错误恢复验证
+// ============================================================================
+
+#[test]
+fn test_recovering_collects_every_error() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a FROM t; SELECT FROM; DELETE FROM u; UPDATE SET; SELECT 1";
+    let recovered = p.parse_recovering(sql, false);
+    assert!(recovered.has_errors());
+    assert_eq!(recovered.statement.list.len(), 3);
+    assert_eq!(recovered.errors.len(), 2);
+    let spans: Vec<&str> = recovered
+        .errors
+        .iter()
+        .map(|err| err.span().unwrap().source_text(sql))
+        .collect();
+    assert_eq!(spans, vec![";", "SET"]);
+    assert_eq!(
+        recovered.statement.list[1].span().source_text(sql),
+        "DELETE FROM u"
+    );
+    assert_eq!(
+        recovered.statement.list[2].span().source_text(sql),
+        "SELECT 1"
+    );
+}
+
+#[test]
+fn test_recovering_with_placeholders() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT 1; SELECT a +; SELECT 2";
+    let recovered = p.parse_recovering(sql, true);
+    assert_eq!(recovered.errors.len(), 1);
+    assert_eq!(recovered.statement.list.len(), 3);
+    assert_eq!(
+        recovered.statement.list[1],
+        StatementInner::Error(Span::new(10, 20))
+    );
+    assert_eq!(
+        recovered.statement.list[1].span().source_text(sql),
+        "SELECT a +"
+    );
+}
+
+#[test]
+fn test_recovering_lexer_errors() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT 1e5e3; SELECT b FROM t; SELECT !c";
+    let recovered = p.parse_recovering(sql, false);
+    assert_eq!(recovered.statement.list.len(), 1);
+    assert_eq!(
+        recovered.errors,
+        vec![
+            ParserError::InvalidToken {
+                kind: LexErrorKind::RepeatedExponent,
+                span: Span::new(10, 11),
+            },
+            ParserError::InvalidToken {
+                kind: LexErrorKind::StrayBang,
+                span: Span::new(38, 39),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_recovering_matches_parse_on_valid_input() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a FROM t WHERE b = 1; DELETE FROM t";
+    let recovered = p.parse_recovering(sql, true);
+    assert!(!recovered.has_errors());
+    assert_eq!(recovered.statement, p.parse(sql).unwrap());
+
+    let recovered = p.parse_recovering("  ;  ", false);
+    assert!(recovered.statement.list.is_empty());
+    assert_eq!(recovered.errors.len(), 1);
+}
+
+// ============================================================================
+// 带引号的标识符验证
+// ============================================================================
+
+#[test]
+fn test_quoted_identifiers_in_select() {
+    let p = Parser::new().unwrap();
+    let sql = r#"SELECT "Order Details"."Unit Price" AS "Price", `select`, [my col] FROM "Order Details""#;
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    assert_eq!(
+        stmt.columns[0],
+        Alias {
+            name: Some(Ident::quoted("Price", QuoteStyle::Double)),
+            value: Expr::Field(Field {
+                prefix: Some(Ident::quoted("Order Details", QuoteStyle::Double)),
+                name: Ident::quoted("Unit Price", QuoteStyle::Double),
+                span: Span::new(7, 35),
+            }),
+            span: Span::new(7, 46),
+        }
+    );
+    assert_eq!(
+        stmt.columns[1].value,
+        Expr::Field(Field {
+            prefix: None,
+            name: Ident::quoted("select", QuoteStyle::Backtick),
+            span: Span::new(48, 56),
+        })
+    );
+    assert_eq!(
+        stmt.columns[2].value,
+        Expr::Field(Field {
+            prefix: None,
+            name: Ident::quoted("my col", QuoteStyle::Bracket),
+            span: Span::new(58, 66),
+        })
+    );
+    let from = stmt.from.as_ref().unwrap();
+    let From::Table(Table::Name(table)) = &from[0] else {
+        panic!("expected table name");
+    };
+    assert_eq!(
+        table.value,
+        Expr::Field(Field {
+            prefix: None,
+            name: Ident::quoted("Order Details", QuoteStyle::Double),
+            span: Span::new(72, 87),
+        })
+    );
+}
+
+#[test]
+fn test_quoted_identifier_escapes_and_case() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(r#"SELECT "say ""hi""", Name, "Name" FROM t"#)
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let names: Vec<Ident> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::Field(field) => field.name,
+            other => panic!("expected field, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(names[0].value, r#"say ""hi"""#);
+    assert_eq!(names[0].unescaped(), r#"say "hi""#);
+    assert!(!names[1].is_quoted());
+    assert!(names[2].is_quoted());
+    assert_eq!(names[2].to_string(), r#""Name""#);
+}
+
+#[test]
+fn test_quoted_cte_name() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(r#"WITH "Recent Orders" ("Id") AS (SELECT id FROM orders) SELECT "Id" FROM "Recent Orders""#)
+        .unwrap();
+    let StatementInner::Query(Query::Cte { ctes, .. }) = &result.list[0] else {
+        panic!("expected Cte");
+    };
+    assert_eq!(
+        ctes[0].name,
+        Ident::quoted("Recent Orders", QuoteStyle::Double)
+    );
+    assert_eq!(
+        ctes[0].columns.as_ref().unwrap()[0],
+        Ident::quoted("Id", QuoteStyle::Double)
+    );
+}
+
+// ============================================================================
+// 字符串字面量前缀验证
+// ============================================================================
+
+#[test]
+fn test_prefixed_string_literals() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT 'it''s', E'a\\tb', N'名字', X'DEADBEEF', B'1010', _utf8mb4'hi' FROM t";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let values: Vec<&Expr> = stmt.columns.iter().map(|column| &column.value).collect();
+    assert_eq!(
+        values[0],
+        &Expr::StringLiteral(StringLiteral {
+            value: "'it''s'",
+            span: Span::new(7, 14)
+        })
+    );
+    assert_eq!(
+        values[1],
+        &Expr::EscapedStringLiteral(StringLiteral {
+            value: "'a\\tb'",
+            span: Span::new(16, 23)
+        })
+    );
+    assert!(matches!(
+        values[2],
+        Expr::NationalStringLiteral(StringLiteral {
+            value: "'名字'",
+            ..
+        })
+    ));
+    assert_eq!(
+        values[3],
+        &Expr::HexStringLiteral(StringLiteral {
+            value: "'DEADBEEF'",
+            span: Span::new(36, 47)
+        })
+    );
+    assert!(matches!(
+        values[4],
+        Expr::BitStringLiteral(StringLiteral {
+            value: "'1010'",
+            ..
+        })
+    ));
+    assert_eq!(
+        values[5],
+        &Expr::IntroducedStringLiteral(IntroducedStringLiteral {
+            charset: "utf8mb4",
+            value: "'hi'",
+            span: Span::new(58, 70),
+        })
+    );
+    assert_eq!(values[5].span().source_text(sql), "_utf8mb4'hi'");
+}
+
+#[test]
+fn test_dollar_quoted_string_literals() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT $$it's a 'body'$$, $fn$ BEGIN RETURN 1; END $fn$ FROM t";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    assert_eq!(
+        stmt.columns[0].value,
+        Expr::DollarQuotedStringLiteral(DollarQuotedStringLiteral {
+            tag: None,
+            value: "it's a 'body'",
+            span: Span::new(7, 24),
+        })
+    );
+    assert_eq!(
+        stmt.columns[1].value,
+        Expr::DollarQuotedStringLiteral(DollarQuotedStringLiteral {
+            tag: Some("fn"),
+            value: " BEGIN RETURN 1; END ",
+            span: Span::new(26, 55),
+        })
+    );
+}
+
+// ============================================================================
+// 绑定参数验证
+// ============================================================================
+
+#[test]
+fn test_placeholders_in_source_order() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a FROM t WHERE id = $2 AND name = :name OR x IN (?, @p) ORDER BY ?";
+    let result = p.parse(sql).unwrap();
+    assert_eq!(
+        result.placeholders(),
+        vec![
+            &Placeholder {
+                style: PlaceholderStyle::Numbered(2),
+                span: Span::new(27, 29)
+            },
+            &Placeholder {
+                style: PlaceholderStyle::Named("name"),
+                span: Span::new(41, 46)
+            },
+            &Placeholder {
+                style: PlaceholderStyle::Positional,
+                span: Span::new(56, 57)
+            },
+            &Placeholder {
+                style: PlaceholderStyle::Named("p"),
+                span: Span::new(59, 61)
+            },
+            &Placeholder {
+                style: PlaceholderStyle::Positional,
+                span: Span::new(72, 73)
+            },
+        ]
+    );
+
+    let sql = "INSERT INTO t (a, b) VALUES (?, ?); UPDATE t SET a = ? WHERE b = ?";
+    let result = p.parse(sql).unwrap();
+    let starts: Vec<usize> = result.placeholders().iter().map(|p| p.span.start).collect();
+    assert_eq!(starts, vec![29, 32, 53, 65]);
+
+    assert!(
+        p.parse("SELECT a FROM t")
+            .unwrap()
+            .placeholders()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_placeholder_errors() {
+    let p = Parser::new().unwrap();
+    // `:` 与 `@` 后面必须紧跟名字
+    assert!(p.parse("SELECT a FROM t WHERE id = :").is_err());
+    assert!(p.parse("SELECT a FROM t WHERE id = @ x").is_err());
+    assert!(p.parse("SELECT $99999999999999999999999").is_err());
+}
+
+// ============================================================================
+// 一元运算符验证
+// ============================================================================
+
+fn first_column<'a>(statement: &'a Statement<'a>) -> &'a Expr<'a> {
+    let StatementInner::Query(Query::Select(stmt)) = &statement.list[0] else {
+        panic!("expected Select");
+    };
+    &stmt.columns[0].value
+}
+
+fn where_clause<'a>(statement: &'a Statement<'a>) -> &'a Expr<'a> {
+    let StatementInner::Query(Query::Select(stmt)) = &statement.list[0] else {
+        panic!("expected Select");
+    };
+    stmt.where_statement.as_ref().expect("expected WHERE")
+}
+
+#[test]
+fn test_minus_is_not_folded_into_number() {
+    let p = Parser::new().unwrap();
+    let result = p.parse("SELECT a-1").unwrap();
+    assert_eq!(
+        first_column(&result),
+        &Expr::BinaryOp(Box::new(BinaryOp {
+            op: BinaryOperator::Subtract,
+            left: Expr::Field(Field {
+                prefix: None,
+                name: Ident::new("a"),
+                span: Span::new(7, 8),
+            }),
+            right: Expr::NumericLiteral(NumericLiteral {
+                value: "1",
+                span: Span::new(9, 10),
+            }),
+        }))
+    );
+
+    let result = p.parse("SELECT -1").unwrap();
+    assert_eq!(
+        first_column(&result),
+        &Expr::UnaryOp(Box::new(UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: Expr::NumericLiteral(NumericLiteral {
+                value: "1",
+                span: Span::new(8, 9),
+            }),
+            span: Span::new(7, 9),
+        }))
+    );
+}
+
+#[test]
+fn test_unary_precedence() {
+    let p = Parser::new().unwrap();
+
+    // -a * b 是 (-a) * b
+    let result = p.parse("SELECT -a * b").unwrap();
+    let Expr::BinaryOp(mul) = first_column(&result) else {
+        panic!("expected BinaryOp");
+    };
+    assert_eq!(mul.op, BinaryOperator::Multiply);
+    assert!(matches!(&mul.left, Expr::UnaryOp(neg) if neg.op == UnaryOperator::Minus));
+
+    // -(a + b) 的操作数是括号里的整个表达式
+    let result = p.parse("SELECT -(a + b)").unwrap();
+    let Expr::UnaryOp(neg) = first_column(&result) else {
+        panic!("expected UnaryOp");
+    };
+    assert!(matches!(&neg.expr, Expr::BinaryOp(add) if add.op == BinaryOperator::Add));
+    assert_eq!(neg.span, Span::new(7, 15));
+
+    // NOT a = 1 AND b 是 (NOT (a = 1)) AND b
+    let result = p.parse("SELECT * FROM t WHERE NOT a = 1 AND b").unwrap();
+    let Expr::BinaryOp(and) = where_clause(&result) else {
+        panic!("expected BinaryOp");
+    };
+    assert_eq!(and.op, BinaryOperator::And);
+    let Expr::UnaryOp(not) = &and.left else {
+        panic!("expected UnaryOp");
+    };
+    assert_eq!(not.op, UnaryOperator::Not);
+    assert!(matches!(&not.expr, Expr::BinaryOp(eq) if eq.op == BinaryOperator::Equal));
+
+    // -a IS NULL 是 (-a) IS NULL
+    let result = p.parse("SELECT * FROM t WHERE -a IS NULL").unwrap();
+    let Expr::IsNull(is_null) = where_clause(&result) else {
+        panic!("expected IsNull");
+    };
+    assert!(matches!(is_null.field.as_ref(), Expr::UnaryOp(_)));
+}
+
+#[test]
+fn test_unary_operands() {
+    let p = Parser::new().unwrap();
+
+    let result = p.parse("SELECT ~a, +1, NOT NOT b").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let ops: Vec<&UnaryOperator> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::UnaryOp(unary) => &unary.op,
+            other => panic!("expected UnaryOp, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        ops,
+        vec![
+            &UnaryOperator::BitNot,
+            &UnaryOperator::Plus,
+            &UnaryOperator::Not
+        ]
+    );
+
+    let result = p
+        .parse("SELECT * FROM t WHERE a BETWEEN -1 AND b + 1")
+        .unwrap();
+    let Expr::Between(between) = where_clause(&result) else {
+        panic!("expected Between");
+    };
+    assert!(matches!(between.lower.as_ref(), Expr::UnaryOp(_)));
+    assert!(matches!(between.upper.as_ref(), Expr::BinaryOp(_)));
+
+    let result = p.parse("SELECT * FROM t WHERE a IN (-1, 2 * 3)").unwrap();
+    assert!(matches!(where_clause(&result), Expr::In(_)));
+
+    let result = p
+        .parse("SELECT * FROM t WHERE NOT EXISTS (SELECT 1)")
+        .unwrap();
+    assert!(matches!(where_clause(&result), Expr::Exists(exists) if exists.is_not));
+
+    assert!(p.parse("SELECT -").is_err());
+    assert!(p.parse("SELECT * FROM t WHERE NOT").is_err());
+}
+
+// ============================================================================
+// PostgreSQL/MySQL 运算符验证
+// ============================================================================
+
+fn binary_op<'a>(expr: &'a Expr<'a>) -> &'a BinaryOp<'a> {
+    match expr {
+        Expr::BinaryOp(binary_op) => binary_op,
+        other => panic!("expected BinaryOp, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_extended_binary_operators() {
+    let p = Parser::new().unwrap();
+    let cases = [
+        ("a || b", BinaryOperator::StringConcat),
+        ("a | b", BinaryOperator::BitOr),
+        ("a -> 'k'", BinaryOperator::JsonGet),
+        ("a ->> 'k'", BinaryOperator::JsonGetText),
+        ("a #> '{k}'", BinaryOperator::JsonGetPath),
+        ("a #>> '{k}'", BinaryOperator::JsonGetPathText),
+        ("a @> b", BinaryOperator::Contains),
+        ("a <@ b", BinaryOperator::ContainedBy),
+        ("a <=> b", BinaryOperator::NullSafeEqual),
+        ("a ~ 'x'", BinaryOperator::RegexMatch),
+        ("a ~* 'x'", BinaryOperator::RegexIMatch),
+        ("a !~ 'x'", BinaryOperator::RegexNotMatch),
+        ("a !~* 'x'", BinaryOperator::RegexNotIMatch),
+        ("a IS DISTINCT FROM b", BinaryOperator::IsDistinctFrom),
+        (
+            "a IS NOT DISTINCT FROM b",
+            BinaryOperator::IsNotDistinctFrom,
+        ),
+    ];
+    for (expr, op) in cases {
+        let sql = format!("SELECT {expr}");
+        let result = p.parse(&sql).unwrap();
+        let column = first_column(&result);
+        assert_eq!(binary_op(column).op, op, "{expr}");
+        assert_eq!(column.span(), Span::new(7, sql.len()), "{expr}");
+    }
+}
+
+#[test]
+fn test_extended_operator_precedence() {
+    let p = Parser::new().unwrap();
+
+    // `|` 是按位或，不再是逻辑 OR：a = b | c 即 a = (b | c)
+    let result = p.parse("SELECT a = b | c").unwrap();
+    let eq = binary_op(first_column(&result));
+    assert_eq!(eq.op, BinaryOperator::Equal);
+    assert_eq!(binary_op(&eq.right).op, BinaryOperator::BitOr);
+
+    // data->'a'->>'b' = 'x' 即 ((data->'a')->>'b') = 'x'
+    let result = p.parse("SELECT data->'a'->>'b' = 'x'").unwrap();
+    let eq = binary_op(first_column(&result));
+    assert_eq!(eq.op, BinaryOperator::Equal);
+    let get_text = binary_op(&eq.left);
+    assert_eq!(get_text.op, BinaryOperator::JsonGetText);
+    assert_eq!(binary_op(&get_text.left).op, BinaryOperator::JsonGet);
+
+    // a || b ~ c AND d 即 ((a || b) ~ c) AND d
+    let result = p.parse("SELECT a || b ~ c AND d").unwrap();
+    let and = binary_op(first_column(&result));
+    assert_eq!(and.op, BinaryOperator::And);
+    let regex = binary_op(&and.left);
+    assert_eq!(regex.op, BinaryOperator::RegexMatch);
+    assert_eq!(binary_op(&regex.left).op, BinaryOperator::StringConcat);
+
+    // -a::int 即 -(a::int)，a::int + 1 即 (a::int) + 1
+    let result = p.parse("SELECT -a::int, a::int + 1").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let Expr::UnaryOp(neg) = &stmt.columns[0].value else {
+        panic!("expected UnaryOp");
+    };
+    assert!(matches!(&neg.expr, Expr::Cast(_)));
+    let add = binary_op(&stmt.columns[1].value);
+    assert_eq!(add.op, BinaryOperator::Add);
+    assert!(matches!(&add.left, Expr::Cast(_)));
+
+    // IS DISTINCT FROM 的右侧不吞掉 AND
+    let result = p
+        .parse("SELECT * FROM t WHERE a IS DISTINCT FROM b + 1 AND c")
+        .unwrap();
+    let and = binary_op(where_clause(&result));
+    assert_eq!(and.op, BinaryOperator::And);
+    let distinct = binary_op(&and.left);
+    assert_eq!(distinct.op, BinaryOperator::IsDistinctFrom);
+    assert_eq!(binary_op(&distinct.right).op, BinaryOperator::Add);
+
+    assert!(p.parse("SELECT a IS DISTINCT b").is_err());
+}
+
+// ============================================================================
+// CAST 与带类型字面量验证
+// ============================================================================
+
+#[test]
+fn test_cast_expressions() {
+    let p = Parser::new().unwrap();
+    let result = p.parse("SELECT CAST(amount AS DECIMAL(12,2))").unwrap();
+    assert_eq!(
+        first_column(&result),
+        &Expr::Cast(Box::new(Cast {
+            kind: CastKind::Cast,
+            expr: Expr::Field(Field {
+                prefix: None,
+                name: Ident::new("amount"),
+                span: Span::new(12, 18),
+            }),
+            data_type: DataType {
+                kind: DataTypeKind::Decimal {
+                    precision: Some(12),
+                    scale: Some(2),
+                },
+                span: Span::new(22, 35),
+            },
+            span: Span::new(7, 36),
+        }))
+    );
+
+    let result = p
+        .parse("SELECT try_cast(x AS int), a::varchar(10)")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let Expr::Cast(try_cast) = &stmt.columns[0].value else {
+        panic!("expected Cast");
+    };
+    assert_eq!(try_cast.kind, CastKind::TryCast);
+    assert_eq!(
+        try_cast.data_type.kind,
+        DataTypeKind::Int { unsigned: false }
+    );
+    let Expr::Cast(shorthand) = &stmt.columns[1].value else {
+        panic!("expected Cast");
+    };
+    assert_eq!(shorthand.kind, CastKind::DoubleColon);
+    assert_eq!(
+        shorthand.data_type.kind,
+        DataTypeKind::Varchar { length: Some(10) }
+    );
+    assert_eq!(shorthand.span, Span::new(27, 41));
+
+    // CAST 可以出现在 IN 列表和 WHERE 里
+    let result = p
+        .parse("SELECT * FROM t WHERE CAST(a AS int) IN (CAST(b AS int), NULL)")
+        .unwrap();
+    assert!(matches!(where_clause(&result), Expr::In(_)));
+
+    let result = p
+        .parse("SELECT a::int[], CAST(b AS UNSIGNED), CAST(c AS CHARACTER VARYING(20))")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let kinds: Vec<&DataTypeKind> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::Cast(cast) => &cast.data_type.kind,
+            _ => panic!("expected Cast"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &DataTypeKind::Array {
+                element: Box::new(DataTypeKind::Int { unsigned: false }),
+                size: None,
+            },
+            &DataTypeKind::Custom {
+                name: "UNSIGNED",
+                args: vec![],
+            },
+            &DataTypeKind::Varchar { length: Some(20) },
+        ]
+    );
+
+    assert!(p.parse("SELECT CAST(a int)").is_err());
+    assert!(p.parse("SELECT CAST(a AS)").is_err());
+    assert!(p.parse("SELECT a::").is_err());
+
+    // 数组后缀由 `[`、`]` token 组成，里面可以有空白
+    let result = p.parse("SELECT CAST(a AS INT[ 3 ])").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let Expr::Cast(cast) = &stmt.columns[0].value else {
+        panic!("expected Cast");
+    };
+    assert_eq!(
+        cast.data_type.kind,
+        DataTypeKind::Array {
+            element: Box::new(DataTypeKind::Int { unsigned: false }),
+            size: Some(3),
+        }
+    );
+    assert!(p.parse("SELECT CAST(a AS INT[x])").is_err());
+    // 下标不能被当作 `[1]` 别名吞掉
+    assert!(p.parse("SELECT a[1] FROM t").is_err());
+
+    // 不紧跟在名字后面的 `[` 仍是方括号标识符
+    let result = p.parse("SELECT [a] [b], c [d] FROM [t]").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    assert_eq!(
+        stmt.columns[0].name,
+        Some(Ident::quoted("b", QuoteStyle::Bracket))
+    );
+    assert_eq!(
+        stmt.columns[1].name,
+        Some(Ident::quoted("d", QuoteStyle::Bracket))
+    );
+}
+
+#[test]
+fn test_typed_literals() {
+    let p = Parser::new().unwrap();
+    let result = p.parse("SELECT DATE '2024-01-01'").unwrap();
+    assert_eq!(
+        first_column(&result),
+        &Expr::TypedLiteral(Box::new(TypedLiteral {
+            data_type: DataType {
+                kind: DataTypeKind::Date,
+                span: Span::new(7, 11)
+            },
+            value: "'2024-01-01'",
+            qualifier: None,
+            span: Span::new(7, 24),
+        }))
+    );
+
+    let result = p.parse("SELECT INTERVAL '7' DAY, interval '1-2' YEAR TO MONTH, TIMESTAMP '2024-01-01 00:00:00'").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let qualifiers: Vec<&Option<IntervalQualifier>> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::TypedLiteral(literal) => &literal.qualifier,
+            other => panic!("expected TypedLiteral, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        qualifiers,
+        vec![
+            &Some(IntervalQualifier {
+                leading: DateTimeField::Day,
+                trailing: None
+            }),
+            &Some(IntervalQualifier {
+                leading: DateTimeField::Year,
+                trailing: Some(DateTimeField::Month),
+            }),
+            &None,
+        ]
+    );
+    assert!(stmt.columns.iter().all(|column| column.name.is_none()));
+
+    // 其他标识符后面跟字符串不是带类型字面量，date 仍可作为列名
+    let result = p
+        .parse("SELECT date FROM t WHERE date > DATE '2024-01-01'")
+        .unwrap();
+    assert!(matches!(first_column(&result), Expr::Field(_)));
+    assert!(p.parse("SELECT INTERVAL '1' YEAR TO").is_err());
+}
+
+// ============================================================================
+// 关键字参数函数验证
+// ============================================================================
+
+fn field(name: &str, start: usize) -> Expr<'_> {
+    Expr::Field(Field {
+        prefix: None,
+        name: Ident::new(name),
+        span: Span::new(start, start + name.len()),
+    })
+}
+
+#[test]
+fn test_extract_and_position() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse("SELECT EXTRACT(YEAR FROM ts), POSITION('a' IN s)")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    assert_eq!(
+        stmt.columns[0].value,
+        Expr::Extract(Box::new(Extract {
+            field: DateTimeField::Year,
+            expr: field("ts", 25),
+            span: Span::new(7, 28),
+        }))
+    );
+    assert_eq!(
+        stmt.columns[1].value,
+        Expr::Position(Box::new(Position {
+            substring: Expr::StringLiteral(StringLiteral {
+                value: "'a'",
+                span: Span::new(39, 42),
+            }),
+            expr: field("s", 46),
+            span: Span::new(30, 48),
+        }))
+    );
+
+    assert!(p.parse("SELECT EXTRACT(CENTURIES FROM ts)").is_err());
+    assert!(p.parse("SELECT EXTRACT(YEAR ts)").is_err());
+}
+
+#[test]
+fn test_substring_trim_overlay() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT SUBSTRING(s FROM 2 FOR 3), substring(s FOR 3), TRIM(LEADING '0' FROM x), \
+               TRIM(BOTH FROM y), TRIM(z), OVERLAY(s PLACING 'x' FROM 2 FOR 3)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let columns: Vec<&Expr> = stmt.columns.iter().map(|column| &column.value).collect();
+
+    let Expr::Substring(substring) = columns[0] else {
+        panic!("expected Substring");
+    };
+    assert!(substring.start.is_some() && substring.length.is_some());
+    let Expr::Substring(substring) = columns[1] else {
+        panic!("expected Substring");
+    };
+    assert!(substring.start.is_none() && substring.length.is_some());
+
+    let Expr::Trim(trim) = columns[2] else {
+        panic!("expected Trim");
+    };
+    assert_eq!(trim.side, Some(TrimSide::Leading));
+    assert!(matches!(trim.characters, Some(Expr::StringLiteral(_))));
+    let Expr::Trim(trim) = columns[3] else {
+        panic!("expected Trim");
+    };
+    assert_eq!(
+        (&trim.side, &trim.characters),
+        (&Some(TrimSide::Both), &None)
+    );
+    let Expr::Trim(trim) = columns[4] else {
+        panic!("expected Trim");
+    };
+    assert_eq!((&trim.side, &trim.characters), (&None, &None));
+
+    let Expr::Overlay(overlay) = columns[5] else {
+        panic!("expected Overlay");
+    };
+    assert!(matches!(overlay.replacement, Expr::StringLiteral(_)));
+    assert!(overlay.length.is_some());
+    assert_eq!(
+        overlay.span.source_text(sql),
+        "OVERLAY(s PLACING 'x' FROM 2 FOR 3)"
+    );
+
+    // 后面没有操作数时 LEADING/TRAILING 是列名
+    let sql = "SELECT TRIM(leading), TRIM(trailing || 'x')";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let trims: Vec<(&Option<TrimSide>, &str)> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::Trim(trim) => (&trim.side, trim.expr.span().source_text(sql)),
+            other => panic!("expected Trim, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(trims, vec![(&None, "leading"), (&None, "trailing || 'x'")]);
+}
+
+#[test]
+fn test_special_functions_with_comma_arguments() {
+    let p = Parser::new().unwrap();
+    // 逗号形式仍然是普通函数调用
+    let result = p
+        .parse("SELECT substring(s, 1, 2), trim(s, 'x'), position(a, b)")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let args: Vec<usize> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::FunctionCall(call) => call.args.len(),
+            other => panic!("expected FunctionCall, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(args, vec![3, 2, 2]);
+
+    assert!(p.parse("SELECT OVERLAY(s PLACING 'x')").is_err());
+    assert!(p.parse("SELECT SUBSTRING(s FROM 2 FOR)").is_err());
+}
+
+// ============================================================================
+// 标量子查询与 ANY/ALL/SOME 验证
+// ============================================================================
+
+#[test]
+fn test_scalar_subquery() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT a FROM t WHERE x = (SELECT max(y) FROM t)";
+    let result = p.parse(sql).unwrap();
+    let comparison = binary_op(where_clause(&result));
+    assert_eq!(comparison.op, BinaryOperator::Equal);
+    let Expr::Subquery(subquery) = &comparison.right else {
+        panic!("expected Subquery, got {:?}", comparison.right);
+    };
+    assert!(matches!(subquery.query, Query::Select(_)));
+    assert_eq!(subquery.span.source_text(sql), "(SELECT max(y) FROM t)");
+
+    // 子查询内部可以是集合运算或 CTE
+    let result = p.parse("SELECT (SELECT 1 UNION SELECT 2)").unwrap();
+    let Expr::Subquery(subquery) = first_column(&result) else {
+        panic!("expected Subquery");
+    };
+    assert!(matches!(subquery.query, Query::SetOperation { .. }));
+    let result = p
+        .parse("SELECT (WITH c AS (SELECT 1) SELECT * FROM c)")
+        .unwrap();
+    let Expr::Subquery(subquery) = first_column(&result) else {
+        panic!("expected Subquery");
+    };
+    assert!(matches!(subquery.query, Query::Cte { .. }));
+}
+
+#[test]
+fn test_subquery_inside_parenthesized_expression() {
+    let p = Parser::new().unwrap();
+    let result = p.parse("SELECT ((SELECT 1) + 1), ((SELECT 2))").unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let sum = binary_op(&stmt.columns[0].value);
+    assert_eq!(sum.op, BinaryOperator::Add);
+    assert!(matches!(sum.left, Expr::Subquery(_)));
+    assert!(matches!(stmt.columns[1].value, Expr::Subquery(_)));
+
+    assert!(p.parse("SELECT (SELECT a FROM t WHERE)").is_err());
+}
+
+#[test]
+fn test_quantified_comparison() {
+    let p = Parser::new().unwrap();
+    let cases = [
+        (
+            "x > ALL (SELECT y FROM u)",
+            BinaryOperator::Greater,
+            Quantifier::All,
+        ),
+        (
+            "x = ANY(SELECT y FROM u)",
+            BinaryOperator::Equal,
+            Quantifier::Any,
+        ),
+        (
+            "x <> SOME (SELECT y FROM u)",
+            BinaryOperator::NotEqual,
+            Quantifier::Some,
+        ),
+    ];
+    for (condition, op, quantifier) in cases {
+        let sql = format!("SELECT * FROM t WHERE {condition}");
+        let result = p.parse(&sql).unwrap();
+        let Expr::Quantified(quantified) = where_clause(&result) else {
+            panic!("expected Quantified for {condition}");
+        };
+        assert_eq!((&quantified.op, &quantified.quantifier), (&op, &quantifier));
+        assert_eq!(quantified.span.source_text(&sql), condition);
+    }
+}
+
+#[test]
+fn test_quantified_comparison_precedence() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT * FROM t WHERE a + b > ALL (SELECT y FROM u UNION SELECT z FROM v) AND c";
+    let result = p.parse(sql).unwrap();
+    let and = binary_op(where_clause(&result));
+    assert_eq!(and.op, BinaryOperator::And);
+    let Expr::Quantified(quantified) = &and.left else {
+        panic!("expected Quantified, got {:?}", and.left);
+    };
+    assert_eq!(binary_op(&quantified.left).op, BinaryOperator::Add);
+    assert!(matches!(quantified.subquery, Query::SetOperation { .. }));
+
+    assert!(p.parse("SELECT a + ALL (SELECT 1)").is_err());
+    assert!(p.parse("SELECT a = ANY (1, 2)").is_err());
+    // 不跟比较运算符时 ANY 只是普通名字
+    assert!(
+        p.parse("SELECT a + any(b), some FROM t WHERE any = some")
+            .is_ok()
+    );
+}
+
+// ============================================================================
+// 窗口帧与 WINDOW 子句验证
+// ============================================================================
+
+fn window_of<'a>(expr: &'a Expr<'a>) -> &'a WindowRef<'a> {
+    match expr {
+        Expr::WindowFunction(window_function) => &window_function.window,
+        other => panic!("expected WindowFunction, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_window_frame_between() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT avg(v) OVER (PARTITION BY date_trunc('day', ts), a + 1 ORDER BY ts \
+               ROWS BETWEEN 6 PRECEDING AND CURRENT ROW EXCLUDE NO OTHERS) FROM t";
+    let result = p.parse(sql).unwrap();
+    let WindowRef::Spec(spec) = window_of(first_column(&result)) else {
+        panic!("expected window spec");
+    };
+    let partition_by = spec.partition_by.as_ref().unwrap();
+    assert!(matches!(partition_by[0], Expr::FunctionCall(_)));
+    assert!(matches!(partition_by[1], Expr::BinaryOp(_)));
+
+    let frame = spec.frame.as_ref().unwrap();
+    assert_eq!(frame.units, WindowFrameUnits::Rows);
+    assert!(
+        matches!(&frame.start, WindowFrameBound::Preceding(Expr::NumericLiteral(n)) if n.value == "6")
+    );
+    assert_eq!(frame.end, Some(WindowFrameBound::CurrentRow));
+    assert_eq!(frame.exclusion, Some(WindowFrameExclusion::NoOthers));
+    assert_eq!(
+        frame.span.source_text(sql),
+        "ROWS BETWEEN 6 PRECEDING AND CURRENT ROW EXCLUDE NO OTHERS"
+    );
+}
+
+#[test]
+fn test_window_frame_forms() {
+    let p = Parser::new().unwrap();
+    let cases = [
+        (
+            "SUM(v) OVER (ORDER BY ts ROWS UNBOUNDED PRECEDING)",
+            WindowFrameUnits::Rows,
+            WindowFrameBound::UnboundedPreceding,
+            None,
+            None,
+        ),
+        (
+            "SUM(v) OVER (ORDER BY ts RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING EXCLUDE TIES)",
+            WindowFrameUnits::Range,
+            WindowFrameBound::CurrentRow,
+            Some(WindowFrameBound::UnboundedFollowing),
+            Some(WindowFrameExclusion::Ties),
+        ),
+        (
+            "SUM(v) OVER (ORDER BY ts GROUPS CURRENT ROW EXCLUDE CURRENT ROW)",
+            WindowFrameUnits::Groups,
+            WindowFrameBound::CurrentRow,
+            None,
+            Some(WindowFrameExclusion::CurrentRow),
+        ),
+        (
+            "SUM(v) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING EXCLUDE GROUP)",
+            WindowFrameUnits::Rows,
+            WindowFrameBound::UnboundedPreceding,
+            Some(WindowFrameBound::UnboundedFollowing),
+            Some(WindowFrameExclusion::Group),
+        ),
+    ];
+    for (column, units, start, end, exclusion) in cases {
+        let sql = format!("SELECT {column} FROM t");
+        let result = p.parse(&sql).unwrap();
+        let WindowRef::Spec(spec) = window_of(first_column(&result)) else {
+            panic!("expected window spec for {column}");
+        };
+        let frame = spec.frame.as_ref().unwrap();
+        assert_eq!(
+            (&frame.units, &frame.start, &frame.end, &frame.exclusion),
+            (&units, &start, &end, &exclusion),
+            "{column}"
+        );
+    }
+
+    assert!(p.parse("SELECT SUM(v) OVER (ROWS 1) FROM t").is_err());
+    assert!(
+        p.parse("SELECT SUM(v) OVER (ROWS BETWEEN 1 PRECEDING) FROM t")
+            .is_err()
+    );
+    assert!(
+        p.parse("SELECT SUM(v) OVER (ROWS CURRENT ROW EXCLUDE OTHERS) FROM t")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_named_window_clause() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT sum(v) OVER w, avg(v) OVER (w ROWS 2 PRECEDING) FROM t \
+               WINDOW w AS (PARTITION BY a ORDER BY ts), w2 AS (w) ORDER BY a";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+
+    let WindowRef::Named { name, span } = window_of(&stmt.columns[0].value) else {
+        panic!("expected named window");
+    };
+    assert_eq!(*name, "w");
+    assert_eq!(span.source_text(sql), "w");
+    assert_eq!(
+        stmt.columns[0].value.span().source_text(sql),
+        "sum(v) OVER w"
+    );
+
+    let WindowRef::Spec(spec) = window_of(&stmt.columns[1].value) else {
+        panic!("expected window spec");
+    };
+    assert_eq!(spec.base, Some(Ident::new("w")));
+    assert!(spec.frame.is_some());
+
+    let windows = stmt.window.as_ref().expect("expected WINDOW clause");
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[0].name, "w");
+    assert!(windows[0].spec.partition_by.is_some() && windows[0].spec.order_by.is_some());
+    assert_eq!(
+        windows[0].span.source_text(sql),
+        "w AS (PARTITION BY a ORDER BY ts)"
+    );
+    assert_eq!(windows[1].spec.base, Some(Ident::new("w")));
+    assert!(stmt.order_by.is_some());
+}
+
+#[test]
+fn test_window_words_as_names() {
+    let p = Parser::new().unwrap();
+    // 后面不跟帧边界时 ROWS/RANGE 是窗口名或列名
+    let sql = "SELECT sum(v) OVER (rows ORDER BY range ROWS UNBOUNDED PRECEDING) FROM t";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let WindowRef::Spec(spec) = window_of(&stmt.columns[0].value) else {
+        panic!("expected window spec");
+    };
+    assert_eq!(spec.base, Some(Ident::new("rows")));
+    let order_by = spec.order_by.as_ref().unwrap();
+    assert_eq!(order_by.span.source_text(sql), "ORDER BY range");
+    let frame = spec.frame.as_ref().unwrap();
+    assert_eq!(
+        (&frame.units, &frame.start),
+        (
+            &WindowFrameUnits::Rows,
+            &WindowFrameBound::UnboundedPreceding
+        )
+    );
+
+    // WINDOW 后面不是 `name AS` 时是别名
+    let sql = "SELECT a window FROM t window GROUP BY a WINDOW w AS (ORDER BY a)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    assert_eq!(stmt.columns[0].name, Some(Ident::new("window")));
+    let Some(From::Table(Table::Name(table))) = stmt.from.as_ref().map(|from| &from[0]) else {
+        panic!("expected table");
+    };
+    assert_eq!(table.name, Some(Ident::new("window")));
+    assert!(stmt.group_by.is_some());
+    assert_eq!(stmt.window.as_ref().map(|windows| windows.len()), Some(1));
+}
+
+// ============================================================================
+// 聚合函数修饰验证
+// ============================================================================
+
+fn function_call<'a>(expr: &'a Expr<'a>) -> &'a FunctionCall<'a> {
+    match expr {
+        Expr::FunctionCall(call) => call,
+        other => panic!("expected FunctionCall, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_aggregate_filter_and_within_group() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT COUNT(*) FILTER (WHERE ok AND n > 1), \
+               percentile_cont(0.5) WITHIN GROUP (ORDER BY x DESC) FROM t";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+
+    let count = function_call(&stmt.columns[0].value);
+    assert_eq!(
+        binary_op(count.filter.as_ref().unwrap()).op,
+        BinaryOperator::And
+    );
+    assert_eq!(
+        count.span.source_text(sql),
+        "COUNT(*) FILTER (WHERE ok AND n > 1)"
+    );
+
+    let percentile = function_call(&stmt.columns[1].value);
+    let within_group = percentile.within_group.as_ref().unwrap();
+    assert_eq!(within_group.columns[0].direction, OrderDirection::DESC);
+    assert!(percentile.order_by.is_none() && percentile.filter.is_none());
+
+    assert!(p.parse("SELECT COUNT(*) FILTER (ok) FROM t").is_err());
+    assert!(
+        p.parse("SELECT percentile_cont(0.5) WITHIN (ORDER BY x) FROM t")
+            .is_err()
+    );
+
+    // 不跟 `(` 或 GROUP 时 FILTER/WITHIN 是别名
+    let result = p
+        .parse("SELECT COUNT(*) filter, max(x) within FROM t")
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let aliases: Vec<_> = stmt.columns.iter().map(|column| column.name).collect();
+    assert_eq!(
+        aliases,
+        vec![Some(Ident::new("filter")), Some(Ident::new("within"))]
+    );
+}
+
+#[test]
+fn test_order_by_and_limit_inside_arguments() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "SELECT string_agg(DISTINCT name, ',' ORDER BY name), array_agg(x LIMIT 10), \
+                array_agg(x IGNORE NULLS ORDER BY y LIMIT 3) FROM t",
+        )
+        .unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+
+    let string_agg = function_call(&stmt.columns[0].value);
+    assert!(string_agg.distinct);
+    assert_eq!(string_agg.args.len(), 2);
+    assert_eq!(string_agg.order_by.as_ref().unwrap().columns.len(), 1);
+
+    let array_agg = function_call(&stmt.columns[1].value);
+    assert_eq!(array_agg.args.len(), 1);
+    assert!(array_agg.limit.is_some() && array_agg.order_by.is_none());
+
+    let array_agg = function_call(&stmt.columns[2].value);
+    assert_eq!(array_agg.null_treatment, Some(NullTreatment::IgnoreNulls));
+    assert!(array_agg.order_by.is_some() && array_agg.limit.is_some());
+
+    assert!(
+        p.parse("SELECT string_agg(name, ORDER BY name) FROM t")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_window_function_null_treatment() {
+    let p = Parser::new().unwrap();
+    let sql = "SELECT FIRST_VALUE(x IGNORE NULLS) OVER (ORDER BY ts), \
+               LAST_VALUE(x) RESPECT NULLS OVER w, \
+               COUNT(*) FILTER (WHERE ok) OVER w FROM t";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Query(Query::Select(stmt)) = &result.list[0] else {
+        panic!("expected Select");
+    };
+    let window_functions: Vec<_> = stmt
+        .columns
+        .iter()
+        .map(|column| match &column.value {
+            Expr::WindowFunction(window_function) => window_function,
+            other => panic!("expected WindowFunction, got {other:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        window_functions[0].function.null_treatment,
+        Some(NullTreatment::IgnoreNulls)
+    );
+    assert_eq!(window_functions[0].null_treatment, None);
+    assert_eq!(window_functions[1].function.null_treatment, None);
+    assert_eq!(
+        window_functions[1].null_treatment,
+        Some(NullTreatment::RespectNulls)
+    );
+    assert_eq!(
+        stmt.columns[1].value.span().source_text(sql),
+        "LAST_VALUE(x) RESPECT NULLS OVER w"
+    );
+    assert!(window_functions[2].function.filter.is_some());
+
+    assert!(p.parse("SELECT LAST_VALUE(x) IGNORE NULLS FROM t").is_err());
+}
+
+// ============================================================================
+// 非保留字仍可用作列名
+// ============================================================================
+
+/// 只在特定位置有意义的词，按名字匹配，不进 Keyword
+const NON_RESERVED_WORDS: &[&str] = &[
+    "cast",
+    "try_cast",
+    "both",
+    "for",
+    "leading",
+    "placing",
+    "trailing",
+    "any",
+    "some",
+    "current",
+    "exclude",
+    "following",
+    "groups",
+    "others",
+    "preceding",
+    "range",
+    "row",
+    "rows",
+    "ties",
+    "unbounded",
+    "window",
+    "filter",
+    "ignore",
+    "respect",
+    "within",
+    "conflict",
+    "do",
+    "duplicate",
+    "nothing",
+    "returning",
+    "merge",
+    "matched",
+    "foreign",
+    "collate",
+    "index",
+];
+
+#[test]
+fn test_non_reserved_words_as_columns() {
+    let p = Parser::new().unwrap();
+    for word in NON_RESERVED_WORDS {
+        let sql = format!("select {word}, t.{word} from t where {word} = 1");
+        let result = p.parse(&sql).unwrap_or_else(|err| panic!("{sql}: {err:?}"));
+        let StatementInner::Query(Query::Select(select)) = &result.list[0] else {
+            panic!("expected SELECT for {sql}");
+        };
+        let names = select
+            .columns
+            .iter()
+            .map(|column| match &column.value {
+                Expr::Field(field) => (field.prefix.map(|prefix| prefix.value), field.name.value),
+                other => panic!("{sql}: expected column, got {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![(None, *word), (Some("t"), *word)], "{sql}");
+    }
+}
@@ -1,14 +1,37 @@
 use simd_sql::{
-    Parser, Statement,
+    Parser, Span, Spanned, Statement,
     ast::{
         ddl::{
-            AlterTable, AlterTableOperation, ColumnConstraint, ColumnDef, CreateTable,
-            DdlStatement, DropTable,
+            AlterColumnAction, AlterTable, AlterTableOperation, ColumnConstraint, ColumnDef,
+            CreateTable, DdlStatement, DropIndex, DropTable, ForeignKeyReference, GeneratedColumn,
+            ReferentialAction, TableConstraintKind,
         },
         statement::StatementInner,
     },
+    common::{
+        data_type::{DataType, DataTypeKind},
+        ident::{Ident, QuoteStyle},
+        order::{NullsOrder, OrderDirection},
+    },
 };
 
+/// The actions of a single `ALTER TABLE` statement.
+fn alter_operations<'s, 'a>(result: &'s Statement<'a>) -> &'s [AlterTableOperation<'a>] {
+    match &result.list[0] {
+        StatementInner::Ddl(DdlStatement::AlterTable(alter)) => &alter.operations,
+        _ => panic!("expected AlterTable"),
+    }
+}
+
+/// The source text of a column's `DEFAULT` expression.
+fn default_text<'a>(column: &ColumnDef, sql: &'a str) -> Option<&'a str> {
+    column
+        .constraint
+        .default
+        .as_ref()
+        .map(|expr| expr.span().source_text(sql))
+}
+
 // ============================================================================
 // CREATE TABLE
 // ============================================================================
@@ -23,18 +46,30 @@ fn test_create_table_basic() {
             list: vec![StatementInner::Ddl(DdlStatement::CreateTable(
                 CreateTable::Table {
                     if_not_exists: false,
-                    name: "t",
+                    name: Ident::new("t"),
                     columns: vec![ColumnDef {
-                        name: "id",
-                        col_type: "INT",
-                        col_type_params: None,
+                        name: Ident::new("id"),
+                        data_type: DataType {
+                            kind: DataTypeKind::Int { unsigned: false },
+                            span: Span::new(19, 22),
+                        },
                         constraint: ColumnConstraint {
+                            name: None,
                             not_null: false,
                             default: None,
                             primary_key: false,
                             unique: false,
+                            check: None,
+                            references: None,
+                            generated: None,
+                            auto_increment: false,
                         },
+                        collation: None,
+                        comment: None,
+                        span: Span::new(16, 22),
                     }],
+                    constraints: vec![],
+                    span: Span::new(0, 23),
                 }
             ))]
         }
@@ -45,18 +80,17 @@ fn test_create_table_basic() {
 fn test_create_table_if_not_exists() {
     let p = Parser::new().unwrap();
     let result = p.parse("CREATE TABLE IF NOT EXISTS t (id INT)").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                if_not_exists,
-                name,
-                ..
-            })) => {
-                assert!(*if_not_exists, "IF NOT EXISTS should be true");
-                assert_eq!(*name, "t");
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
+            if_not_exists,
+            name,
+            ..
+        })) => {
+            assert!(*if_not_exists, "IF NOT EXISTS should be true");
+            assert_eq!(*name, "t");
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
@@ -65,21 +99,24 @@ fn test_create_table_multiple_columns() {
     let p = Parser::new().unwrap();
     let sql = "CREATE TABLE users (id INT, name TEXT, age INT)";
     let result = p.parse(sql).unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 3);
-                assert_eq!(columns[0].name, "id");
-                assert_eq!(columns[0].col_type, "INT");
-                assert_eq!(columns[1].name, "name");
-                assert_eq!(columns[1].col_type, "TEXT");
-                assert_eq!(columns[2].name, "age");
-                assert_eq!(columns[2].col_type, "INT");
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 3);
+            assert_eq!(columns[0].name, "id");
+            assert_eq!(
+                columns[0].data_type.kind,
+                DataTypeKind::Int { unsigned: false }
+            );
+            assert_eq!(columns[1].name, "name");
+            assert_eq!(columns[1].data_type.kind, DataTypeKind::Text);
+            assert_eq!(columns[2].name, "age");
+            assert_eq!(
+                columns[2].data_type.kind,
+                DataTypeKind::Int { unsigned: false }
+            );
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
@@ -87,16 +124,13 @@ fn test_create_table_multiple_columns() {
 fn test_create_table_not_null() {
     let p = Parser::new().unwrap();
     let result = p.parse("CREATE TABLE t (id INT NOT NULL)").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert!(columns[0].constraint.not_null);
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert!(columns[0].constraint.not_null);
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
@@ -104,16 +138,13 @@ fn test_create_table_not_null() {
 fn test_create_table_primary_key() {
     let p = Parser::new().unwrap();
     let result = p.parse("CREATE TABLE t (id INT PRIMARY KEY)").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert!(columns[0].constraint.primary_key);
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert!(columns[0].constraint.primary_key);
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
@@ -121,50 +152,43 @@ fn test_create_table_primary_key() {
 fn test_create_table_unique() {
     let p = Parser::new().unwrap();
     let result = p.parse("CREATE TABLE t (email TEXT UNIQUE)").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert!(columns[0].constraint.unique);
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert!(columns[0].constraint.unique);
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
 #[test]
 fn test_create_table_default_value() {
     let p = Parser::new().unwrap();
-    let result = p.parse("CREATE TABLE t (score INT DEFAULT 0)").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert_eq!(columns[0].constraint.default, Some("0"));
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let sql = "CREATE TABLE t (score INT DEFAULT 0)";
+    let result = p.parse(sql).unwrap();
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert_eq!(default_text(&columns[0], sql), Some("0"));
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
 #[test]
 fn test_create_table_default_string() {
     let p = Parser::new().unwrap();
-    let result = p.parse("CREATE TABLE t (name TEXT DEFAULT 'Alice')").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert_eq!(columns[0].constraint.default, Some("'Alice'"));
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let sql = "CREATE TABLE t (name TEXT DEFAULT 'Alice')";
+    let result = p.parse(sql).unwrap();
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert_eq!(default_text(&columns[0], sql), Some("'Alice'"));
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
@@ -179,65 +203,365 @@ CREATE TABLE products (\
     active BOOLEAN DEFAULT TRUE\
 )";
     let result = p.parse(sql).unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 4);
-                // id: INT PRIMARY KEY NOT NULL
-                assert!(columns[0].constraint.primary_key);
-                assert!(columns[0].constraint.not_null);
-                // name: TEXT NOT NULL UNIQUE
-                assert!(columns[1].constraint.not_null);
-                assert!(columns[1].constraint.unique);
-                // price: DECIMAL DEFAULT 0
-                assert_eq!(columns[2].constraint.default, Some("0"));
-                // active: BOOLEAN DEFAULT TRUE
-                assert_eq!(columns[3].constraint.default, Some("TRUE"));
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 4);
+            // id: INT PRIMARY KEY NOT NULL
+            assert!(columns[0].constraint.primary_key);
+            assert!(columns[0].constraint.not_null);
+            // name: TEXT NOT NULL UNIQUE
+            assert!(columns[1].constraint.not_null);
+            assert!(columns[1].constraint.unique);
+            // price: DECIMAL DEFAULT 0
+            assert_eq!(default_text(&columns[2], sql), Some("0"));
+            // active: BOOLEAN DEFAULT TRUE
+            assert_eq!(default_text(&columns[3], sql), Some("TRUE"));
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
+#[test]
+fn test_create_table_expression_defaults() {
+    let p = Parser::new().unwrap();
+    let sql = "CREATE TABLE t (\
+        a INT DEFAULT -1 NOT NULL, \
+        b TIMESTAMP DEFAULT now(), \
+        c INT DEFAULT (a + 1) UNIQUE, \
+        d TEXT DEFAULT 'x' || 'y')";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(default_text(&columns[0], sql), Some("-1"));
+    assert!(columns[0].constraint.not_null);
+    assert_eq!(default_text(&columns[1], sql), Some("now()"));
+    assert_eq!(default_text(&columns[2], sql), Some("a + 1"));
+    assert!(columns[2].constraint.unique);
+    assert_eq!(default_text(&columns[3], sql), Some("'x' || 'y'"));
+
+    assert!(p.parse("CREATE TABLE t (a INT DEFAULT)").is_err());
+}
+
+#[test]
+fn test_create_table_generated_columns() {
+    let p = Parser::new().unwrap();
+    let sql = "CREATE TABLE t (\
+        id INT GENERATED ALWAYS AS IDENTITY PRIMARY KEY, \
+        seq INT GENERATED BY DEFAULT AS IDENTITY, \
+        total INT GENERATED ALWAYS AS (price * qty) STORED, \
+        half INT GENERATED ALWAYS AS (total / 2), \
+        legacy INT AUTO_INCREMENT NOT NULL)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(
+        columns[0].constraint.generated,
+        Some(GeneratedColumn::Identity { always: true })
+    );
+    assert!(columns[0].constraint.primary_key);
+    assert_eq!(
+        columns[1].constraint.generated,
+        Some(GeneratedColumn::Identity { always: false })
+    );
+    let Some(GeneratedColumn::Expr { expr, stored }) = &columns[2].constraint.generated else {
+        panic!("expected generated column");
+    };
+    assert_eq!(expr.span().source_text(sql), "price * qty");
+    assert!(*stored);
+    assert!(matches!(
+        columns[3].constraint.generated,
+        Some(GeneratedColumn::Expr { stored: false, .. })
+    ));
+    assert!(columns[4].constraint.auto_increment);
+    assert!(columns[4].constraint.not_null);
+
+    // BY DEFAULT 只能用于 IDENTITY
+    assert!(
+        p.parse("CREATE TABLE t (a INT GENERATED BY DEFAULT AS (1))")
+            .is_err()
+    );
+    assert!(p.parse("CREATE TABLE t (a INT GENERATED AS (1))").is_err());
+}
+
+#[test]
+fn test_create_table_collate_and_comment() {
+    let p = Parser::new().unwrap();
+    let sql =
+        r#"CREATE TABLE t (name TEXT COLLATE "C" NOT NULL COMMENT 'display name', note TEXT)"#;
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(
+        columns[0].collation,
+        Some(Ident::quoted("C", QuoteStyle::Double))
+    );
+    assert!(columns[0].constraint.not_null);
+    assert_eq!(
+        columns[0].comment.as_ref().map(|comment| comment.value),
+        Some("'display name'")
+    );
+    assert_eq!(columns[1].collation, None);
+    assert_eq!(columns[1].comment, None);
+
+    assert!(p.parse("CREATE TABLE t (a TEXT COMMENT)").is_err());
+}
+
 #[test]
 fn test_create_table_varchar_type() {
     let p = Parser::new().unwrap();
     let sql = "CREATE TABLE t (name VARCHAR(100) NOT NULL)";
     let result = p.parse(sql).unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
-                columns, ..
-            })) => {
-                assert_eq!(columns.len(), 1);
-                assert_eq!(columns[0].name, "name");
-                assert_eq!(columns[0].col_type, "VARCHAR");
-                assert!(columns[0].col_type_params.is_some());
-                assert!(columns[0].constraint.not_null);
-            }
-            _ => panic!("expected CreateTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) => {
+            assert_eq!(columns.len(), 1);
+            assert_eq!(columns[0].name, "name");
+            assert_eq!(
+                columns[0].data_type.kind,
+                DataTypeKind::Varchar { length: Some(100) }
+            );
+            assert!(columns[0].constraint.not_null);
+        }
+        _ => panic!("expected CreateTable"),
     }
 }
 
+#[test]
+fn test_create_table_rich_data_types() {
+    let p = Parser::new().unwrap();
+    let sql = "CREATE TABLE t (\
+        a DOUBLE PRECISION, \
+        b CHARACTER VARYING(255), \
+        c TIMESTAMP(3) WITH TIME ZONE NOT NULL, \
+        d NUMERIC(10,2), \
+        e INT[], \
+        f TEXT[3][], \
+        g ENUM('a','b'), \
+        h UNSIGNED BIGINT, \
+        i INT(11) UNSIGNED, \
+        j TIME WITHOUT TIME ZONE, \
+        k geometry(Point, 4326))";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    let kinds: Vec<&DataTypeKind> = columns
+        .iter()
+        .map(|column| &column.data_type.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &DataTypeKind::Double,
+            &DataTypeKind::Varchar { length: Some(255) },
+            &DataTypeKind::Timestamp {
+                precision: Some(3),
+                with_time_zone: true,
+            },
+            &DataTypeKind::Decimal {
+                precision: Some(10),
+                scale: Some(2),
+            },
+            &DataTypeKind::Array {
+                element: Box::new(DataTypeKind::Int { unsigned: false }),
+                size: None,
+            },
+            &DataTypeKind::Array {
+                element: Box::new(DataTypeKind::Array {
+                    element: Box::new(DataTypeKind::Text),
+                    size: Some(3),
+                }),
+                size: None,
+            },
+            &DataTypeKind::Enum(vec!["'a'", "'b'"]),
+            &DataTypeKind::BigInt { unsigned: true },
+            &DataTypeKind::Int { unsigned: true },
+            &DataTypeKind::Time {
+                precision: None,
+                with_time_zone: false,
+            },
+            &DataTypeKind::Custom {
+                name: "geometry",
+                args: vec!["Point", "4326"],
+            },
+        ]
+    );
+    assert!(columns[2].constraint.not_null);
+    assert_eq!(
+        columns[2].data_type.span.source_text(sql),
+        "TIMESTAMP(3) WITH TIME ZONE"
+    );
+    assert_eq!(columns[5].data_type.span.source_text(sql), "TEXT[3][]");
+}
+
+#[test]
+fn test_data_type_synonyms_compare_equal() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "CREATE TABLE t (a INTEGER, b INT4, c TIMESTAMPTZ, \
+             d TIMESTAMP WITH TIME ZONE, e FLOAT8, f DOUBLE)",
+        )
+        .unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(columns[0].data_type.kind, columns[1].data_type.kind);
+    assert_eq!(columns[2].data_type.kind, columns[3].data_type.kind);
+    assert_eq!(columns[4].data_type.kind, columns[5].data_type.kind);
+    assert_ne!(columns[0].data_type.kind, columns[2].data_type.kind);
+}
+
+#[test]
+fn test_data_type_errors() {
+    let p = Parser::new().unwrap();
+    assert!(p.parse("CREATE TABLE t (a VARCHAR(x))").is_err());
+    assert!(p.parse("CREATE TABLE t (a DECIMAL(10, 2, 1))").is_err());
+    assert!(p.parse("CREATE TABLE t (a TEXT UNSIGNED)").is_err());
+    assert!(p.parse("CREATE TABLE t (a ENUM())").is_err());
+    assert!(p.parse("CREATE TABLE t (a TIMESTAMP WITH TIME)").is_err());
+}
+
 #[test]
 fn test_create_table_as_select() {
     let p = Parser::new().unwrap();
     let result = p.parse("CREATE TABLE t AS SELECT * FROM s").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::AsSelect {
-                name, ..
-            })) => {
-                assert_eq!(*name, "t");
-            }
-            _ => panic!("expected CreateTable::AsSelect"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::AsSelect { name, .. })) => {
+            assert_eq!(*name, "t");
+        }
+        _ => panic!("expected CreateTable::AsSelect"),
     }
 }
 
+#[test]
+fn test_create_table_constraints() {
+    let p = Parser::new().unwrap();
+    let sql = "\
+CREATE TABLE order_items (\
+    order_id INT,\
+    line INT,\
+    product_id INT,\
+    qty INT,\
+    CONSTRAINT pk_items PRIMARY KEY (order_id, line),\
+    UNIQUE (product_id, order_id),\
+    CONSTRAINT fk_product FOREIGN KEY (product_id) REFERENCES products (id) \
+        ON DELETE CASCADE ON UPDATE RESTRICT,\
+    CHECK (qty > 0)\
+)";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
+        columns,
+        constraints,
+        ..
+    })) = &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(columns.len(), 4);
+    assert_eq!(constraints.len(), 4);
+
+    assert_eq!(constraints[0].name, Some(Ident::new("pk_items")));
+    assert_eq!(
+        constraints[0].kind,
+        TableConstraintKind::PrimaryKey {
+            columns: vec![Ident::new("order_id"), Ident::new("line")],
+        }
+    );
+    assert_eq!(constraints[1].name, None);
+    assert!(
+        matches!(&constraints[1].kind, TableConstraintKind::Unique { columns } if columns.len() == 2)
+    );
+
+    let TableConstraintKind::ForeignKey {
+        columns,
+        references,
+    } = &constraints[2].kind
+    else {
+        panic!("expected FOREIGN KEY");
+    };
+    assert_eq!(*columns, vec![Ident::new("product_id")]);
+    assert_eq!(references.table, "products");
+    assert_eq!(references.columns, vec![Ident::new("id")]);
+    assert_eq!(references.on_delete, Some(ReferentialAction::Cascade));
+    assert_eq!(references.on_update, Some(ReferentialAction::Restrict));
+    assert!(matches!(constraints[3].kind, TableConstraintKind::Check(_)));
+}
+
+#[test]
+fn test_create_table_inline_references_and_check() {
+    let p = Parser::new().unwrap();
+    let sql = "CREATE TABLE t (\
+        id INT CONSTRAINT pk_t PRIMARY KEY, \
+        owner INT NOT NULL REFERENCES users ON DELETE SET NULL ON UPDATE NO ACTION, \
+        score INT CHECK (score BETWEEN 0 AND ?))";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table { columns, .. })) =
+        &result.list[0]
+    else {
+        panic!("expected CreateTable");
+    };
+    assert_eq!(columns[0].constraint.name, Some(Ident::new("pk_t")));
+    assert!(columns[0].constraint.primary_key);
+    assert!(columns[1].constraint.not_null);
+    assert_eq!(
+        columns[1].constraint.references,
+        Some(Box::new(ForeignKeyReference {
+            table: Ident::new("users"),
+            columns: vec![],
+            on_delete: Some(ReferentialAction::SetNull),
+            on_update: Some(ReferentialAction::NoAction),
+            span: Span::new(71, 126),
+        }))
+    );
+    assert!(columns[2].constraint.check.is_some());
+    // ColumnConstraint 仍可 Clone
+    assert_eq!(columns[2].constraint.clone(), columns[2].constraint);
+    // CHECK 里的表达式参与遍历
+    assert_eq!(result.placeholders().len(), 1);
+}
+
+#[test]
+fn test_create_table_constraint_errors() {
+    let p = Parser::new().unwrap();
+    assert!(p.parse("CREATE TABLE t (a INT, PRIMARY KEY ())").is_err());
+    assert!(p.parse("CREATE TABLE t (a INT, FOREIGN KEY (a))").is_err());
+    assert!(p.parse("CREATE TABLE t (a INT, CONSTRAINT c)").is_err());
+    assert!(p.parse("CREATE TABLE t (a INT CONSTRAINT c)").is_err());
+    assert!(p.parse("CREATE TABLE t (a INT CHECK a > 0)").is_err());
+    assert!(
+        p.parse("CREATE TABLE t (a INT REFERENCES u ON DELETE SET)")
+            .is_err()
+    );
+    assert!(
+        p.parse("CREATE TABLE t (a INT REFERENCES u ON DELETE NO)")
+            .is_err()
+    );
+    assert!(
+        p.parse("CREATE TABLE t (a INT REFERENCES u ON DELETE NO CASCADE)")
+            .is_err()
+    );
+    assert!(
+        p.parse("CREATE TABLE t (a INT REFERENCES u ON DELETE CASCADE ON DELETE RESTRICT)")
+            .is_err()
+    );
+}
+
 // ============================================================================
 // DROP TABLE
 // ============================================================================
@@ -251,8 +575,9 @@ fn test_drop_table_basic() {
         Statement {
             list: vec![StatementInner::Ddl(DdlStatement::DropTable(DropTable {
                 if_exists: false,
-                names: vec!["t"],
+                names: vec![Ident::new("t")],
                 cascade: false,
+                span: Span::new(0, 12),
             }))]
         }
     );
@@ -262,15 +587,12 @@ fn test_drop_table_basic() {
 fn test_drop_table_if_exists() {
     let p = Parser::new().unwrap();
     let result = p.parse("DROP TABLE IF EXISTS t").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::DropTable(DropTable {
-                if_exists, ..
-            })) => {
-                assert!(*if_exists);
-            }
-            _ => panic!("expected DropTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::DropTable(DropTable { if_exists, .. })) => {
+            assert!(*if_exists);
+        }
+        _ => panic!("expected DropTable"),
     }
 }
 
@@ -278,18 +600,15 @@ fn test_drop_table_if_exists() {
 fn test_drop_table_multiple() {
     let p = Parser::new().unwrap();
     let result = p.parse("DROP TABLE t1, t2, t3").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::DropTable(DropTable {
-                names, ..
-            })) => {
-                assert_eq!(names.len(), 3);
-                assert_eq!(names[0], "t1");
-                assert_eq!(names[1], "t2");
-                assert_eq!(names[2], "t3");
-            }
-            _ => panic!("expected DropTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::DropTable(DropTable { names, .. })) => {
+            assert_eq!(names.len(), 3);
+            assert_eq!(names[0], "t1");
+            assert_eq!(names[1], "t2");
+            assert_eq!(names[2], "t3");
+        }
+        _ => panic!("expected DropTable"),
     }
 }
 
@@ -297,15 +616,12 @@ fn test_drop_table_multiple() {
 fn test_drop_table_cascade() {
     let p = Parser::new().unwrap();
     let result = p.parse("DROP TABLE t CASCADE").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::DropTable(DropTable {
-                cascade, ..
-            })) => {
-                assert!(*cascade);
-            }
-            _ => panic!("expected DropTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::DropTable(DropTable { cascade, .. })) => {
+            assert!(*cascade);
+        }
+        _ => panic!("expected DropTable"),
     }
 }
 
@@ -313,15 +629,12 @@ fn test_drop_table_cascade() {
 fn test_drop_table_restrict() {
     let p = Parser::new().unwrap();
     let result = p.parse("DROP TABLE t RESTRICT").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::DropTable(DropTable {
-                cascade, ..
-            })) => {
-                assert!(!*cascade, "RESTRICT means cascade=false");
-            }
-            _ => panic!("expected DropTable"),
-        },
+    let Statement { list } = result;
+    match &list[0] {
+        StatementInner::Ddl(DdlStatement::DropTable(DropTable { cascade, .. })) => {
+            assert!(!*cascade, "RESTRICT means cascade=false");
+        }
+        _ => panic!("expected DropTable"),
     }
 }
 
@@ -337,20 +650,32 @@ fn test_alter_table_add_column() {
         result,
         Statement {
             list: vec![StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                name: "t",
-                operation: AlterTableOperation::AddColumn {
+                name: Ident::new("t"),
+                operations: vec![AlterTableOperation::AddColumn {
+                    if_not_exists: false,
                     column: ColumnDef {
-                        name: "x",
-                        col_type: "INT",
-                        col_type_params: None,
+                        name: Ident::new("x"),
+                        data_type: DataType {
+                            kind: DataTypeKind::Int { unsigned: false },
+                            span: Span::new(27, 30),
+                        },
                         constraint: ColumnConstraint {
+                            name: None,
                             not_null: false,
                             default: None,
                             primary_key: false,
                             unique: false,
+                            check: None,
+                            references: None,
+                            generated: None,
+                            auto_increment: false,
                         },
+                        collation: None,
+                        comment: None,
+                        span: Span::new(25, 30),
                     },
-                },
+                }],
+                span: Span::new(0, 30),
             }))]
         }
     );
@@ -360,18 +685,13 @@ fn test_alter_table_add_column() {
 fn test_alter_table_add_without_column_keyword() {
     let p = Parser::new().unwrap();
     let result = p.parse("ALTER TABLE t ADD x INT NOT NULL").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                operation: AlterTableOperation::AddColumn { column },
-                ..
-            })) => {
-                assert_eq!(column.name, "x");
-                assert_eq!(column.col_type, "INT");
-                assert!(column.constraint.not_null);
-            }
-            _ => panic!("expected AlterTable"),
-        },
+    match alter_operations(&result) {
+        [AlterTableOperation::AddColumn { column, .. }] => {
+            assert_eq!(column.name, "x");
+            assert_eq!(column.data_type.kind, DataTypeKind::Int { unsigned: false });
+            assert!(column.constraint.not_null);
+        }
+        _ => panic!("expected AlterTable"),
     }
 }
 
@@ -383,11 +703,12 @@ fn test_alter_table_drop_column() {
         result,
         Statement {
             list: vec![StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                name: "t",
-                operation: AlterTableOperation::DropColumn {
-                    name: "x",
+                name: Ident::new("t"),
+                operations: vec![AlterTableOperation::DropColumn {
+                    name: Ident::new("x"),
                     cascade: false,
-                },
+                }],
+                span: Span::new(0, 27),
             }))]
         }
     );
@@ -397,17 +718,12 @@ fn test_alter_table_drop_column() {
 fn test_alter_table_drop_without_column_keyword() {
     let p = Parser::new().unwrap();
     let result = p.parse("ALTER TABLE t DROP x").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                operation: AlterTableOperation::DropColumn { name, cascade },
-                ..
-            })) => {
-                assert_eq!(*name, "x");
-                assert!(!cascade);
-            }
-            _ => panic!("expected AlterTable"),
-        },
+    match alter_operations(&result) {
+        [AlterTableOperation::DropColumn { name, cascade }] => {
+            assert_eq!(*name, "x");
+            assert!(!cascade);
+        }
+        _ => panic!("expected AlterTable"),
     }
 }
 
@@ -415,17 +731,12 @@ fn test_alter_table_drop_without_column_keyword() {
 fn test_alter_table_drop_column_cascade() {
     let p = Parser::new().unwrap();
     let result = p.parse("ALTER TABLE t DROP COLUMN x CASCADE").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                operation: AlterTableOperation::DropColumn { name, cascade },
-                ..
-            })) => {
-                assert_eq!(*name, "x");
-                assert!(*cascade);
-            }
-            _ => panic!("expected AlterTable"),
-        },
+    match alter_operations(&result) {
+        [AlterTableOperation::DropColumn { name, cascade }] => {
+            assert_eq!(*name, "x");
+            assert!(*cascade);
+        }
+        _ => panic!("expected AlterTable"),
     }
 }
 
@@ -437,8 +748,9 @@ fn test_alter_table_rename_to() {
         result,
         Statement {
             list: vec![StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                name: "t",
-                operation: AlterTableOperation::RenameTo("t2"),
+                name: Ident::new("t"),
+                operations: vec![AlterTableOperation::RenameTo(Ident::new("t2"))],
+                span: Span::new(0, 26),
             }))]
         }
     );
@@ -448,17 +760,12 @@ fn test_alter_table_rename_to() {
 fn test_alter_table_rename_column() {
     let p = Parser::new().unwrap();
     let result = p.parse("ALTER TABLE t RENAME COLUMN old TO new").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                operation: AlterTableOperation::RenameColumn { old, new },
-                ..
-            })) => {
-                assert_eq!(*old, "old");
-                assert_eq!(*new, "new");
-            }
-            _ => panic!("expected AlterTable"),
-        },
+    match alter_operations(&result) {
+        [AlterTableOperation::RenameColumn { old, new }] => {
+            assert_eq!(*old, "old");
+            assert_eq!(*new, "new");
+        }
+        _ => panic!("expected AlterTable"),
     }
 }
 
@@ -466,20 +773,309 @@ fn test_alter_table_rename_column() {
 fn test_alter_table_rename_without_column_keyword() {
     let p = Parser::new().unwrap();
     let result = p.parse("ALTER TABLE t RENAME old TO new").unwrap();
-    match result {
-        Statement { list } => match &list[0] {
-            StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
-                operation: AlterTableOperation::RenameColumn { old, new },
-                ..
-            })) => {
-                assert_eq!(*old, "old");
-                assert_eq!(*new, "new");
-            }
-            _ => panic!("expected AlterTable"),
-        },
+    match alter_operations(&result) {
+        [AlterTableOperation::RenameColumn { old, new }] => {
+            assert_eq!(*old, "old");
+            assert_eq!(*new, "new");
+        }
+        _ => panic!("expected AlterTable"),
     }
 }
 
+#[test]
+fn test_alter_table_multiple_actions() {
+    let p = Parser::new().unwrap();
+    let sql = "ALTER TABLE orders \
+        ADD COLUMN IF NOT EXISTS note TEXT, \
+        ALTER COLUMN status SET DEFAULT 'new', \
+        ALTER total DROP DEFAULT, \
+        ALTER COLUMN customer_id SET NOT NULL, \
+        ALTER COLUMN legacy DROP NOT NULL, \
+        ALTER COLUMN amount TYPE NUMERIC(12, 2) USING amount::numeric, \
+        ALTER COLUMN code SET DATA TYPE VARCHAR(20), \
+        DROP COLUMN old CASCADE";
+    let result = p.parse(sql).unwrap();
+    let operations = alter_operations(&result);
+    assert_eq!(operations.len(), 8);
+
+    let AlterTableOperation::AddColumn {
+        if_not_exists,
+        column,
+    } = &operations[0]
+    else {
+        panic!("expected ADD COLUMN");
+    };
+    assert!(*if_not_exists);
+    assert_eq!(column.name, "note");
+
+    let AlterTableOperation::AlterColumn {
+        name,
+        action: AlterColumnAction::SetDefault(default),
+    } = &operations[1]
+    else {
+        panic!("expected SET DEFAULT");
+    };
+    assert_eq!(*name, "status");
+    assert_eq!(default.span().source_text(sql), "'new'");
+
+    assert!(matches!(
+        &operations[2],
+        AlterTableOperation::AlterColumn {
+            name,
+            action: AlterColumnAction::DropDefault,
+        } if *name == "total"
+    ));
+    assert!(matches!(
+        operations[3],
+        AlterTableOperation::AlterColumn {
+            action: AlterColumnAction::SetNotNull,
+            ..
+        }
+    ));
+    assert!(matches!(
+        operations[4],
+        AlterTableOperation::AlterColumn {
+            action: AlterColumnAction::DropNotNull,
+            ..
+        }
+    ));
+
+    let AlterTableOperation::AlterColumn {
+        action: AlterColumnAction::SetDataType { data_type, using },
+        ..
+    } = &operations[5]
+    else {
+        panic!("expected TYPE");
+    };
+    assert_eq!(
+        data_type.kind,
+        DataTypeKind::Decimal {
+            precision: Some(12),
+            scale: Some(2),
+        }
+    );
+    assert!(using.is_some());
+    assert!(matches!(
+        &operations[6],
+        AlterTableOperation::AlterColumn {
+            action: AlterColumnAction::SetDataType { using: None, .. },
+            ..
+        }
+    ));
+    assert!(matches!(
+        operations[7],
+        AlterTableOperation::DropColumn { cascade: true, .. }
+    ));
+    assert_eq!(result.span().source_text(sql), sql);
+}
+
+#[test]
+fn test_alter_table_constraints() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "ALTER TABLE items \
+             ADD CONSTRAINT fk_order FOREIGN KEY (order_id) REFERENCES orders (id) ON DELETE CASCADE, \
+             ADD UNIQUE (sku), \
+             DROP CONSTRAINT IF EXISTS chk_qty RESTRICT, \
+             DROP CONSTRAINT pk_items CASCADE",
+        )
+        .unwrap();
+    let operations = alter_operations(&result);
+    assert_eq!(operations.len(), 4);
+
+    let AlterTableOperation::AddConstraint(constraint) = &operations[0] else {
+        panic!("expected ADD CONSTRAINT");
+    };
+    assert_eq!(constraint.name, Some(Ident::new("fk_order")));
+    assert!(matches!(
+        constraint.kind,
+        TableConstraintKind::ForeignKey { .. }
+    ));
+    assert!(matches!(
+        &operations[1],
+        AlterTableOperation::AddConstraint(constraint)
+            if constraint.name.is_none()
+                && matches!(constraint.kind, TableConstraintKind::Unique { .. })
+    ));
+    assert_eq!(
+        operations[2],
+        AlterTableOperation::DropConstraint {
+            if_exists: true,
+            name: Ident::new("chk_qty"),
+            cascade: false,
+        }
+    );
+    assert_eq!(
+        operations[3],
+        AlterTableOperation::DropConstraint {
+            if_exists: false,
+            name: Ident::new("pk_items"),
+            cascade: true,
+        }
+    );
+}
+
+#[test]
+fn test_alter_table_mysql_modify_and_change() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(
+            "ALTER TABLE t MODIFY COLUMN a BIGINT NOT NULL, MODIFY b TEXT, \
+             CHANGE COLUMN c d INT UNSIGNED DEFAULT 0, CHANGE e f TEXT",
+        )
+        .unwrap();
+    let operations = alter_operations(&result);
+    assert_eq!(operations.len(), 4);
+
+    let AlterTableOperation::ModifyColumn { column } = &operations[0] else {
+        panic!("expected MODIFY");
+    };
+    assert_eq!(column.name, "a");
+    assert_eq!(
+        column.data_type.kind,
+        DataTypeKind::BigInt { unsigned: false }
+    );
+    assert!(column.constraint.not_null);
+    assert!(matches!(
+        &operations[1],
+        AlterTableOperation::ModifyColumn { column } if column.name == "b"
+    ));
+
+    let AlterTableOperation::ChangeColumn { old, column } = &operations[2] else {
+        panic!("expected CHANGE");
+    };
+    assert_eq!(*old, "c");
+    assert_eq!(column.name, "d");
+    assert_eq!(column.data_type.kind, DataTypeKind::Int { unsigned: true });
+    assert!(column.constraint.default.is_some());
+    assert!(matches!(
+        &operations[3],
+        AlterTableOperation::ChangeColumn { old, column } if *old == "e" && column.name == "f"
+    ));
+}
+
+#[test]
+fn test_alter_table_action_errors() {
+    let p = Parser::new().unwrap();
+    assert!(p.parse("ALTER TABLE t ADD COLUMN a INT,").is_err());
+    assert!(p.parse("ALTER TABLE t ALTER COLUMN a SET").is_err());
+    assert!(p.parse("ALTER TABLE t ALTER COLUMN a DROP").is_err());
+    assert!(p.parse("ALTER TABLE t ALTER COLUMN a TYPE").is_err());
+    assert!(
+        p.parse("ALTER TABLE t ALTER COLUMN a SET DATA INT")
+            .is_err()
+    );
+    assert!(p.parse("ALTER TABLE t DROP CONSTRAINT").is_err());
+    assert!(p.parse("ALTER TABLE t ADD CONSTRAINT c").is_err());
+    assert!(p.parse("ALTER TABLE t CHANGE a").is_err());
+}
+
+// ============================================================================
+// CREATE / DROP INDEX
+// ============================================================================
+
+#[test]
+fn test_create_index_full() {
+    let p = Parser::new().unwrap();
+    let sql = "CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS idx_users_email ON users \
+               USING btree (email ASC NULLS LAST, lower(email) DESC) \
+               INCLUDE (id, name) WHERE deleted_at IS NULL";
+    let result = p.parse(sql).unwrap();
+    let StatementInner::Ddl(DdlStatement::CreateIndex(index)) = &result.list[0] else {
+        panic!("expected CreateIndex");
+    };
+    assert!(index.unique);
+    assert!(index.concurrently);
+    assert!(index.if_not_exists);
+    assert_eq!(index.name, Some(Ident::new("idx_users_email")));
+    assert_eq!(index.table, "users");
+    assert_eq!(index.using, Some(Ident::new("btree")));
+
+    assert_eq!(index.columns.len(), 2);
+    assert_eq!(index.columns[0].direction, OrderDirection::ASC);
+    assert_eq!(index.columns[0].nulls_order, Some(NullsOrder::Last));
+    assert_eq!(index.columns[1].span.source_text(sql), "lower(email) DESC");
+    assert_eq!(index.columns[1].direction, OrderDirection::DESC);
+
+    assert_eq!(index.include, vec![Ident::new("id"), Ident::new("name")]);
+    assert_eq!(
+        index
+            .where_statement
+            .as_ref()
+            .map(|expr| expr.span().source_text(sql)),
+        Some("deleted_at IS NULL")
+    );
+    assert_eq!(index.span.source_text(sql), sql);
+}
+
+#[test]
+fn test_create_index_minimal() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse("CREATE INDEX idx ON t (a); CREATE INDEX ON t (b, c)")
+        .unwrap();
+    assert_eq!(result.list.len(), 2);
+    let StatementInner::Ddl(DdlStatement::CreateIndex(index)) = &result.list[0] else {
+        panic!("expected CreateIndex");
+    };
+    assert!(!index.unique);
+    assert!(!index.concurrently);
+    assert_eq!(index.name, Some(Ident::new("idx")));
+    assert_eq!(index.using, None);
+    assert!(index.include.is_empty());
+    assert_eq!(index.where_statement, None);
+
+    let StatementInner::Ddl(DdlStatement::CreateIndex(index)) = &result.list[1] else {
+        panic!("expected CreateIndex");
+    };
+    assert_eq!(index.name, None);
+    assert_eq!(index.columns.len(), 2);
+
+    // 部分索引的条件参与遍历
+    let result = p.parse("CREATE INDEX i ON t (a) WHERE b > ?").unwrap();
+    assert_eq!(result.placeholders().len(), 1);
+}
+
+#[test]
+fn test_drop_index() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse("DROP INDEX CONCURRENTLY IF EXISTS a, b CASCADE")
+        .unwrap();
+    assert_eq!(
+        result.list[0],
+        StatementInner::Ddl(DdlStatement::DropIndex(DropIndex {
+            concurrently: true,
+            if_exists: true,
+            names: vec![Ident::new("a"), Ident::new("b")],
+            table: None,
+            cascade: true,
+            span: Span::new(0, 46),
+        }))
+    );
+
+    let result = p.parse("DROP INDEX idx ON t").unwrap();
+    let StatementInner::Ddl(DdlStatement::DropIndex(drop)) = &result.list[0] else {
+        panic!("expected DropIndex");
+    };
+    assert_eq!(drop.names, vec![Ident::new("idx")]);
+    assert_eq!(drop.table, Some(Ident::new("t")));
+    assert!(!drop.cascade);
+}
+
+#[test]
+fn test_index_errors() {
+    let p = Parser::new().unwrap();
+    assert!(p.parse("CREATE INDEX idx ON t").is_err());
+    assert!(p.parse("CREATE INDEX idx ON t ()").is_err());
+    assert!(p.parse("CREATE INDEX idx t (a)").is_err());
+    assert!(p.parse("CREATE UNIQUE TABLE t (a INT)").is_err());
+    assert!(p.parse("CREATE INDEX IF NOT EXISTS ON t (a)").is_err());
+    assert!(p.parse("CREATE INDEX idx ON t (a) INCLUDE ()").is_err());
+    assert!(p.parse("DROP INDEX").is_err());
+}
+
 // ============================================================================
 // 错误路径
 // ============================================================================
@@ -521,3 +1117,84 @@ fn test_ddl_mixed_with_select() {
         _ => panic!("expected Query as second statement"),
     }
 }
+
+#[test]
+fn test_quoted_ddl_names() {
+    let p = Parser::new().unwrap();
+    let result = p
+        .parse(r#"CREATE TABLE "Order Details" ([Unit Price] INT, `order` TEXT)"#)
+        .unwrap();
+    match &result.list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
+            name,
+            columns,
+            ..
+        })) => {
+            assert_eq!(*name, Ident::quoted("Order Details", QuoteStyle::Double));
+            assert_eq!(
+                columns[0].name,
+                Ident::quoted("Unit Price", QuoteStyle::Bracket)
+            );
+            assert_eq!(
+                columns[1].name,
+                Ident::quoted("order", QuoteStyle::Backtick)
+            );
+        }
+        _ => panic!("expected CreateTable"),
+    }
+
+    let result = p
+        .parse(r#"ALTER TABLE "T" RENAME COLUMN "Old Name" TO "New Name""#)
+        .unwrap();
+    match &result.list[0] {
+        StatementInner::Ddl(DdlStatement::AlterTable(AlterTable {
+            name, operations, ..
+        })) => {
+            assert_eq!(*name, Ident::quoted("T", QuoteStyle::Double));
+            let [AlterTableOperation::RenameColumn { old, new }] = operations.as_slice() else {
+                panic!("expected RENAME COLUMN");
+            };
+            assert_eq!(*old, Ident::quoted("Old Name", QuoteStyle::Double));
+            assert_eq!(*new, Ident::quoted("New Name", QuoteStyle::Double));
+        }
+        _ => panic!("expected AlterTable"),
+    }
+}
+
+#[test]
+fn test_ddl_non_reserved_words_as_names() {
+    let p = Parser::new().unwrap();
+    // INDEX、FOREIGN、COLLATE 不是保留字，可以作为列名
+    let result = p
+        .parse(
+            "CREATE TABLE t (index INT, foreign INT COLLATE nocase, collate TEXT, \
+             FOREIGN KEY (foreign) REFERENCES p (id))",
+        )
+        .unwrap();
+    match &result.list[0] {
+        StatementInner::Ddl(DdlStatement::CreateTable(CreateTable::Table {
+            columns,
+            constraints,
+            ..
+        })) => {
+            let names = columns
+                .iter()
+                .map(|column| column.name.value)
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["index", "foreign", "collate"]);
+            assert_eq!(columns[1].collation, Some(Ident::new("nocase")));
+            assert!(matches!(
+                constraints[0].kind,
+                TableConstraintKind::ForeignKey { .. }
+            ));
+        }
+        _ => panic!("expected CreateTable"),
+    }
+
+    let result = p.parse("ALTER TABLE t ADD foreign INT").unwrap();
+    assert!(matches!(
+        alter_operations(&result),
+        [AlterTableOperation::AddColumn { .. }]
+    ));
+    assert!(p.parse("CREATE UNIQUE idx ON t (a)").is_err());
+}
@@ -5,7 +5,21 @@
 use std::vec;
 
 use minivec::mini_vec;
-use simd_sql::{Parser, Query, SelectStatement, Statement, ast::statement::StatementInner, common::{alias::Alias, expr::{Between, BinaryOp, BinaryOperator, Expr, Field, In, InValue, Like, NumericLiteral, StringLiteral}, from::{From, Table}, limit::Limit, order::{Order, OrderDirection, OrderItem}}};
+use simd_sql::{
+    Parser, Query, SelectStatement, Span, Statement,
+    ast::statement::StatementInner,
+    common::{
+        alias::Alias,
+        expr::{
+            Between, BinaryOp, BinaryOperator, Expr, Field, In, InValue, Like, NumericLiteral,
+            StringLiteral,
+        },
+        from::{From, Table},
+        ident::Ident,
+        limit::Limit,
+        order::{Order, OrderDirection, OrderItem},
+    },
+};
 
 // ============================================================================
 // SELECT 语句测试
@@ -73,28 +87,40 @@ fn parse_select_with_group_by() {
 fn parse_select_group_by_rollup() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, b, COUNT(*) FROM t GROUP BY ROLLUP (a, b)";
-    assert!(p.parse(sql).is_ok(), "SELECT with GROUP BY ROLLUP should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with GROUP BY ROLLUP should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_cube() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, b, c, COUNT(*) FROM t GROUP BY CUBE (a, b, c)";
-    assert!(p.parse(sql).is_ok(), "SELECT with GROUP BY CUBE should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with GROUP BY CUBE should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_grouping_sets() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, b, COUNT(*) FROM t GROUP BY GROUPING SETS ((a, b), (a), (b))";
-    assert!(p.parse(sql).is_ok(), "SELECT with GROUPING SETS should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with GROUPING SETS should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_mixed() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, COUNT(*) FROM t GROUP BY a, ROLLUP (b, c), CUBE (d, e)";
-    assert!(p.parse(sql).is_ok(), "SELECT with mixed GROUP BY should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with mixed GROUP BY should parse"
+    );
 }
 
 #[test]
@@ -110,36 +136,52 @@ fn parse_select_rollup_with_having_order_limit() {
 #[test]
 fn parse_select_grouping_sets_with_having() {
     let p = Parser::new().expect("failed to initialize Parser");
-    let sql = "SELECT a, COUNT(*) AS cnt FROM t GROUP BY GROUPING SETS ((a, b), (a)) HAVING cnt > 0";
-    assert!(p.parse(sql).is_ok(), "GROUPING SETS with HAVING should parse");
+    let sql =
+        "SELECT a, COUNT(*) AS cnt FROM t GROUP BY GROUPING SETS ((a, b), (a)) HAVING cnt > 0";
+    assert!(
+        p.parse(sql).is_ok(),
+        "GROUPING SETS with HAVING should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_rollup_single() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, COUNT(*) FROM t GROUP BY ROLLUP (a)";
-    assert!(p.parse(sql).is_ok(), "SELECT with ROLLUP (single column) should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with ROLLUP (single column) should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_cube_single() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, COUNT(*) FROM t GROUP BY CUBE (a)";
-    assert!(p.parse(sql).is_ok(), "SELECT with CUBE (single column) should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with CUBE (single column) should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_grouping_sets_single() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT a, COUNT(*) FROM t GROUP BY GROUPING SETS ((a, b))";
-    assert!(p.parse(sql).is_ok(), "SELECT with GROUPING SETS (single set) should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with GROUPING SETS (single set) should parse"
+    );
 }
 
 #[test]
 fn parse_select_group_by_rollup_cube_grouping_sets_in_stmt() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT category, SUM(amount) FROM sales GROUP BY ROLLUP (category), CUBE (region), GROUPING SETS ((year), (quarter))";
-    assert!(p.parse(sql).is_ok(), "complex GROUP BY with all three forms should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "complex GROUP BY with all three forms should parse"
+    );
 }
 
 #[test]
@@ -264,63 +306,90 @@ fn parse_select_with_full_join() {
 fn parse_select_with_natural_join() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users NATURAL JOIN orders";
-    assert!(p.parse(sql).is_ok(), "SELECT with NATURAL JOIN should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with NATURAL JOIN should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_natural_left_join() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users NATURAL LEFT JOIN orders";
-    assert!(p.parse(sql).is_ok(), "SELECT with NATURAL LEFT JOIN should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with NATURAL LEFT JOIN should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_natural_right_join() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users NATURAL RIGHT JOIN orders";
-    assert!(p.parse(sql).is_ok(), "SELECT with NATURAL RIGHT JOIN should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with NATURAL RIGHT JOIN should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_natural_full_join() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users NATURAL FULL JOIN orders";
-    assert!(p.parse(sql).is_ok(), "SELECT with NATURAL FULL JOIN should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with NATURAL FULL JOIN should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_join_using() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users JOIN orders USING (user_id)";
-    assert!(p.parse(sql).is_ok(), "SELECT with JOIN ... USING should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with JOIN ... USING should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_join_using_multiple() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users JOIN orders USING (user_id, order_id)";
-    assert!(p.parse(sql).is_ok(), "SELECT with JOIN ... USING (multiple columns) should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with JOIN ... USING (multiple columns) should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_left_join_using() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users LEFT JOIN orders USING (id)";
-    assert!(p.parse(sql).is_ok(), "SELECT with LEFT JOIN ... USING should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with LEFT JOIN ... USING should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_right_join_using() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users RIGHT JOIN orders USING (id)";
-    assert!(p.parse(sql).is_ok(), "SELECT with RIGHT JOIN ... USING should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with RIGHT JOIN ... USING should parse"
+    );
 }
 
 #[test]
 fn parse_select_with_full_join_using() {
     let p = Parser::new().expect("failed to initialize Parser");
     let sql = "SELECT * FROM users FULL JOIN orders USING (id)";
-    assert!(p.parse(sql).is_ok(), "SELECT with FULL JOIN ... USING should parse");
+    assert!(
+        p.parse(sql).is_ok(),
+        "SELECT with FULL JOIN ... USING should parse"
+    );
 }
 
 #[test]
@@ -861,104 +930,261 @@ LIMIT 100;";
     assert_eq!(
         result,
         Statement {
-            list: vec![
-                StatementInner::Query(Query::Select(SelectStatement {
-                    distinct: false,
-                    columns: vec![
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t1"), name: "id" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t1"), name: "name" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t1"), name: "created_at" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t2"), name: "order_id" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t2"), name: "amount" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t2"), name: "status" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t3"), name: "log_id" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t3"), name: "event_type" })},
-                        Alias {name: None, value: Expr::Field(Field { prefix: Some("t3"), name: "payload" })},
-
-                    ],
-                    from: Some(mini_vec![From::LeftJoin { 
-                        left: Box::new(From::LeftJoin {
-                            left: Box::new(From::Table(Table::Name(Alias { name: Some("t1"), value: Expr::Field(Field {prefix: None, name: "user_table"}) }))),
-                            right: Box::new(From::Table(Table::Name(Alias { name: Some("t2"), value: Expr::Field(Field {prefix: None, name: "order_table"}) }))),
-                            condition: Expr::BinaryOp(Box::new(BinaryOp {
-                                op: BinaryOperator::Equal,
-                                left: Expr::Field(Field { prefix: Some("t1"), name: "id" }),
-                                right: Expr::Field(Field { prefix: Some("t2"), name: "user_id" })
-                            }))
+            list: vec![StatementInner::Query(Query::Select(SelectStatement {
+                distinct: false,
+                columns: vec![
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t1")),
+                            name: Ident::new("id"),
+                            span: Span::new(12, 17)
+                        }),
+                        span: Span::new(12, 17)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t1")),
+                            name: Ident::new("name"),
+                            span: Span::new(19, 26)
+                        }),
+                        span: Span::new(19, 26)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t1")),
+                            name: Ident::new("created_at"),
+                            span: Span::new(28, 41)
+                        }),
+                        span: Span::new(28, 41)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t2")),
+                            name: Ident::new("order_id"),
+                            span: Span::new(47, 58)
+                        }),
+                        span: Span::new(47, 58)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t2")),
+                            name: Ident::new("amount"),
+                            span: Span::new(60, 69)
+                        }),
+                        span: Span::new(60, 69)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t2")),
+                            name: Ident::new("status"),
+                            span: Span::new(71, 80)
+                        }),
+                        span: Span::new(71, 80)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t3")),
+                            name: Ident::new("log_id"),
+                            span: Span::new(86, 95)
                         }),
-                        right: Box::new(From::Table(Table::Name(Alias { name: Some("t3"), value: Expr::Field(Field {prefix: None, name: "log_table"}) }))),
+                        span: Span::new(86, 95)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t3")),
+                            name: Ident::new("event_type"),
+                            span: Span::new(97, 110)
+                        }),
+                        span: Span::new(97, 110)
+                    },
+                    Alias {
+                        name: None,
+                        value: Expr::Field(Field {
+                            prefix: Some(Ident::new("t3")),
+                            name: Ident::new("payload"),
+                            span: Span::new(112, 122)
+                        }),
+                        span: Span::new(112, 122)
+                    },
+                ],
+                from: Some(mini_vec![From::LeftJoin {
+                    left: Box::new(From::LeftJoin {
+                        left: Box::new(From::Table(Table::Name(Alias {
+                            name: Some(Ident::new("t1")),
+                            value: Expr::Field(Field {
+                                prefix: None,
+                                name: Ident::new("user_table"),
+                                span: Span::new(128, 138)
+                            }),
+                            span: Span::new(128, 141)
+                        }))),
+                        right: Box::new(From::Table(Table::Name(Alias {
+                            name: Some(Ident::new("t2")),
+                            value: Expr::Field(Field {
+                                prefix: None,
+                                name: Ident::new("order_table"),
+                                span: Span::new(152, 163)
+                            }),
+                            span: Span::new(152, 166)
+                        }))),
                         condition: Expr::BinaryOp(Box::new(BinaryOp {
                             op: BinaryOperator::Equal,
-                            left: Expr::Field(Field { prefix: Some("t1"), name: "id" }),
-                            right: Expr::Field(Field { prefix: Some("t3"), name: "user_id" })
+                            left: Expr::Field(Field {
+                                prefix: Some(Ident::new("t1")),
+                                name: Ident::new("id"),
+                                span: Span::new(170, 175)
+                            }),
+                            right: Expr::Field(Field {
+                                prefix: Some(Ident::new("t2")),
+                                name: Ident::new("user_id"),
+                                span: Span::new(178, 188)
+                            })
                         }))
-                    }]),
-                    where_statement: Some(Expr::BinaryOp(Box::new(
-                        BinaryOp {
+                    }),
+                    right: Box::new(From::Table(Table::Name(Alias {
+                        name: Some(Ident::new("t3")),
+                        value: Expr::Field(Field {
+                            prefix: None,
+                            name: Ident::new("log_table"),
+                            span: Span::new(199, 208)
+                        }),
+                        span: Span::new(199, 211)
+                    }))),
+                    condition: Expr::BinaryOp(Box::new(BinaryOp {
+                        op: BinaryOperator::Equal,
+                        left: Expr::Field(Field {
+                            prefix: Some(Ident::new("t1")),
+                            name: Ident::new("id"),
+                            span: Span::new(215, 220)
+                        }),
+                        right: Expr::Field(Field {
+                            prefix: Some(Ident::new("t3")),
+                            name: Ident::new("user_id"),
+                            span: Span::new(223, 233)
+                        })
+                    }))
+                }]),
+                where_statement: Some(Expr::BinaryOp(Box::new(BinaryOp {
+                    op: BinaryOperator::And,
+                    left: Expr::BinaryOp(Box::new(BinaryOp {
+                        op: BinaryOperator::And,
+                        left: Expr::BinaryOp(Box::new(BinaryOp {
                             op: BinaryOperator::And,
-                            left: Expr::BinaryOp(Box::new(BinaryOp{
-                                op: BinaryOperator::And,
-                                left: Expr::BinaryOp(Box::new(BinaryOp {
-                                    op: BinaryOperator::And,
-                                    left: Expr::BinaryOp(Box::new(BinaryOp {
-                                        op: BinaryOperator::Equal,
-                                        left: Expr::Field(Field { prefix: Some("t1"), name: "status" }),
-                                        right: Expr::NumericLiteral(NumericLiteral {
-                                            value: "1"
-                                        })
-                                    })),
-                                    right: Expr::Between(Between {
-                                            is_not: false,
-                                            field: Box::new(Expr::Field(Field { prefix: Some("t2"), name: "created_at" })),
-                                            lower: Box::new(Expr::StringLiteral(StringLiteral {
-                                                value: "'2024-01-01'",
-                                            })),
-                                            upper: Box::new(Expr::StringLiteral(StringLiteral {
-                                                value: "'2025-01-01'",
-                                            })),
-                                        })
-                                })),
-                                right: Expr::In(In {
-                                        is_not: false,
-                                        field: Box::new(Expr::Field(Field { prefix: Some("t3"), name: "event_type" })),
-                                        in_value: InValue::List(mini_vec![
-                                            Expr::StringLiteral(StringLiteral { value: "'click'" }),
-                                            Expr::StringLiteral(StringLiteral { value: "'view'" }),
-                                            Expr::StringLiteral(StringLiteral { value: "'purchase'" }),
-                                        ])
-                                    })
+                            left: Expr::BinaryOp(Box::new(BinaryOp {
+                                op: BinaryOperator::Equal,
+                                left: Expr::Field(Field {
+                                    prefix: Some(Ident::new("t1")),
+                                    name: Ident::new("status"),
+                                    span: Span::new(245, 254)
+                                }),
+                                right: Expr::NumericLiteral(NumericLiteral {
+                                    value: "1",
+                                    span: Span::new(257, 258),
+                                })
                             })),
-                            right: Expr::BinaryOp(Box::new(BinaryOp {
-                                op: BinaryOperator::Or,
-                                left: Expr::BinaryOp(Box::new(BinaryOp {
-                                    op: BinaryOperator::Greater,
-                                    left: Expr::Field(Field { prefix: Some("t2"), name: "amount" }),
-                                    right: Expr::NumericLiteral(NumericLiteral { value: "100" }),
+                            right: Expr::Between(Between {
+                                is_not: false,
+                                field: Box::new(Expr::Field(Field {
+                                    prefix: Some(Ident::new("t2")),
+                                    name: Ident::new("created_at"),
+                                    span: Span::new(267, 280)
                                 })),
-                                right: Expr::Like(Like {
-                                    is_not: false,
-                                    field: Box::new(Expr::Field(Field { prefix: Some("t3"), name: "payload" })),
-                                    pattern: Box::new(Expr::StringLiteral(StringLiteral{value: "'%error%'"}))
+                                lower: Box::new(Expr::StringLiteral(StringLiteral {
+                                    value: "'2024-01-01'",
+                                    span: Span::new(289, 301),
+                                })),
+                                upper: Box::new(Expr::StringLiteral(StringLiteral {
+                                    value: "'2025-01-01'",
+                                    span: Span::new(306, 318),
+                                })),
+                            })
+                        })),
+                        right: Expr::In(In {
+                            is_not: false,
+                            field: Box::new(Expr::Field(Field {
+                                prefix: Some(Ident::new("t3")),
+                                name: Ident::new("event_type"),
+                                span: Span::new(327, 340)
+                            })),
+                            in_value: InValue::List(mini_vec![
+                                Expr::StringLiteral(StringLiteral {
+                                    value: "'click'",
+                                    span: Span::new(345, 352)
+                                }),
+                                Expr::StringLiteral(StringLiteral {
+                                    value: "'view'",
+                                    span: Span::new(354, 360)
+                                }),
+                                Expr::StringLiteral(StringLiteral {
+                                    value: "'purchase'",
+                                    span: Span::new(362, 372)
                                 }),
+                            ]),
+                            span: Span::new(327, 373),
+                        })
+                    })),
+                    right: Expr::BinaryOp(Box::new(BinaryOp {
+                        op: BinaryOperator::Or,
+                        left: Expr::BinaryOp(Box::new(BinaryOp {
+                            op: BinaryOperator::Greater,
+                            left: Expr::Field(Field {
+                                prefix: Some(Ident::new("t2")),
+                                name: Ident::new("amount"),
+                                span: Span::new(392, 401)
+                            }),
+                            right: Expr::NumericLiteral(NumericLiteral {
+                                value: "100",
+                                span: Span::new(404, 407)
+                            }),
+                        })),
+                        right: Expr::Like(Like {
+                            is_not: false,
+                            field: Box::new(Expr::Field(Field {
+                                prefix: Some(Ident::new("t3")),
+                                name: Ident::new("payload"),
+                                span: Span::new(420, 430)
+                            })),
+                            pattern: Box::new(Expr::StringLiteral(StringLiteral {
+                                value: "'%error%'",
+                                span: Span::new(436, 445)
                             }))
-                        }))),
-                    group_by: None,
-                    having_statement: None,
-                    order_by: Some(Order {
-                        columns: mini_vec![
-                            OrderItem{
-                                expr: Expr::Field(Field { prefix: Some("t1"), name: "created_at" }),
-                                direction: OrderDirection::DESC,
-                                nulls_order: None,
-                            }
-                        ]
+                        }),
+                    }))
+                }))),
+                group_by: None,
+                having_statement: None,
+                window: None,
+                order_by: Some(Order {
+                    columns: mini_vec![OrderItem {
+                        expr: Expr::Field(Field {
+                            prefix: Some(Ident::new("t1")),
+                            name: Ident::new("created_at"),
+                            span: Span::new(461, 474)
+                        }),
+                        direction: OrderDirection::DESC,
+                        nulls_order: None,
+                        span: Span::new(461, 479),
+                    }],
+                    span: Span::new(452, 479),
+                }),
+                limit: Some(Limit {
+                    offset: None,
+                    limit: Expr::NumericLiteral(NumericLiteral {
+                        value: "100",
+                        span: Span::new(486, 489)
                     }),
-                    limit: Some(Limit {
-                        offset: None,
-                        limit: Expr::NumericLiteral(NumericLiteral { value: "100" })
-                    }) }))
-            ]
+                    span: Span::new(480, 489),
+                }),
+                span: Span::new(0, 489)
+            }))]
         }
     );
 }
-